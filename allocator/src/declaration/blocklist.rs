@@ -0,0 +1 @@
+pub use common::declaration::{ActionList, Blocklist};
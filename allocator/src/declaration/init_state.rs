@@ -1,7 +1 @@
-use serde::{Deserialize, Serialize};
-use super::Position;
-
-#[derive(Deserialize, Serialize, Default, Debug, Clone)]
-pub struct InitState {
-    pub ball: Option<Position>,
-}
+pub use common::declaration::{InitState, PlayerInitState};
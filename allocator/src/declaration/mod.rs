@@ -5,6 +5,7 @@ pub mod referee;
 pub mod host_port;
 pub mod stop_event;
 pub mod init_state;
+pub mod blocklist;
 
 pub mod position;
 pub mod unum;
@@ -17,8 +18,15 @@ pub use player::{
     PlayerBase as PlayerBaseDeclaration,
 };
 pub use referee::Referee as RefereeDeclaration;
-pub use init_state::InitState as InitStateDeclaration;
+pub use init_state::{
+    InitState as InitStateDeclaration,
+    PlayerInitState as PlayerInitStateDeclaration,
+};
 pub use stop_event::StoppingEvent as StopEventDeclaration;
+pub use blocklist::{
+    ActionList as ActionListDeclaration,
+    Blocklist as BlocklistDeclaration,
+};
 
 pub use position::Position;
 pub use host_port::HostPort;
@@ -1,7 +1 @@
-use serde::{Deserialize, Serialize};
-
-#[derive(Deserialize, Serialize, Default, Debug, Clone)]
-pub struct StoppingEvent {
-    pub timeup: Option<u16>,
-}
-
+pub use common::stopping::StoppingEvent;
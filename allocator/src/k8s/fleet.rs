@@ -26,6 +26,8 @@ impl K8sClient {
     pub async fn create_fleet_v1(&self, name: String, gs_conf: ConfigV1) -> Result<()> {
         let api: Api<Fleet> = Api::namespaced(self.client.clone(), &self.agones_ns);
 
+        let rcss_env = gs_conf.rcss_env();
+
         // Convert ConfigV1 into labels & annotations
         let metadata: MetaData = gs_conf.try_into()
             .map_err(|e: common::errors::BuilderError| Error::InvalidMetaData(format!("{e:?}")))?;
@@ -34,11 +36,14 @@ impl K8sClient {
         let annotations = metadata.annotations.into_map();
 
         let fleet = {
+            let mut container = ContainerBuilder::default_rcss();
+            container.with_envs(rcss_env);
+
             let mut fleet = FleetBuilder::new();
             fleet.with_name(name)
                 .with_labels(labels)
                 .with_annotations(annotations)
-                .add_container(ContainerBuilder::default_rcss())
+                .add_container(container)
                 .map_err(|e| Error::InvalidMetaData(format!("{e:?}")))?;
             
             fleet.build_into()
@@ -3,6 +3,7 @@ use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
 use common::errors::BuilderError;
 use crate::declaration::{
+    BlocklistDeclaration,
     InitStateDeclaration,
     RefereeDeclaration,
     StopEventDeclaration
@@ -16,6 +17,7 @@ pub struct Annotations {
     pub init: InitStateDeclaration,
     pub referee: RefereeDeclaration,
     pub stopping: StopEventDeclaration,
+    pub blocklist: BlocklistDeclaration,
 }
 
 impl Annotations {
@@ -29,9 +31,12 @@ impl Annotations {
         let init = map.get("init")
             .and_then(|i| serde_json::from_str(i).ok())
             .unwrap_or_default();
+        let blocklist = map.get("blocklist")
+            .and_then(|b| serde_json::from_str(b).ok())
+            .unwrap_or_default();
         let team_l = map.remove("team.l").unwrap_or("TeamLeft".to_string());
         let team_r = map.remove("team.r").unwrap_or("TeamRight".to_string());
-        Annotations { referee, stopping, init, team_l, team_r }
+        Annotations { referee, stopping, init, blocklist, team_l, team_r }
     }
     pub fn into_map(self) -> HashMap<String, String> {
         let mut map = HashMap::new();
@@ -46,6 +51,9 @@ impl Annotations {
         if let Ok(init_str) = serde_json::to_string(&self.init) {
             map.insert("init".to_string(), init_str);
         }
+        if let Ok(blocklist_str) = serde_json::to_string(&self.blocklist) {
+            map.insert("blocklist".to_string(), blocklist_str);
+        }
         map
     }
 }
@@ -3,14 +3,16 @@ use std::collections::HashMap;
 use common::errors::BuilderError;
 
 use crate::declaration::{
+    ActionListDeclaration,
+    BlocklistDeclaration,
     HostPort,
     ImageDeclaration,
     InitStateDeclaration,
     PlayerBaseDeclaration,
     PlayerDeclaration,
+    PlayerInitStateDeclaration,
     Position as PositionDeclaration,
     RefereeDeclaration,
-    StopEventDeclaration,
     Unum,
 };
 use crate::schema::{
@@ -41,6 +43,14 @@ impl TryFrom<ConfigV1> for MetaData {
         let mut players_r = HashMap::new();
         let mut team_name_l = None;
         let mut team_name_r = None;
+        let mut init = InitStateDeclaration {
+            ball: init_state.ball.map(|position| PositionDeclaration {
+                x: position.x.into(),
+                y: position.y.into(),
+            }),
+            ..Default::default()
+        };
+        let mut blocklist = BlocklistDeclaration::default();
 
         insert_team(
             teams.allies,
@@ -48,6 +58,8 @@ impl TryFrom<ConfigV1> for MetaData {
             &mut players_r,
             &mut team_name_l,
             &mut team_name_r,
+            &mut init,
+            &mut blocklist,
         )?;
         insert_team(
             teams.opponents,
@@ -55,6 +67,8 @@ impl TryFrom<ConfigV1> for MetaData {
             &mut players_r,
             &mut team_name_l,
             &mut team_name_r,
+            &mut init,
+            &mut blocklist,
         )?;
 
         Ok(MetaData {
@@ -65,15 +79,9 @@ impl TryFrom<ConfigV1> for MetaData {
                 referee: RefereeDeclaration {
                     enabled: referee.enable,
                 },
-                stopping: StopEventDeclaration {
-                    timeup: stopping.time_up,
-                },
-                init: InitStateDeclaration {
-                    ball: init_state.ball.map(|position| PositionDeclaration {
-                        x: position.x.into(),
-                        y: position.y.into(),
-                    }),
-                },
+                stopping: stopping.into(),
+                init,
+                blocklist,
             },
         })
     }
@@ -85,6 +93,8 @@ fn insert_team(
     player_r: &mut HashMap<Unum, PlayerLabel>,
     team_name_l: &mut Option<String>,
     team_name_r: &mut Option<String>,
+    init: &mut InitStateDeclaration,
+    blocklist: &mut BlocklistDeclaration,
 ) -> Result<(), BuilderError> {
     let TeamV1 {
         name,
@@ -92,9 +102,9 @@ fn insert_team(
         players,
     } = team;
 
-    let (labels, team_name) = match side {
-        TeamSideV1::Left => (player_l, team_name_l),
-        TeamSideV1::Right => (player_r, team_name_r),
+    let (labels, team_name, inits, blocked) = match side {
+        TeamSideV1::Left => (player_l, team_name_l, &mut init.players_l, &mut blocklist.players_l),
+        TeamSideV1::Right => (player_r, team_name_r, &mut init.players_r, &mut blocklist.players_r),
     };
 
     if team_name.replace(name.clone()).is_some() {
@@ -105,7 +115,11 @@ fn insert_team(
     }
 
     for player in players {
-        let (unum, label) = convert_player(player)?;
+        let (unum, label, init, actions) = convert_player(player)?;
+        inits.insert(unum, init);
+        if !actions.is_empty() {
+            blocked.insert(unum, actions);
+        }
         if labels.insert(unum, label).is_some() {
             return Err(BuilderError::InvalidField {
                 field: "teams.players",
@@ -117,12 +131,15 @@ fn insert_team(
     Ok(())
 }
 
-fn convert_player(player: PlayerV1) -> Result<(Unum, PlayerLabel), BuilderError> {
+fn convert_player(
+    player: PlayerV1,
+) -> Result<(Unum, PlayerLabel, PlayerInitStateDeclaration, ActionListDeclaration), BuilderError> {
     let PlayerV1 {
         unum,
         goalie,
         policy,
-        ..
+        init_state,
+        blocklist,
     } = player;
 
     let unum = Unum::try_from(unum)?;
@@ -145,7 +162,20 @@ fn convert_player(player: PlayerV1) -> Result<(Unum, PlayerLabel), BuilderError>
         },
     };
 
-    Ok((unum, PlayerLabel { player }))
+    let init = PlayerInitStateDeclaration {
+        pos: init_state.pos.map(|position| PositionDeclaration {
+            x: position.x.into(),
+            y: position.y.into(),
+        }),
+        stamina: init_state.stamina,
+    };
+
+    let actions = ActionListDeclaration {
+        dash: blocklist.dash,
+        r#catch: blocklist.r#catch,
+    };
+
+    Ok((unum, PlayerLabel { player }, init, actions))
 }
 
 #[cfg(test)]
@@ -171,6 +201,11 @@ mod tests {
                     "players": [{
                         "unum": 1,
                         "goalie": true,
+                        "init_state": {
+                            "pos": { "x": 0.75, "y": 0.5 },
+                            "stamina": 4000
+                        },
+                        "blocklist": { "dash": false, "catch": true },
                         "policy": {
                             "kind": "agent",
                             "agent": "ssp",
@@ -204,15 +239,45 @@ mod tests {
         assert_eq!(metadata.annotations.team_r, "Righties");
         assert!(!metadata.annotations.referee.enabled);
         assert_eq!(metadata.annotations.stopping.timeup, Some(6000));
+        assert_eq!(metadata.annotations.stopping.goal_l, Some(3));
+        assert_eq!(metadata.annotations.stopping.goal_r, None);
 
         let ball = metadata
             .annotations
             .init
             .ball
+            .as_ref()
             .expect("ball init state should be preserved");
         assert_eq!(ball.x, 0.5);
         assert_eq!(ball.y, 0.25);
 
+        let goalie = &metadata.annotations.init.players_r[&right_unum];
+        let pos = goalie.pos.as_ref().expect("player position should be preserved");
+        assert_eq!((pos.x, pos.y), (0.75, 0.5));
+        assert_eq!(goalie.stamina, Some(4000));
+        let fielder = &metadata.annotations.init.players_l[&left_unum];
+        assert!(fielder.pos.is_none() && fielder.stamina.is_none());
+
+        let init: InitStateDeclaration = serde_json::from_str(
+            &serde_json::to_string(&metadata.annotations.init).expect("init should serialize"),
+        )
+        .expect("init should deserialize");
+        assert_eq!(init.players_r[&right_unum].stamina, Some(4000));
+        // what the service's agones annotation watcher is tested against
+        let annotations = metadata.annotations.clone().into_map();
+        assert_eq!(
+            annotations["init"],
+            r#"{"ball":{"x":0.5,"y":0.25},"players_l":{"2":{"pos":null,"stamina":null}},"players_r":{"1":{"pos":{"x":0.75,"y":0.5},"stamina":4000}}}"#
+        );
+        assert_eq!(annotations["blocklist"], r#"{"players_l":{},"players_r":{"1":{"dash":false,"catch":true}}}"#);
+
+        let blocklist = &metadata.annotations.blocklist;
+        assert_eq!(
+            blocklist.players_r.get(&right_unum),
+            Some(&ActionListDeclaration { dash: false, r#catch: true })
+        );
+        assert!(blocklist.players_l.is_empty());
+
         assert!(matches!(
             &metadata.labels.left[&left_unum].player,
             PlayerDeclaration::Helios { .. }
@@ -1,7 +1,9 @@
 use std::collections::HashMap;
 use std::net::Ipv4Addr;
 use serde::{Deserialize, Serialize};
+use common::process::config::{Config as ProcessConfig, Presets};
 
+use crate::declaration::StopEventDeclaration;
 use crate::schema::v1::utils::pos_in_court;
 
 use super::{Schema, TeamsV1, Position};
@@ -21,7 +23,13 @@ pub struct ConfigV1 {
     #[serde(default)]
     pub init_state: GlobalInitStateV1,
     #[serde(default)]
-    pub env:    Option<HashMap<String, String>>
+    pub env:    Option<HashMap<String, String>>,
+    /// One of [`Presets::BUILTIN`], the server's own defaults when `None`.
+    #[serde(default)]
+    pub preset: Option<String>,
+    /// rcssserver parameters applied over the preset.
+    #[serde(default)]
+    pub rcss: Option<ProcessConfig>,
 }
 
 const fn default_host() -> Ipv4Addr {
@@ -37,7 +45,28 @@ impl Schema for ConfigV1 {
         self.teams.verify()?;
         self.referee.verify()?;
         self.stopping.verify()?;
-        self.init_state.verify()
+        self.init_state.verify()?;
+        if let Some(preset) = &self.preset && !Presets::BUILTIN.contains(&preset.as_str()) {
+            return Err("unknown rcssserver preset");
+        }
+        if let Some(rcss) = &self.rcss && rcss.validate().is_err() {
+            return Err("invalid rcssserver configuration");
+        }
+        Ok(())
+    }
+}
+
+impl ConfigV1 {
+    /// Environment of the rcss container selecting the preset and overrides.
+    pub fn rcss_env(&self) -> Vec<(&'static str, String)> {
+        let mut ret = vec![];
+        if let Some(preset) = &self.preset {
+            ret.push((Presets::ENV, preset.clone()));
+        }
+        if let Some(rcss) = &self.rcss {
+            ret.push((Presets::OVERRIDES_ENV, serde_json::to_string(rcss).expect("config serializes")));
+        }
+        ret
     }
 }
 
@@ -63,13 +92,23 @@ impl Schema for RefereeV1 {
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
 pub struct StoppingEventV1 {
     pub time_up: Option<u16>,
-    pub goal_l: Option<u8>,
-    pub goal_r: Option<u8>,
+    pub goal_l: Option<u32>,
+    pub goal_r: Option<u32>,
 }
 
 impl Schema for StoppingEventV1 {
     fn verify(&self) -> Result<(), &'static str> {
-        Ok(())
+        StopEventDeclaration::from(self.clone()).verify()
+    }
+}
+
+impl From<StoppingEventV1> for StopEventDeclaration {
+    fn from(stopping: StoppingEventV1) -> Self {
+        Self {
+            timeup: stopping.time_up,
+            goal_l: stopping.goal_l,
+            goal_r: stopping.goal_r,
+        }
     }
 }
 
@@ -28,4 +28,43 @@ mod tests {
 
         Ok(())
     }
+
+    #[test]
+    fn test_rcss_preset() -> Result<(), Box<dyn std::error::Error>> {
+        use super::Schema;
+
+        let mut config: serde_json::Value = serde_json::from_str(include_str!("../../../template.json"))?;
+        config["preset"] = "keepaway".into();
+        config["rcss"] = serde_json::json!({ "server": { "keepaway_width": 30.0 } });
+        let config: super::ConfigV1 = serde_json::from_value(config)?;
+        assert_eq!(config.verify(), Ok(()));
+        assert_eq!(config.rcss_env(), vec![
+            ("RCSS_PRESET", "keepaway".to_string()),
+            ("RCSS_OVERRIDES", r#"{"server":{"keepaway_width":30.0},"player":{},"csv_saver":{}}"#.to_string()),
+        ]);
+
+        let mut unknown = config.clone();
+        unknown.preset = Some("friendly".to_string());
+        assert!(unknown.verify().is_err());
+
+        let mut invalid = config;
+        invalid.rcss.as_mut().unwrap().server_then(|c| {
+            c.keepaway_width(100.0);
+        });
+        assert!(invalid.verify().is_err());
+
+        Ok(())
+    }
+
+    #[test]
+    fn test_reject_zero_goal_limit() -> Result<(), Box<dyn std::error::Error>> {
+        use super::Schema;
+
+        let mut config: super::ConfigV1 = serde_json::from_str(include_str!("../../../template.json"))?;
+        assert_eq!(config.verify(), Ok(()));
+        config.stopping.goal_r = Some(0);
+        assert!(config.verify().is_err());
+
+        Ok(())
+    }
 }
@@ -40,8 +40,8 @@ impl Schema for PlayerV1 {
 /// Default all unset
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
 pub struct PlayerInitStateV1 {
-    pos: Option<Position>,
-    stamina: Option<u16>,
+    pub pos: Option<Position>,
+    pub stamina: Option<u16>,
 }
 
 impl Schema for PlayerInitStateV1 {
@@ -57,8 +57,8 @@ impl Schema for PlayerInitStateV1 {
 /// Default for all false
 #[derive(Deserialize, Serialize, Default, Clone, Debug)]
 pub struct PlayerActionList {
-    dash: bool,
-    r#catch: bool,
+    pub dash: bool,
+    pub r#catch: bool,
 }
 
 impl Schema for PlayerActionList {
@@ -0,0 +1,242 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Cursor, Region, Team, UnumSet};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Action {
+    /// `(pos REGION)`
+    Pos { region: Region },
+    /// `(home REGION)`
+    Home { region: Region },
+    /// `(bto REGION {BMOVE...})`
+    BallTo { region: Region, moves: Vec<BallMove> },
+    /// `(bpos REGION)`
+    BallPos { region: Region },
+    /// `(mark TEAM UNUM_SET)`
+    Mark { team: Team, unums: UnumSet },
+    /// `(markl REGION)`
+    MarkLineRegion { region: Region },
+    /// `(markl TEAM UNUM_SET)`
+    MarkLinePlayer { team: Team, unums: UnumSet },
+    /// `(oline REGION)`
+    OffsideLine { region: Region },
+    /// `(htype TYPE)`
+    HeteroType { player_type: i32 },
+    Hold,
+    /// `(pass REGION)`
+    PassRegion { region: Region },
+    /// `(pass UNUM_SET)`
+    PassPlayer { unums: UnumSet },
+    /// `(dribble REGION)`
+    Dribble { region: Region },
+    /// `(clear REGION)`
+    Clear { region: Region },
+    Shoot,
+    /// `(tackle UNUM_SET)`
+    Tackle { unums: UnumSet },
+    Intercept,
+    /// An action defined earlier with `definea`.
+    Named { name: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum BallMove {
+    Pass,
+    Dribble,
+    Clear,
+    Score,
+}
+
+/// `(do|dont TEAM UNUM_SET ACTION...)` or a directive defined with `defined`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Directive {
+    Do {
+        positive: bool,
+        team: Team,
+        unums: UnumSet,
+        actions: Vec<Action>,
+    },
+    Named {
+        name: String,
+    },
+}
+
+impl BallMove {
+    pub fn encode(self) -> &'static str {
+        match self {
+            BallMove::Pass => "pass",
+            BallMove::Dribble => "dribble",
+            BallMove::Clear => "clear",
+            BallMove::Score => "score",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "pass" => Some(BallMove::Pass),
+            "dribble" => Some(BallMove::Dribble),
+            "clear" => Some(BallMove::Clear),
+            "score" => Some(BallMove::Score),
+            _ => None,
+        }
+    }
+}
+
+impl Action {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        if let Sexpr::Str(name) = expr {
+            return Some(Action::Named {
+                name: name.to_string(),
+            });
+        }
+
+        let args = expr.args();
+        let region = || -> Option<Region> {
+            let [region] = args else {
+                return None;
+            };
+            Region::from_sexpr(region)
+        };
+        // (pass REGION) and (pass UNUM_SET) share a head, so do (markl ...)
+        let is_region = args.first().is_some_and(|a| a.is_list() || matches!(a, Sexpr::Str(_)));
+
+        let mut cursor = Cursor::new(args);
+        let ret = match expr.head()? {
+            "pos" => return Some(Action::Pos { region: region()? }),
+            "home" => return Some(Action::Home { region: region()? }),
+            "bpos" => return Some(Action::BallPos { region: region()? }),
+            "oline" => return Some(Action::OffsideLine { region: region()? }),
+            "dribble" => return Some(Action::Dribble { region: region()? }),
+            "clear" => return Some(Action::Clear { region: region()? }),
+            "markl" if is_region => return Some(Action::MarkLineRegion { region: region()? }),
+            "pass" if is_region => return Some(Action::PassRegion { region: region()? }),
+            "bto" => Action::BallTo {
+                region: Region::from_sexpr(cursor.next()?)?,
+                moves: cursor
+                    .set()?
+                    .into_iter()
+                    .map(BallMove::decode)
+                    .collect::<Option<_>>()?,
+            },
+            "mark" => Action::Mark {
+                team: cursor.parse()?,
+                unums: cursor.unum_set()?,
+            },
+            "markl" => Action::MarkLinePlayer {
+                team: cursor.parse()?,
+                unums: cursor.unum_set()?,
+            },
+            "pass" => Action::PassPlayer {
+                unums: cursor.unum_set()?,
+            },
+            "htype" => Action::HeteroType {
+                player_type: cursor.parse()?,
+            },
+            "tackle" => Action::Tackle {
+                unums: cursor.unum_set()?,
+            },
+            "hold" => Action::Hold,
+            "shoot" => Action::Shoot,
+            "intercept" => Action::Intercept,
+            _ => return None,
+        };
+
+        cursor.is_done().then_some(ret)
+    }
+}
+
+impl Directive {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        if let Sexpr::Str(name) = expr {
+            return Some(Directive::Named {
+                name: name.to_string(),
+            });
+        }
+
+        let positive = match expr.head()? {
+            "do" => true,
+            "dont" => false,
+            _ => return None,
+        };
+
+        let mut cursor = Cursor::new(expr.args());
+        let team = cursor.parse()?;
+        let unums = cursor.unum_set()?;
+        let actions = cursor
+            .rest()
+            .iter()
+            .map(Action::from_sexpr)
+            .collect::<Option<Vec<_>>>()?;
+        if actions.is_empty() {
+            return None;
+        }
+
+        Some(Directive::Do {
+            positive,
+            team,
+            unums,
+            actions,
+        })
+    }
+}
+
+impl Display for Action {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Action::Pos { region } => write!(f, "(pos {region})"),
+            Action::Home { region } => write!(f, "(home {region})"),
+            Action::BallTo { region, moves } => {
+                write!(f, "(bto {region} {{")?;
+                for (idx, bmove) in moves.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(" ")?;
+                    }
+                    f.write_str(bmove.encode())?;
+                }
+                f.write_str("})")
+            }
+            Action::BallPos { region } => write!(f, "(bpos {region})"),
+            Action::Mark { team, unums } => write!(f, "(mark {team} {unums})"),
+            Action::MarkLineRegion { region } => write!(f, "(markl {region})"),
+            Action::MarkLinePlayer { team, unums } => write!(f, "(markl {team} {unums})"),
+            Action::OffsideLine { region } => write!(f, "(oline {region})"),
+            Action::HeteroType { player_type } => write!(f, "(htype {player_type})"),
+            Action::Hold => f.write_str("(hold)"),
+            Action::PassRegion { region } => write!(f, "(pass {region})"),
+            Action::PassPlayer { unums } => write!(f, "(pass {unums})"),
+            Action::Dribble { region } => write!(f, "(dribble {region})"),
+            Action::Clear { region } => write!(f, "(clear {region})"),
+            Action::Shoot => f.write_str("(shoot)"),
+            Action::Tackle { unums } => write!(f, "(tackle {unums})"),
+            Action::Intercept => f.write_str("(intercept)"),
+            Action::Named { name } => write!(f, "\"{name}\""),
+        }
+    }
+}
+
+impl Display for Directive {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Directive::Do {
+                positive,
+                team,
+                unums,
+                actions,
+            } => {
+                let head = if *positive { "do" } else { "dont" };
+                write!(f, "({head} {team} {unums}")?;
+                for action in actions {
+                    write!(f, " {action}")?;
+                }
+                f.write_str(")")
+            }
+            Directive::Named { name } => write!(f, "\"{name}\""),
+        }
+    }
+}
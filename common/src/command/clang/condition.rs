@@ -0,0 +1,229 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Cursor, Region, Team, UnumSet};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Condition {
+    True,
+    False,
+    /// `(ppos TEAM UNUM_SET MIN MAX REGION)`, between MIN and MAX of the
+    /// players are inside the region.
+    PlayerPos {
+        team: Team,
+        unums: UnumSet,
+        min: u8,
+        max: u8,
+        region: Region,
+    },
+    /// `(bpos REGION)`
+    BallPos { region: Region },
+    /// `(bowner TEAM UNUM_SET)`
+    BallOwner { team: Team, unums: UnumSet },
+    /// `(playm PLAY_MODE)`, CLang's own play mode names, e.g. `bko` or `ko_our`.
+    PlayMode { mode: String },
+    And { conditions: Vec<Condition> },
+    Or { conditions: Vec<Condition> },
+    Not { condition: Box<Condition> },
+    /// `(time < 100)`, `(opp_goals >= 2)` ...
+    Compare {
+        var: CompareVar,
+        op: CompareOp,
+        value: i32,
+    },
+    /// `(unum VAR UNUM_SET)`
+    Unum { var: String, unums: UnumSet },
+    /// A condition defined earlier with `definec`.
+    Named { name: String },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum CompareVar {
+    Time,
+    OppGoals,
+    OurGoals,
+    GoalDiff,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub enum CompareOp {
+    #[serde(rename = "<")]
+    Lt,
+    #[serde(rename = "<=")]
+    Le,
+    #[serde(rename = "==")]
+    Eq,
+    #[serde(rename = "!=")]
+    Ne,
+    #[serde(rename = ">=")]
+    Ge,
+    #[serde(rename = ">")]
+    Gt,
+}
+
+impl CompareVar {
+    pub fn encode(self) -> &'static str {
+        match self {
+            CompareVar::Time => "time",
+            CompareVar::OppGoals => "opp_goals",
+            CompareVar::OurGoals => "our_goals",
+            CompareVar::GoalDiff => "goal_diff",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "time" => Some(CompareVar::Time),
+            "opp_goals" => Some(CompareVar::OppGoals),
+            "our_goals" => Some(CompareVar::OurGoals),
+            "goal_diff" => Some(CompareVar::GoalDiff),
+            _ => None,
+        }
+    }
+}
+
+impl CompareOp {
+    pub fn encode(self) -> &'static str {
+        match self {
+            CompareOp::Lt => "<",
+            CompareOp::Le => "<=",
+            CompareOp::Eq => "==",
+            CompareOp::Ne => "!=",
+            CompareOp::Ge => ">=",
+            CompareOp::Gt => ">",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "<" => Some(CompareOp::Lt),
+            "<=" => Some(CompareOp::Le),
+            "==" => Some(CompareOp::Eq),
+            "!=" => Some(CompareOp::Ne),
+            ">=" => Some(CompareOp::Ge),
+            ">" => Some(CompareOp::Gt),
+            _ => None,
+        }
+    }
+}
+
+impl Condition {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        if let Sexpr::Str(name) = expr {
+            return Some(Condition::Named {
+                name: name.to_string(),
+            });
+        }
+
+        let args = expr.args();
+        let list = || args.iter().map(Condition::from_sexpr).collect::<Option<_>>();
+
+        let ret = match expr.head()? {
+            "true" => Condition::True,
+            "false" => Condition::False,
+            "ppos" => {
+                let mut cursor = Cursor::new(args);
+                let ret = Condition::PlayerPos {
+                    team: cursor.parse()?,
+                    unums: cursor.unum_set()?,
+                    min: cursor.parse()?,
+                    max: cursor.parse()?,
+                    region: Region::from_sexpr(cursor.next()?)?,
+                };
+                return cursor.is_done().then_some(ret);
+            }
+            "bpos" => {
+                let [region] = args else {
+                    return None;
+                };
+                Condition::BallPos {
+                    region: Region::from_sexpr(region)?,
+                }
+            }
+            "bowner" => {
+                let mut cursor = Cursor::new(args);
+                let ret = Condition::BallOwner {
+                    team: cursor.parse()?,
+                    unums: cursor.unum_set()?,
+                };
+                return cursor.is_done().then_some(ret);
+            }
+            "playm" => {
+                let [mode] = args else {
+                    return None;
+                };
+                Condition::PlayMode {
+                    mode: mode.atom()?.to_string(),
+                }
+            }
+            "and" => Condition::And { conditions: list()? },
+            "or" => Condition::Or { conditions: list()? },
+            "not" => {
+                let [condition] = args else {
+                    return None;
+                };
+                Condition::Not {
+                    condition: Box::new(Condition::from_sexpr(condition)?),
+                }
+            }
+            "unum" => {
+                let mut cursor = Cursor::new(args);
+                let ret = Condition::Unum {
+                    var: cursor.next()?.atom()?.to_string(),
+                    unums: cursor.unum_set()?,
+                };
+                return cursor.is_done().then_some(ret);
+            }
+            var => {
+                let [op, value] = args else {
+                    return None;
+                };
+                Condition::Compare {
+                    var: CompareVar::decode(var)?,
+                    op: CompareOp::decode(op.atom()?)?,
+                    value: value.parse_atom()?,
+                }
+            }
+        };
+
+        Some(ret)
+    }
+}
+
+impl Display for Condition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let list = |f: &mut Formatter<'_>, name: &str, conditions: &[Condition]| {
+            write!(f, "({name}")?;
+            for condition in conditions {
+                write!(f, " {condition}")?;
+            }
+            f.write_str(")")
+        };
+
+        match self {
+            Condition::True => f.write_str("(true)"),
+            Condition::False => f.write_str("(false)"),
+            Condition::PlayerPos {
+                team,
+                unums,
+                min,
+                max,
+                region,
+            } => write!(f, "(ppos {team} {unums} {min} {max} {region})"),
+            Condition::BallPos { region } => write!(f, "(bpos {region})"),
+            Condition::BallOwner { team, unums } => write!(f, "(bowner {team} {unums})"),
+            Condition::PlayMode { mode } => write!(f, "(playm {mode})"),
+            Condition::And { conditions } => list(f, "and", conditions),
+            Condition::Or { conditions } => list(f, "or", conditions),
+            Condition::Not { condition } => write!(f, "(not {condition})"),
+            Condition::Compare { var, op, value } => {
+                write!(f, "({} {} {value})", var.encode(), op.encode())
+            }
+            Condition::Unum { var, unums } => write!(f, "(unum {var} {unums})"),
+            Condition::Named { name } => write!(f, "\"{name}\""),
+        }
+    }
+}
@@ -0,0 +1,371 @@
+//! The standard coach language, sent by the online coach with `say` and heard
+//! by players as `(hear TIME online_coach_SIDE MESSAGE)`.
+//!
+//! Every type decodes from a parsed [`Sexpr`] with `from_sexpr` and encodes
+//! through `Display`.
+
+mod action;
+mod condition;
+mod region;
+mod rule;
+
+pub use action::{Action, BallMove, Directive};
+pub use condition::{CompareOp, CompareVar, Condition};
+pub use region::{Point, Region};
+pub use rule::{Activation, Definition, IdList, Rule};
+
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::{self, Sexpr};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Message {
+    /// `(info TOKEN...)`, what the coach believes will happen.
+    Info { tokens: Vec<Token> },
+    /// `(advice TOKEN...)`, what the coach wants to happen.
+    Advice { tokens: Vec<Token> },
+    /// `(define DEFINITION...)`
+    Define { definitions: Vec<Definition> },
+    /// `(rule ACTIVATION...)`
+    Rule { activations: Vec<Activation> },
+    /// `(delete IDS)`
+    Del { ids: IdList },
+    /// `(meta (ver N)...)`
+    Meta { versions: Vec<u32> },
+    /// `(freeform "TEXT")`
+    Freeform { text: String },
+}
+
+/// A token of `info` and `advice`, `(TTL CONDITION DIRECTIVE...)` or `(clear)`.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Token {
+    Rule {
+        ttl: u32,
+        condition: Condition,
+        directives: Vec<Directive>,
+    },
+    Clear,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Team {
+    Our,
+    Opp,
+}
+
+/// `{UNUM...}`, `{0}` stands for the whole team.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq, Eq)]
+#[serde(transparent)]
+pub struct UnumSet(pub Vec<u8>);
+
+impl Message {
+    pub fn freeform(text: impl Into<String>) -> Self {
+        Message::Freeform { text: text.into() }
+    }
+
+    pub fn parse(raw: &str) -> Option<Self> {
+        Self::from_sexpr(&sexpr::parse(raw).ok()?)
+    }
+
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        let args = expr.args();
+        let ret = match expr.head()? {
+            "info" => Message::Info {
+                tokens: args.iter().map(Token::from_sexpr).collect::<Option<_>>()?,
+            },
+            "advice" => Message::Advice {
+                tokens: args.iter().map(Token::from_sexpr).collect::<Option<_>>()?,
+            },
+            "define" => Message::Define {
+                definitions: args
+                    .iter()
+                    .map(Definition::from_sexpr)
+                    .collect::<Option<_>>()?,
+            },
+            "rule" => Message::Rule {
+                activations: args
+                    .iter()
+                    .map(Activation::from_sexpr)
+                    .collect::<Option<_>>()?,
+            },
+            "delete" => {
+                let [ids] = args else {
+                    return None;
+                };
+                Message::Del {
+                    ids: IdList::from_sexpr(ids)?,
+                }
+            }
+            "meta" => Message::Meta {
+                versions: args
+                    .iter()
+                    .map(|token| match token.head()? {
+                        "ver" => token.args().first()?.parse_atom(),
+                        _ => None,
+                    })
+                    .collect::<Option<_>>()?,
+            },
+            "freeform" => {
+                let [text] = args else {
+                    return None;
+                };
+                Message::Freeform {
+                    text: text.text()?.to_string(),
+                }
+            }
+            _ => return None,
+        };
+
+        Some(ret)
+    }
+}
+
+impl Token {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        if expr.head() == Some("clear") {
+            return Some(Token::Clear);
+        }
+
+        let [ttl, condition, directives @ ..] = expr.list()? else {
+            return None;
+        };
+        if directives.is_empty() {
+            return None;
+        }
+        Some(Token::Rule {
+            ttl: ttl.parse_atom()?,
+            condition: Condition::from_sexpr(condition)?,
+            directives: directives
+                .iter()
+                .map(Directive::from_sexpr)
+                .collect::<Option<_>>()?,
+        })
+    }
+}
+
+impl Team {
+    pub fn encode(self) -> &'static str {
+        match self {
+            Team::Our => "our",
+            Team::Opp => "opp",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "our" => Some(Team::Our),
+            "opp" => Some(Team::Opp),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for Team {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <Team as FromStr>::Err> {
+        Self::decode(s).ok_or(())
+    }
+}
+
+impl UnumSet {
+    pub fn all() -> Self {
+        UnumSet(vec![0])
+    }
+}
+
+impl Display for Message {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        fn list<T: Display>(f: &mut Formatter<'_>, name: &str, items: &[T]) -> std::fmt::Result {
+            write!(f, "({name}")?;
+            for item in items {
+                write!(f, " {item}")?;
+            }
+            f.write_str(")")
+        }
+
+        match self {
+            Message::Info { tokens } => list(f, "info", tokens),
+            Message::Advice { tokens } => list(f, "advice", tokens),
+            Message::Define { definitions } => list(f, "define", definitions),
+            Message::Rule { activations } => list(f, "rule", activations),
+            Message::Del { ids } => write!(f, "(delete {ids})"),
+            Message::Meta { versions } => {
+                f.write_str("(meta")?;
+                for version in versions {
+                    write!(f, " (ver {version})")?;
+                }
+                f.write_str(")")
+            }
+            Message::Freeform { text } => write!(f, "(freeform \"{text}\")"),
+        }
+    }
+}
+
+impl Display for Token {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Token::Rule {
+                ttl,
+                condition,
+                directives,
+            } => {
+                write!(f, "({ttl} {condition}")?;
+                for directive in directives {
+                    write!(f, " {directive}")?;
+                }
+                f.write_str(")")
+            }
+            Token::Clear => f.write_str("(clear)"),
+        }
+    }
+}
+
+impl Display for Team {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.encode())
+    }
+}
+
+impl Display for UnumSet {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        f.write_str("{")?;
+        for (idx, unum) in self.0.iter().enumerate() {
+            if idx > 0 {
+                f.write_str(" ")?;
+            }
+            write!(f, "{unum}")?;
+        }
+        f.write_str("}")
+    }
+}
+
+/// Walks the arguments of a CLang list.
+///
+/// Braced sets such as `{1 2 3}` are not s-expressions, the parser hands them
+/// over as the atoms `{1`, `2` and `3}`, so they are stitched back together here.
+struct Cursor<'s, 'a> {
+    items: &'s [Sexpr<'a>],
+    pos: usize,
+}
+
+impl<'s, 'a> Cursor<'s, 'a> {
+    fn new(items: &'s [Sexpr<'a>]) -> Self {
+        Self { items, pos: 0 }
+    }
+
+    fn next(&mut self) -> Option<&'s Sexpr<'a>> {
+        let item = self.items.get(self.pos)?;
+        self.pos += 1;
+        Some(item)
+    }
+
+    fn parse<T: FromStr>(&mut self) -> Option<T> {
+        self.next()?.parse_atom()
+    }
+
+    fn set(&mut self) -> Option<Vec<&'a str>> {
+        let mut rest = self.next()?.atom()?.strip_prefix('{')?;
+        let mut items = Vec::new();
+        loop {
+            if let Some(last) = rest.strip_suffix('}') {
+                items.extend(last.split_whitespace());
+                return Some(items);
+            }
+            items.extend(rest.split_whitespace());
+            rest = self.next()?.atom()?;
+        }
+    }
+
+    fn unum_set(&mut self) -> Option<UnumSet> {
+        let unums = self
+            .set()?
+            .into_iter()
+            .map(|unum| unum.parse().ok())
+            .collect::<Option<_>>()?;
+        Some(UnumSet(unums))
+    }
+
+    fn rest(&self) -> &'s [Sexpr<'a>] {
+        &self.items[self.pos..]
+    }
+
+    fn is_done(&self) -> bool {
+        self.pos == self.items.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn roundtrip(raw: &str) -> Message {
+        let msg = Message::parse(raw).unwrap_or_else(|| panic!("failed to parse {raw}"));
+        assert_eq!(msg.to_string(), raw);
+        msg
+    }
+
+    #[test]
+    fn test_info_advice() {
+        let msg = roundtrip(
+            "(advice (6000 (and (playm play_on) (bowner our {7 9})) \
+             (do our {2 3} (pos (rec (pt -30 -20) (pt -10 20))) (mark opp {10}))))",
+        );
+        let Message::Advice { tokens } = msg else {
+            panic!("expected advice");
+        };
+        let Token::Rule { ttl, directives, .. } = &tokens[0] else {
+            panic!("expected a rule token");
+        };
+        assert_eq!(*ttl, 6000);
+        assert_eq!(
+            directives[0],
+            Directive::Do {
+                positive: true,
+                team: Team::Our,
+                unums: UnumSet(vec![2, 3]),
+                actions: vec![
+                    Action::Pos {
+                        region: Region::Rec {
+                            points: [Point::abs(-30.0, -20.0), Point::abs(-10.0, 20.0)]
+                        }
+                    },
+                    Action::Mark { team: Team::Opp, unums: UnumSet(vec![10]) },
+                ],
+            }
+        );
+
+        roundtrip("(info (100 (time >= 3000) (dont opp {0} (pass {5}) (bto (pt ball) {pass dribble}))) (clear))");
+    }
+
+    #[test]
+    fn test_define_rule_delete() {
+        roundtrip(
+            "(define (definer \"box\" (arc (pt our 1) 0 10 0 360)) \
+             (definec \"ahead\" (not (ppos opp {0} 1 11 \"box\"))) \
+             (definerule defend direc ((true) (do our {0} (home (reg (null) \"box\"))))))",
+        );
+        roundtrip("(rule (on defend) (off (a b)))");
+        roundtrip("(delete all)");
+        roundtrip("(meta (ver 8))");
+        roundtrip("(freeform \"keep the line\")");
+    }
+
+    #[test]
+    fn test_loose_braces() {
+        let msg = Message::parse("(advice (10 (true) (do our { 1 2 } (shoot))))").unwrap();
+        assert_eq!(msg.to_string(), "(advice (10 (true) (do our {1 2} (shoot))))");
+    }
+
+    #[test]
+    fn test_rejects() {
+        assert!(Message::parse("(advice (10 (true)))").is_none());
+        assert!(Message::parse("(advice (10 (true) (do our {1 2 (shoot))))").is_none());
+        assert!(Message::parse("(rule (maybe all))").is_none());
+        assert!(Message::parse("(hello)").is_none());
+    }
+}
@@ -0,0 +1,159 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::Team;
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Point {
+    /// `(pt X Y)`
+    Abs { x: f64, y: f64 },
+    /// `(pt ball)`
+    Ball,
+    /// `(pt TEAM UNUM)`
+    Player { team: Team, unum: u8 },
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Region {
+    /// `(null)`
+    Null,
+    Point { point: Point },
+    /// `(quad P P P P)`
+    Quad { points: [Point; 4] },
+    /// `(arc CENTER R_IN R_OUT START SPAN)`
+    Arc {
+        center: Point,
+        radius_in: f64,
+        radius_out: f64,
+        start: f64,
+        span: f64,
+    },
+    /// `(reg REGION...)`
+    Union { regions: Vec<Region> },
+    /// `(tri P P P)`
+    Tri { points: [Point; 3] },
+    /// `(rec P P)`
+    Rec { points: [Point; 2] },
+    /// A region defined earlier with `definer`.
+    Named { name: String },
+}
+
+impl Point {
+    pub fn abs(x: f64, y: f64) -> Self {
+        Point::Abs { x, y }
+    }
+
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        if expr.head()? != "pt" {
+            return None;
+        }
+        match expr.args() {
+            [ball] if ball.atom()? == "ball" => Some(Point::Ball),
+            [x, y] if x.atom()? == "our" || x.atom()? == "opp" => Some(Point::Player {
+                team: x.parse_atom()?,
+                unum: y.parse_atom()?,
+            }),
+            [x, y] => Some(Point::Abs {
+                x: x.parse_atom()?,
+                y: y.parse_atom()?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Region {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        if let Sexpr::Str(name) = expr {
+            return Some(Region::Named {
+                name: name.to_string(),
+            });
+        }
+
+        let args = expr.args();
+        let points = || args.iter().map(Point::from_sexpr).collect::<Option<Vec<_>>>();
+
+        match expr.head()? {
+            "null" => Some(Region::Null),
+            "pt" => Point::from_sexpr(expr).map(|point| Region::Point { point }),
+            "quad" => Some(Region::Quad {
+                points: points()?.try_into().ok()?,
+            }),
+            "tri" => Some(Region::Tri {
+                points: points()?.try_into().ok()?,
+            }),
+            "rec" => Some(Region::Rec {
+                points: points()?.try_into().ok()?,
+            }),
+            "arc" => {
+                let [center, radius_in, radius_out, start, span] = args else {
+                    return None;
+                };
+                Some(Region::Arc {
+                    center: Point::from_sexpr(center)?,
+                    radius_in: radius_in.parse_atom()?,
+                    radius_out: radius_out.parse_atom()?,
+                    start: start.parse_atom()?,
+                    span: span.parse_atom()?,
+                })
+            }
+            "reg" => Some(Region::Union {
+                regions: args
+                    .iter()
+                    .map(Region::from_sexpr)
+                    .collect::<Option<_>>()?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+impl Display for Point {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Point::Abs { x, y } => write!(f, "(pt {x} {y})"),
+            Point::Ball => f.write_str("(pt ball)"),
+            Point::Player { team, unum } => write!(f, "(pt {team} {unum})"),
+        }
+    }
+}
+
+impl Display for Region {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let points = |f: &mut Formatter<'_>, name: &str, points: &[Point]| {
+            write!(f, "({name}")?;
+            for point in points {
+                write!(f, " {point}")?;
+            }
+            f.write_str(")")
+        };
+
+        match self {
+            Region::Null => f.write_str("(null)"),
+            Region::Point { point } => write!(f, "{point}"),
+            Region::Quad { points: p } => points(f, "quad", p),
+            Region::Tri { points: p } => points(f, "tri", p),
+            Region::Rec { points: p } => points(f, "rec", p),
+            Region::Arc {
+                center,
+                radius_in,
+                radius_out,
+                start,
+                span,
+            } => write!(f, "(arc {center} {radius_in} {radius_out} {start} {span})"),
+            Region::Union { regions } => {
+                f.write_str("(reg")?;
+                for region in regions {
+                    write!(f, " {region}")?;
+                }
+                f.write_str(")")
+            }
+            Region::Named { name } => write!(f, "\"{name}\""),
+        }
+    }
+}
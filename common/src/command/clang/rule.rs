@@ -0,0 +1,218 @@
+use std::fmt::{Display, Formatter};
+
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Action, Condition, Directive, Region};
+
+/// Rule ids for `rule` and `delete`, `all` or one or more names.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "ids", rename_all = "snake_case")]
+pub enum IdList {
+    All,
+    Ids(Vec<String>),
+}
+
+/// `(on IDS)` or `(off IDS)` inside a `rule` message.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Activation {
+    pub on: bool,
+    pub ids: IdList,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Rule {
+    /// `(CONDITION DIRECTIVE...)`
+    Simple {
+        condition: Condition,
+        directives: Vec<Directive>,
+    },
+    /// `(CONDITION RULE...)`
+    Nested { condition: Condition, rules: Vec<Rule> },
+    /// Rules defined earlier with `definerule`.
+    Ids { ids: IdList },
+}
+
+/// One token of a `define` message.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Definition {
+    /// `(definec "NAME" CONDITION)`
+    Condition { name: String, condition: Condition },
+    /// `(defined "NAME" DIRECTIVE)`
+    Directive { name: String, directive: Directive },
+    /// `(definer "NAME" REGION)`
+    Region { name: String, region: Region },
+    /// `(definea "NAME" ACTION)`
+    Action { name: String, action: Action },
+    /// `(definerule NAME model|direc RULE)`, model rules only describe what
+    /// the coach expects and are not followed.
+    Rule { name: String, model: bool, rule: Rule },
+}
+
+impl IdList {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        match expr {
+            Sexpr::Atom("all") => Some(IdList::All),
+            Sexpr::Atom(id) => Some(IdList::Ids(vec![id.to_string()])),
+            Sexpr::List(ids) if !ids.is_empty() => Some(IdList::Ids(
+                ids.iter()
+                    .map(|id| id.atom().map(str::to_string))
+                    .collect::<Option<_>>()?,
+            )),
+            _ => None,
+        }
+    }
+}
+
+impl Activation {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        let on = match expr.head()? {
+            "on" => true,
+            "off" => false,
+            _ => return None,
+        };
+        let [ids] = expr.args() else {
+            return None;
+        };
+        Some(Activation {
+            on,
+            ids: IdList::from_sexpr(ids)?,
+        })
+    }
+}
+
+impl Rule {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        let Some([condition, rest @ ..]) = expr.list() else {
+            return IdList::from_sexpr(expr).map(|ids| Rule::Ids { ids });
+        };
+        let Some(condition) = Condition::from_sexpr(condition) else {
+            return IdList::from_sexpr(expr).map(|ids| Rule::Ids { ids });
+        };
+        if rest.is_empty() {
+            return None;
+        }
+
+        if let Some(directives) = rest.iter().map(Directive::from_sexpr).collect() {
+            return Some(Rule::Simple {
+                condition,
+                directives,
+            });
+        }
+        Some(Rule::Nested {
+            condition,
+            rules: rest.iter().map(Rule::from_sexpr).collect::<Option<_>>()?,
+        })
+    }
+}
+
+impl Definition {
+    pub fn from_sexpr(expr: &Sexpr) -> Option<Self> {
+        let args = expr.args();
+        if expr.head()? == "definerule" {
+            let [name, kind, rule] = args else {
+                return None;
+            };
+            let model = match kind.atom()? {
+                "model" => true,
+                "direc" => false,
+                _ => return None,
+            };
+            return Some(Definition::Rule {
+                name: name.text()?.to_string(),
+                model,
+                rule: Rule::from_sexpr(rule)?,
+            });
+        }
+
+        let [name, body] = args else {
+            return None;
+        };
+        let name = name.text()?.to_string();
+        let ret = match expr.head()? {
+            "definec" => Definition::Condition {
+                name,
+                condition: Condition::from_sexpr(body)?,
+            },
+            "defined" => Definition::Directive {
+                name,
+                directive: Directive::from_sexpr(body)?,
+            },
+            "definer" => Definition::Region {
+                name,
+                region: Region::from_sexpr(body)?,
+            },
+            "definea" => Definition::Action {
+                name,
+                action: Action::from_sexpr(body)?,
+            },
+            _ => return None,
+        };
+
+        Some(ret)
+    }
+}
+
+impl Display for IdList {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            IdList::All => f.write_str("all"),
+            IdList::Ids(ids) if ids.len() == 1 => f.write_str(&ids[0]),
+            IdList::Ids(ids) => write!(f, "({})", ids.join(" ")),
+        }
+    }
+}
+
+impl Display for Activation {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let head = if self.on { "on" } else { "off" };
+        write!(f, "({head} {})", self.ids)
+    }
+}
+
+impl Display for Rule {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Rule::Simple {
+                condition,
+                directives,
+            } => {
+                write!(f, "({condition}")?;
+                for directive in directives {
+                    write!(f, " {directive}")?;
+                }
+                f.write_str(")")
+            }
+            Rule::Nested { condition, rules } => {
+                write!(f, "({condition}")?;
+                for rule in rules {
+                    write!(f, " {rule}")?;
+                }
+                f.write_str(")")
+            }
+            Rule::Ids { ids } => write!(f, "{ids}"),
+        }
+    }
+}
+
+impl Display for Definition {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Definition::Condition { name, condition } => {
+                write!(f, "(definec \"{name}\" {condition})")
+            }
+            Definition::Directive { name, directive } => {
+                write!(f, "(defined \"{name}\" {directive})")
+            }
+            Definition::Region { name, region } => write!(f, "(definer \"{name}\" {region})"),
+            Definition::Action { name, action } => write!(f, "(definea \"{name}\" {action})"),
+            Definition::Rule { name, model, rule } => {
+                let kind = if *model { "model" } else { "direc" };
+                write!(f, "(definerule {name} {kind} {rule})")
+            }
+        }
+    }
+}
@@ -0,0 +1,86 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{CoachCommand, Command};
+
+/// `(change_player_type UNUM PLAYER_TYPE)`, answered with
+/// `(ok change_player_type UNUM PLAYER_TYPE)`.
+///
+/// Refusals come back as `(warning ...)`, they are reported as errors.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandChangePlayerType {
+    pub unum: u8,
+    pub player_type: u8,
+}
+
+impl Command for CommandChangePlayerType {
+    type Kind = CoachCommand;
+    type Ok = CommandChangePlayerTypeOk;
+    type Error = CommandChangePlayerTypeError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::ChangePlayerType
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(change_player_type {} {})", self.unum, self.player_type)
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        let [unum, player_type] = tokens else {
+            return None;
+        };
+        Some(CommandChangePlayerTypeOk {
+            unum: unum.parse_atom()?,
+            player_type: player_type.parse_atom()?,
+        })
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandChangePlayerTypeOk {
+    pub unum: u8,
+    pub player_type: u8,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandChangePlayerTypeError {
+    #[error("No player with this uniform number.")]
+    NoSuchPlayer,
+    #[error("The team has no substitutions left.")]
+    NoSubsLeft,
+    #[error("Too many players of that type are on the field.")]
+    MaxOfThatType,
+    #[error("Substitutions are not allowed during play_on.")]
+    CannotSubWhilePlayOn,
+    #[error("The goalie must keep the default type.")]
+    CannotChangeGoalie,
+    #[error("The UNUM or PLAYER_TYPE argument was not valid.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandChangePlayerTypeError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandChangePlayerTypeError as FromStr>::Err> {
+        match s {
+            "no_such_player" => Ok(Self::NoSuchPlayer),
+            "no_subs_left" => Ok(Self::NoSubsLeft),
+            "max_of_that_type_on_field" => Ok(Self::MaxOfThatType),
+            "cannot_sub_while_playon" => Ok(Self::CannotSubWhilePlayOn),
+            "cannot_change_goalie" => Ok(Self::CannotChangeGoalie),
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
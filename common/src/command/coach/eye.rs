@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::command::trainer::eye::CommandEyeOk;
+use crate::sexpr::Sexpr;
+use crate::types::EyeMode;
+
+use super::{CoachCommand, Command};
+
+/// `(eye on|off)`, with `on` a `see_global` frame is sent every cycle.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandEye {
+    pub mode: EyeMode,
+}
+
+impl Command for CommandEye {
+    type Kind = CoachCommand;
+    type Ok = CommandEyeOk;
+    type Error = CommandEyeError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Eye
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(eye {})", self.mode.encode())
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        let [mode] = tokens else {
+            return None;
+        };
+        mode.parse_atom().map(|mode| CommandEyeOk { mode })
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandEyeError {
+    #[error("MODE did not match on or off.")]
+    IllegalMode,
+    #[error("The MODE argument was omitted.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandEyeError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandEyeError as FromStr>::Err> {
+        match s {
+            "illegal_mode" => Ok(Self::IllegalMode),
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,76 @@
+use std::str::FromStr;
+
+use arcstr::ArcStr;
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+use crate::types::Side;
+
+use super::{CoachCommand, Command};
+
+/// `(init TEAM_NAME [(version VERSION)])`, answered with `(init SIDE ok)`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandInit {
+    pub team_name: String,
+    pub version: Option<u8>,
+}
+
+impl Command for CommandInit {
+    type Kind = CoachCommand;
+    type Ok = CommandInitOk;
+    type Error = CommandInitError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Init
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.version {
+            Some(version) => arcstr::format!("(init {} (version {version}))", self.team_name),
+            None => arcstr::format!("(init {})", self.team_name),
+        }
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        // (init SIDE ok)
+        let [side, ok] = tokens else {
+            return None;
+        };
+        if ok.atom()? != "ok" {
+            return None;
+        }
+        let side = side.parse_atom()?;
+        Some(CommandInitOk { side })
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandInitOk {
+    pub side: Side,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandInitError {
+    #[error("No team with this name, or it already has a coach.")]
+    NoSuchTeamOrAlreadyHaveCoach,
+    #[error("The TEAM_NAME was omitted.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandInitError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandInitError as FromStr>::Err> {
+        match s {
+            "no_such_team_or_already_have_coach" => Ok(Self::NoSuchTeamOrAlreadyHaveCoach),
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+use crate::types::WorldSnapshot;
+
+use super::{CoachCommand, Command};
+
+/// `(look)`, answered with `(ok look TIME OBJECT...)` like the trainer's.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandLook;
+
+impl Command for CommandLook {
+    type Kind = CoachCommand;
+    type Ok = CommandLookOk;
+    type Error = CommandLookError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Look
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(look)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        WorldSnapshot::from_tokens(tokens)
+    }
+
+    // never error
+}
+
+pub type CommandLookOk = WorldSnapshot;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandLookError {}
+
+impl FromStr for CommandLookError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandLookError as FromStr>::Err> {
+        Err(())
+    }
+}
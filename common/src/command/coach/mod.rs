@@ -0,0 +1,157 @@
+pub mod change_player_type;
+pub mod eye;
+pub mod init;
+pub mod look;
+pub mod say;
+pub mod team_graphic;
+pub mod team_names;
+
+pub use change_player_type::CommandChangePlayerType as ChangePlayerType;
+pub use eye::CommandEye as Eye;
+pub use init::CommandInit as Init;
+pub use look::CommandLook as Look;
+pub use say::CommandSay as Say;
+pub use team_graphic::CommandTeamGraphic as TeamGraphic;
+pub use team_names::CommandTeamNames as TeamNames;
+
+use arcstr::{ArcStr, literal};
+use std::any::Any;
+
+use super::{Command, CommandAny};
+use crate::sexpr::Sexpr;
+
+/// Online coach commands, one coach per team on `olcoach_port`.
+#[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
+pub enum CoachCommand {
+    Init,
+    ChangePlayerType,
+    Say,
+    TeamGraphic,
+    Eye,
+    Look,
+    TeamNames,
+}
+
+impl CommandAny for CoachCommand {
+    fn encode(&self) -> ArcStr {
+        match self {
+            CoachCommand::Init => literal!("init"),
+            CoachCommand::ChangePlayerType => literal!("change_player_type"),
+            CoachCommand::Say => literal!("say"),
+            CoachCommand::TeamGraphic => literal!("team_graphic"),
+            CoachCommand::Eye => literal!("eye"),
+            CoachCommand::Look => literal!("look"),
+            CoachCommand::TeamNames => literal!("team_names"),
+        }
+    }
+
+    fn decode(s: &str) -> Option<Self> {
+        match s {
+            "init" => Some(CoachCommand::Init),
+            "change_player_type" => Some(CoachCommand::ChangePlayerType),
+            "say" => Some(CoachCommand::Say),
+            "team_graphic" => Some(CoachCommand::TeamGraphic),
+            "eye" => Some(CoachCommand::Eye),
+            "look" => Some(CoachCommand::Look),
+            "team_names" => Some(CoachCommand::TeamNames),
+            _ => None,
+        }
+    }
+
+    fn parse_ret_ok(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>> {
+        match self {
+            CoachCommand::Init => {
+                Init::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::ChangePlayerType => {
+                ChangePlayerType::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Say => {
+                Say::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamGraphic => {
+                TeamGraphic::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Eye => {
+                Eye::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Look => {
+                Look::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamNames => {
+                TeamNames::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+        }
+    }
+
+    fn parse_ret_err(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>> {
+        match self {
+            CoachCommand::Init => {
+                Init::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::ChangePlayerType => {
+                ChangePlayerType::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Say => {
+                Say::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamGraphic => {
+                TeamGraphic::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Eye => {
+                Eye::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::Look => {
+                Look::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            CoachCommand::TeamNames => {
+                TeamNames::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sexpr;
+    use crate::types::{EyeMode, Side};
+
+    #[test]
+    fn test_encode() {
+        let init = Init { team_name: "HELIOS".to_string(), version: Some(19) };
+        assert_eq!(init.encode(), "(init HELIOS (version 19))");
+
+        let change = ChangePlayerType { unum: 9, player_type: 4 };
+        assert_eq!(change.encode(), "(change_player_type 9 4)");
+
+        let say = Say::freeform("keep the line");
+        assert_eq!(say.encode(), "(say (freeform \"keep the line\"))");
+
+        let graphic = TeamGraphic { x: 0, y: 1, xpm: vec!["8 8 1 1".to_string(), "a c #FF0000".to_string()] };
+        assert_eq!(graphic.encode(), "(team_graphic (0 1 \"8 8 1 1\" \"a c #FF0000\"))");
+
+        assert_eq!(Eye { mode: EyeMode::On }.encode(), "(eye on)");
+    }
+
+    #[test]
+    fn test_parse_ret() {
+        let msg = sexpr::parse("(init r ok)").unwrap();
+        assert_eq!(Init::parse_ret_ok(msg.args()).unwrap().side, Side::RIGHT);
+
+        let msg = sexpr::parse("(ok change_player_type 9 4)").unwrap();
+        let ok = ChangePlayerType::parse_ret_ok(&msg.args()[1..]).unwrap();
+        assert_eq!((ok.unum, ok.player_type), (9, 4));
+
+        let msg = sexpr::parse("(warning no_subs_left)").unwrap();
+        assert!(matches!(
+            ChangePlayerType::parse_ret_err(msg.args()),
+            Some(change_player_type::CommandChangePlayerTypeError::NoSubsLeft)
+        ));
+
+        let msg = sexpr::parse("(ok team_names (team l HELIOS))").unwrap();
+        let ok = TeamNames::parse_ret_ok(&msg.args()[1..]).unwrap();
+        assert_eq!(ok.left.as_deref(), Some("HELIOS"));
+        assert!(ok.right.is_none());
+    }
+}
@@ -0,0 +1,77 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::command::clang::Message;
+use crate::sexpr::Sexpr;
+
+use super::{CoachCommand, Command};
+
+/// `(say MESSAGE)` with a CLang message, answered with `(ok say)`.
+///
+/// Freeform messages are only delivered during non-play-on modes unless the
+/// server allows more.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandSay {
+    pub message: Message,
+}
+
+impl CommandSay {
+    pub fn new(message: Message) -> Self {
+        Self { message }
+    }
+
+    pub fn freeform(text: impl Into<String>) -> Self {
+        Self::new(Message::freeform(text))
+    }
+}
+
+impl Command for CommandSay {
+    type Kind = CoachCommand;
+    type Ok = CommandSayOk;
+    type Error = CommandSayError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::Say
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(say {})", self.message)
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        tokens.is_empty().then_some(())
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandSayOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandSayError {
+    #[error("The freeform message budget is used up.")]
+    SaidTooManyFreeformMessages,
+    #[error("The message is not valid CLang.")]
+    CouldNotParseSay,
+    #[error("The MESSAGE was omitted.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandSayError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandSayError as FromStr>::Err> {
+        match s {
+            "said_too_many_freeform_messages" => Ok(Self::SaidTooManyFreeformMessages),
+            "could_not_parse_say" => Ok(Self::CouldNotParseSay),
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
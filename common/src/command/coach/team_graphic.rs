@@ -0,0 +1,75 @@
+use std::str::FromStr;
+
+use arcstr::ArcStr;
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{CoachCommand, Command};
+
+/// `(team_graphic (X Y "XPM LINE"...))`, one 8x8 tile of the team logo,
+/// answered with `(ok team_graphic X Y)`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTeamGraphic {
+    pub x: u8,
+    pub y: u8,
+    pub xpm: Vec<String>,
+}
+
+impl Command for CommandTeamGraphic {
+    type Kind = CoachCommand;
+    type Ok = CommandTeamGraphicOk;
+    type Error = CommandTeamGraphicError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::TeamGraphic
+    }
+
+    fn encode(&self) -> ArcStr {
+        let mut ret = std::format!("(team_graphic ({} {}", self.x, self.y);
+        for line in &self.xpm {
+            ret += &std::format!(" \"{line}\"");
+        }
+        ret += "))";
+        ret.into()
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        let [x, y] = tokens else {
+            return None;
+        };
+        Some(CommandTeamGraphicOk {
+            x: x.parse_atom()?,
+            y: y.parse_atom()?,
+        })
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTeamGraphicOk {
+    pub x: u8,
+    pub y: u8,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTeamGraphicError {
+    #[error("The tile position or XPM data was not valid.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTeamGraphicError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTeamGraphicError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,45 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use crate::command::trainer::TeamNames as TrainerTeamNames;
+use crate::sexpr::Sexpr;
+
+use super::{CoachCommand, Command};
+
+pub use crate::command::trainer::team_names::CommandTeamNamesOk;
+
+/// `(team_names)`, answered with `(ok team_names [(team l NAME)] [(team r NAME)])`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTeamNames;
+
+impl Command for CommandTeamNames {
+    type Kind = CoachCommand;
+    type Ok = CommandTeamNamesOk;
+    type Error = CommandTeamNamesError;
+
+    fn kind(&self) -> Self::Kind {
+        CoachCommand::TeamNames
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(team_names)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        TrainerTeamNames::parse_ret_ok(tokens)
+    }
+
+    // never error
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTeamNamesError {}
+
+impl FromStr for CommandTeamNamesError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandTeamNamesError as FromStr>::Err> {
+        Err(())
+    }
+}
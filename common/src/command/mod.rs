@@ -5,6 +5,10 @@ use std::error::Error;
 use std::fmt::Debug;
 use std::hash::Hash;
 
+use crate::sexpr::Sexpr;
+
+pub mod clang;
+pub mod coach;
 pub mod player;
 pub mod trainer;
 
@@ -15,13 +19,13 @@ pub trait Command: Debug {
 
     fn kind(&self) -> Self::Kind;
     fn encode(&self) -> ArcStr;
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok>
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok>
     where
         Self: Sized,
     {
         None // default never ok
     }
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error>
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error>
     where
         Self: Sized,
     {
@@ -34,8 +38,8 @@ pub trait CommandAny: Hash + Eq + Clone + Debug + Send + Sync + 'static {
     fn decode(s: &str) -> Option<Self>
     where
         Self: Sized;
-    fn parse_ret_ok(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>>;
-    fn parse_ret_err(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>>;
+    fn parse_ret_ok(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>>;
+    fn parse_ret_err(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>>;
 }
 
 pub trait CommandOk: Serialize + Debug + Send + 'static {}
@@ -0,0 +1,81 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format, literal};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(attentionto TEAM UNUM)` or `(attentionto off)`. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandAttentionTo {
+    #[serde(default)]
+    pub target: Option<AttentionTarget>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum AttentionTeam {
+    Our,
+    Opp,
+}
+
+impl AttentionTeam {
+    pub fn encode(self) -> &'static str {
+        match self {
+            AttentionTeam::Our => "our",
+            AttentionTeam::Opp => "opp",
+        }
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+pub struct AttentionTarget {
+    pub team: AttentionTeam,
+    pub unum: u8,
+}
+
+impl Command for CommandAttentionTo {
+    type Kind = PlayerCommand;
+    type Ok = CommandAttentionToOk;
+    type Error = CommandAttentionToError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::AttentionTo
+    }
+
+    fn encode(&self) -> ArcStr {
+        match &self.target {
+            Some(AttentionTarget { team, unum }) => {
+                format!("(attentionto {} {unum})", team.encode())
+            }
+            None => literal!("(attentionto off)"),
+        }
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandAttentionToOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandAttentionToError {
+    #[error("The TEAM or UNUM argument was not valid.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandAttentionToError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandAttentionToError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, PlayerCommand};
+
+/// `(bye)`, the server drops the player without replying.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandBye;
+
+impl Command for CommandBye {
+    type Kind = PlayerCommand;
+    type Ok = CommandByeOk;
+    type Error = CommandByeError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Bye
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(bye)")
+    }
+
+    // never ok, never error
+}
+
+pub type CommandByeOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandByeError {}
+
+impl FromStr for CommandByeError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandByeError as FromStr>::Err> {
+        Err(())
+    }
+}
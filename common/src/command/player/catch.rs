@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(catch DIRECTION)`, goalie only. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandCatch {
+    pub dir: f64,
+}
+
+impl Command for CommandCatch {
+    type Kind = PlayerCommand;
+    type Ok = CommandCatchOk;
+    type Error = CommandCatchError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Catch
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(catch {})", self.dir)
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandCatchOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandCatchError {
+    #[error("The DIRECTION argument was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandCatchError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandCatchError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,62 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+use crate::types::{ViewQuality, ViewWidth};
+
+use super::{Command, PlayerCommand};
+
+/// `(change_view WIDTH [QUALITY])`
+///
+/// Version 19 servers only know `high` quality, so it is usually left out.
+/// No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandChangeView {
+    pub width: ViewWidth,
+    #[serde(default)]
+    pub quality: Option<ViewQuality>,
+}
+
+impl Command for CommandChangeView {
+    type Kind = PlayerCommand;
+    type Ok = CommandChangeViewOk;
+    type Error = CommandChangeViewError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::ChangeView
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.quality {
+            Some(quality) => format!("(change_view {} {})", self.width.encode(), quality.encode()),
+            None => format!("(change_view {})", self.width.encode()),
+        }
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandChangeViewOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandChangeViewError {
+    #[error("The WIDTH or QUALITY argument was not valid.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandChangeViewError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandChangeViewError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,60 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(dash POWER [DIRECTION])`
+///
+/// Dashes without a direction go straight ahead. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandDash {
+    pub power: f64,
+    #[serde(default)]
+    pub dir: Option<f64>,
+}
+
+impl Command for CommandDash {
+    type Kind = PlayerCommand;
+    type Ok = CommandDashOk;
+    type Error = CommandDashError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Dash
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.dir {
+            Some(dir) => format!("(dash {} {dir})", self.power),
+            None => format!("(dash {})", self.power),
+        }
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandDashOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandDashError {
+    #[error("The POWER argument was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandDashError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandDashError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -1,9 +1,11 @@
 use std::str::FromStr;
 use crate::types;
 use super::{Command, PlayerCommand};
-use arcstr::{ArcStr, format, literal};
+use arcstr::ArcStr;
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+
 #[derive(Clone, Debug)]
 pub struct CommandInit {
     pub team_name: String,
@@ -46,25 +48,41 @@ impl Command for CommandInit {
         ret.into()
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
-        todo!()
-        // tokens.is_empty().then_some(())
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        // (init SIDE UNUM PLAY_MODE)
+        let [side, unum, play_mode] = tokens else {
+            return None;
+        };
+        Some(CommandInitOk {
+            side: side.parse_atom()?,
+            unum: unum.parse_atom()?,
+            play_mode: play_mode.parse_atom()?,
+        })
     }
 
-    // never error
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
 }
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandInitOk {
-    side: types::Side,
-    unum: u8,
-    play_mode: types::PlayMode,
+    pub side: types::Side,
+    pub unum: u8,
+    pub play_mode: types::PlayMode,
 }
 
 #[derive(thiserror::Error, Debug)]
 pub enum CommandInitError {
     #[error("no more team or player or goalie")]
-    NoMoreTeamOrPlayerOrGoalie
+    NoMoreTeamOrPlayerOrGoalie,
+    #[error("the server does not support the requested protocol version")]
+    IllegalClientVersion,
+    #[error("illegal command form")]
+    IllegalCommandForm,
 }
 
 impl FromStr for CommandInitError {
@@ -72,6 +90,8 @@ impl FromStr for CommandInitError {
     fn from_str(s: &str) -> Result<Self, <CommandInitError as FromStr>::Err> {
         match s {
             "no_more_team_or_player_or_goalie" => Ok(CommandInitError::NoMoreTeamOrPlayerOrGoalie),
+            "illegal_client_version" => Ok(CommandInitError::IllegalClientVersion),
+            "illegal_command_form" => Ok(CommandInitError::IllegalCommandForm),
             _ => Err(())
         }
     }
@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(kick POWER DIRECTION)`. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandKick {
+    pub power: f64,
+    pub dir: f64,
+}
+
+impl Command for CommandKick {
+    type Kind = PlayerCommand;
+    type Ok = CommandKickOk;
+    type Error = CommandKickError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Kick
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(kick {} {})", self.power, self.dir)
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandKickOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandKickError {
+    #[error("The POWER or DIRECTION argument was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandKickError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandKickError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
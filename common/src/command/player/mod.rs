@@ -1,44 +1,294 @@
+pub mod attention_to;
+pub mod bye;
+pub mod catch;
+pub mod change_view;
+pub mod dash;
+pub mod done;
 pub mod init;
+pub mod kick;
+pub mod r#move;
+pub mod point_to;
+pub mod reconnect;
+pub mod say;
+pub mod score;
+pub mod sense_body;
+pub mod tackle;
+pub mod turn;
+pub mod turn_neck;
 
+pub use attention_to::{CommandAttentionTo as AttentionTo, AttentionTarget, AttentionTeam};
+pub use bye::CommandBye as Bye;
+pub use catch::CommandCatch as Catch;
+pub use change_view::CommandChangeView as ChangeView;
+pub use dash::CommandDash as Dash;
+pub use done::CommandDone as Done;
 pub use init::CommandInit;
+pub use init::CommandInit as Init;
+pub use kick::CommandKick as Kick;
+pub use r#move::CommandMove as Move;
+pub use point_to::{CommandPointTo as PointTo, PointToTarget};
+pub use reconnect::CommandReconnect as Reconnect;
+pub use say::CommandSay as Say;
+pub use score::CommandScore as Score;
+pub use sense_body::CommandSenseBody as SenseBody;
+pub use tackle::CommandTackle as Tackle;
+pub use turn::CommandTurn as Turn;
+pub use turn_neck::CommandTurnNeck as TurnNeck;
 
 use arcstr::{ArcStr, literal};
 use std::any::Any;
 
 use super::{Command, CommandAny};
+use crate::sexpr::Sexpr;
 
+/// Player commands, encoded for protocol version 19.
+///
+/// Only `init`, `reconnect`, `score` and `sense_body` are answered by the
+/// server, send the others without waiting, see `RichClient::send`.
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
 pub enum PlayerCommand {
     Init,
+    Dash,
+    Turn,
+    TurnNeck,
+    Kick,
+    Tackle,
+    Catch,
+    Move,
+    Say,
+    ChangeView,
+    AttentionTo,
+    PointTo,
+    Score,
+    SenseBody,
+    Bye,
+    Done,
+    Reconnect,
 }
 
 impl CommandAny for PlayerCommand {
     fn encode(&self) -> ArcStr {
         match self {
             PlayerCommand::Init => literal!("init"),
+            PlayerCommand::Dash => literal!("dash"),
+            PlayerCommand::Turn => literal!("turn"),
+            PlayerCommand::TurnNeck => literal!("turn_neck"),
+            PlayerCommand::Kick => literal!("kick"),
+            PlayerCommand::Tackle => literal!("tackle"),
+            PlayerCommand::Catch => literal!("catch"),
+            PlayerCommand::Move => literal!("move"),
+            PlayerCommand::Say => literal!("say"),
+            PlayerCommand::ChangeView => literal!("change_view"),
+            PlayerCommand::AttentionTo => literal!("attentionto"),
+            PlayerCommand::PointTo => literal!("pointto"),
+            PlayerCommand::Score => literal!("score"),
+            PlayerCommand::SenseBody => literal!("sense_body"),
+            PlayerCommand::Bye => literal!("bye"),
+            PlayerCommand::Done => literal!("done"),
+            PlayerCommand::Reconnect => literal!("reconnect"),
         }
     }
 
     fn decode(s: &str) -> Option<Self> {
         match s {
             "init" => Some(PlayerCommand::Init),
+            "dash" => Some(PlayerCommand::Dash),
+            "turn" => Some(PlayerCommand::Turn),
+            "turn_neck" => Some(PlayerCommand::TurnNeck),
+            "kick" => Some(PlayerCommand::Kick),
+            "tackle" => Some(PlayerCommand::Tackle),
+            "catch" => Some(PlayerCommand::Catch),
+            "move" => Some(PlayerCommand::Move),
+            "say" => Some(PlayerCommand::Say),
+            "change_view" => Some(PlayerCommand::ChangeView),
+            "attentionto" => Some(PlayerCommand::AttentionTo),
+            "pointto" => Some(PlayerCommand::PointTo),
+            "score" => Some(PlayerCommand::Score),
+            "sense_body" => Some(PlayerCommand::SenseBody),
+            "bye" => Some(PlayerCommand::Bye),
+            "done" => Some(PlayerCommand::Done),
+            "reconnect" => Some(PlayerCommand::Reconnect),
             _ => None,
         }
     }
 
-    fn parse_ret_ok(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>> {
+    fn parse_ret_ok(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>> {
         match self {
             PlayerCommand::Init => {
-                CommandInit::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+                Init::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Dash => {
+                Dash::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Turn => {
+                Turn::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::TurnNeck => {
+                TurnNeck::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Kick => {
+                Kick::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Tackle => {
+                Tackle::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Catch => {
+                Catch::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Move => {
+                Move::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Say => {
+                Say::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::ChangeView => {
+                ChangeView::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::AttentionTo => {
+                AttentionTo::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::PointTo => {
+                PointTo::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Score => {
+                Score::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::SenseBody => {
+                SenseBody::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Bye => {
+                Bye::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Done => {
+                Done::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Reconnect => {
+                Reconnect::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
             }
         }
     }
 
-    fn parse_ret_err(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>> {
+    fn parse_ret_err(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>> {
         match self {
             PlayerCommand::Init => {
-                CommandInit::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+                Init::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Dash => {
+                Dash::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Turn => {
+                Turn::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::TurnNeck => {
+                TurnNeck::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Kick => {
+                Kick::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Tackle => {
+                Tackle::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Catch => {
+                Catch::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Move => {
+                Move::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Say => {
+                Say::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::ChangeView => {
+                ChangeView::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::AttentionTo => {
+                AttentionTo::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::PointTo => {
+                PointTo::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Score => {
+                Score::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::SenseBody => {
+                SenseBody::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Bye => {
+                Bye::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Done => {
+                Done::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
+            PlayerCommand::Reconnect => {
+                Reconnect::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
             }
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sexpr;
+    use crate::types::{PlayMode, Side, Vec2, ViewWidth};
+
+    #[test]
+    fn test_encode_body() {
+        assert_eq!(Dash { power: 100.0, dir: None }.encode(), "(dash 100)");
+        assert_eq!(Dash { power: 80.0, dir: Some(-45.0) }.encode(), "(dash 80 -45)");
+        assert_eq!(Turn { moment: 30.5 }.encode(), "(turn 30.5)");
+        assert_eq!(TurnNeck { angle: -90.0 }.encode(), "(turn_neck -90)");
+        assert_eq!(Kick { power: 50.0, dir: 10.0 }.encode(), "(kick 50 10)");
+        assert_eq!(Tackle { power_or_dir: 0.0, foul: true }.encode(), "(tackle 0 on)");
+        assert_eq!(Catch { dir: 15.0 }.encode(), "(catch 15)");
+        assert_eq!(Move { pos: Vec2::new(-10.0, 5.0) }.encode(), "(move -10 5)");
+    }
+
+    #[test]
+    fn test_encode_misc() {
+        let say = Say::new("pass me").unwrap();
+        assert_eq!(say.encode(), "(say \"pass me\")");
+        assert!(Say::new("say \"hi\"").is_err());
+        assert!(serde_json::from_str::<Say>(r#"{"message":"a \" b"}"#).is_err());
+
+        let view = ChangeView { width: ViewWidth::Wide, quality: None };
+        assert_eq!(view.encode(), "(change_view wide)");
+
+        let attention = AttentionTo {
+            target: Some(AttentionTarget { team: AttentionTeam::Our, unum: 10 }),
+        };
+        assert_eq!(attention.encode(), "(attentionto our 10)");
+        assert_eq!(AttentionTo { target: None }.encode(), "(attentionto off)");
+        assert_eq!(PointTo { target: None }.encode(), "(pointto off)");
+
+        let reconnect = Reconnect { team_name: "HELIOS".to_string(), unum: 3 };
+        assert_eq!(reconnect.encode(), "(reconnect HELIOS 3)");
+    }
+
+    #[test]
+    fn test_parse_init() {
+        let msg = sexpr::parse("(init r 7 before_kick_off)").unwrap();
+        let ok = CommandInit::parse_ret_ok(msg.args()).unwrap();
+        assert_eq!(ok.side, Side::RIGHT);
+        assert_eq!(ok.unum, 7);
+        assert_eq!(ok.play_mode, PlayMode::PM_BeforeKickOff);
+
+        let msg = sexpr::parse("(error no_more_team_or_player_or_goalie)").unwrap();
+        let kind = PlayerCommand::decode("init").unwrap();
+        assert!(kind.parse_ret_err(msg.args()).is_some());
+    }
+
+    #[test]
+    fn test_parse_replies() {
+        let msg = sexpr::parse("(score 3000 1 2)").unwrap();
+        let ok = Score::parse_ret_ok(msg.args()).unwrap();
+        assert_eq!((ok.time, ok.our, ok.their), (3000, 1, 2));
+
+        let msg = sexpr::parse("(reconnect l play_on)").unwrap();
+        let ok = Reconnect::parse_ret_ok(msg.args()).unwrap();
+        assert_eq!(ok.side, Side::LEFT);
+        assert_eq!(ok.play_mode, PlayMode::PM_PlayOn);
+
+        let msg = sexpr::parse("(sense_body 42 (view_mode high normal))").unwrap();
+        assert_eq!(SenseBody::parse_ret_ok(msg.args()).unwrap().time, 42);
+    }
+}
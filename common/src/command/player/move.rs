@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+use crate::types::Vec2;
+
+use super::{Command, PlayerCommand};
+
+/// `(move X Y)`, only honoured before kick-off and after goals. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandMove {
+    pub pos: Vec2,
+}
+
+impl Command for CommandMove {
+    type Kind = PlayerCommand;
+    type Ok = CommandMoveOk;
+    type Error = CommandMoveError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Move
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(move {} {})", self.pos.x, self.pos.y)
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandMoveOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandMoveError {
+    #[error("The position was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandMoveError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandMoveError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,63 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format, literal};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(pointto DIST DIR)` or `(pointto off)`. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandPointTo {
+    #[serde(default)]
+    pub target: Option<PointToTarget>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct PointToTarget {
+    pub dist: f64,
+    pub dir: f64,
+}
+
+impl Command for CommandPointTo {
+    type Kind = PlayerCommand;
+    type Ok = CommandPointToOk;
+    type Error = CommandPointToError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::PointTo
+    }
+
+    fn encode(&self) -> ArcStr {
+        match self.target {
+            Some(PointToTarget { dist, dir }) => format!("(pointto {dist} {dir})"),
+            None => literal!("(pointto off)"),
+        }
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandPointToOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandPointToError {
+    #[error("The DIST or DIR argument was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandPointToError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandPointToError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,72 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+use crate::types::{PlayMode, Side};
+
+use super::{Command, PlayerCommand};
+
+/// `(reconnect TEAM_NAME UNUM)`, answered with `(reconnect SIDE PLAY_MODE)`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandReconnect {
+    pub team_name: String,
+    pub unum: u8,
+}
+
+impl Command for CommandReconnect {
+    type Kind = PlayerCommand;
+    type Ok = CommandReconnectOk;
+    type Error = CommandReconnectError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Reconnect
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(reconnect {} {})", self.team_name, self.unum)
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        let [side, play_mode] = tokens else {
+            return None;
+        };
+        Some(CommandReconnectOk {
+            side: side.parse_atom()?,
+            play_mode: play_mode.parse_atom()?,
+        })
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandReconnectOk {
+    pub side: Side,
+    pub play_mode: PlayMode,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandReconnectError {
+    #[error("No player with this team and uniform number to reconnect to.")]
+    NoSuchPlayer,
+    #[error("The TEAM_NAME or UNUM argument was not valid.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandReconnectError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandReconnectError as FromStr>::Err> {
+        match s {
+            "reconnect" => Ok(Self::NoSuchPlayer),
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,84 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(say "MESSAGE")`
+///
+/// The message is quoted so it may hold spaces, it must not hold `"` itself,
+/// which [`CommandSay::new`] checks. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+#[serde(try_from = "RawSay")]
+pub struct CommandSay {
+    message: String,
+}
+
+#[derive(Deserialize)]
+struct RawSay {
+    message: String,
+}
+
+impl TryFrom<RawSay> for CommandSay {
+    type Error = CommandSayError;
+
+    fn try_from(raw: RawSay) -> Result<Self, Self::Error> {
+        Self::new(raw.message)
+    }
+}
+
+impl CommandSay {
+    pub fn new(message: impl Into<String>) -> Result<Self, CommandSayError> {
+        let message = message.into();
+        if message.contains('"') {
+            return Err(CommandSayError::IllegalCommandForm);
+        }
+        Ok(Self { message })
+    }
+
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+impl Command for CommandSay {
+    type Kind = PlayerCommand;
+    type Ok = CommandSayOk;
+    type Error = CommandSayError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Say
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(say \"{}\")", self.message)
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandSayOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandSayError {
+    #[error("The MESSAGE was omitted or held illegal characters.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandSayError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandSayError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(score)`, answered with `(score TIME OUR THEIR)`.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandScore;
+
+impl Command for CommandScore {
+    type Kind = PlayerCommand;
+    type Ok = CommandScoreOk;
+    type Error = CommandScoreError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Score
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(score)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        let [time, our, their] = tokens else {
+            return None;
+        };
+        Some(CommandScoreOk {
+            time: time.parse_atom()?,
+            our: our.parse_atom()?,
+            their: their.parse_atom()?,
+        })
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandScoreOk {
+    pub time: u16,
+    pub our: u16,
+    pub their: u16,
+}
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandScoreError {
+    #[error("The command carried unexpected arguments.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandScoreError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandScoreError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use crate::perception::SenseBody;
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(sense_body)`, answered with a `(sense_body TIME ...)` frame.
+///
+/// The server also sends those frames unrequested every cycle, a pending call
+/// resolves with whichever arrives first.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandSenseBody;
+
+impl Command for CommandSenseBody {
+    type Kind = PlayerCommand;
+    type Ok = CommandSenseBodyOk;
+    type Error = CommandSenseBodyError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::SenseBody
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(sense_body)")
+    }
+
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        SenseBody::from_tokens(tokens)
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandSenseBodyOk = SenseBody;
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandSenseBodyError {
+    #[error("The command carried unexpected arguments.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandSenseBodyError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandSenseBodyError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
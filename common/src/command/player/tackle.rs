@@ -0,0 +1,61 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(tackle POWER_OR_DIR [on])`
+///
+/// `foul` makes an intentional foul attempt. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTackle {
+    pub power_or_dir: f64,
+    #[serde(default)]
+    pub foul: bool,
+}
+
+impl Command for CommandTackle {
+    type Kind = PlayerCommand;
+    type Ok = CommandTackleOk;
+    type Error = CommandTackleError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Tackle
+    }
+
+    fn encode(&self) -> ArcStr {
+        if self.foul {
+            format!("(tackle {} on)", self.power_or_dir)
+        } else {
+            format!("(tackle {})", self.power_or_dir)
+        }
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandTackleOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTackleError {
+    #[error("The POWER_OR_DIR argument was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTackleError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTackleError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(turn MOMENT)`, in degrees. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTurn {
+    pub moment: f64,
+}
+
+impl Command for CommandTurn {
+    type Kind = PlayerCommand;
+    type Ok = CommandTurnOk;
+    type Error = CommandTurnError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::Turn
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(turn {})", self.moment)
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandTurnOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTurnError {
+    #[error("The MOMENT argument was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTurnError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTurnError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,53 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, format};
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Command, PlayerCommand};
+
+/// `(turn_neck ANGLE)`, relative to the current neck angle. No reply on success.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandTurnNeck {
+    pub angle: f64,
+}
+
+impl Command for CommandTurnNeck {
+    type Kind = PlayerCommand;
+    type Ok = CommandTurnNeckOk;
+    type Error = CommandTurnNeckError;
+
+    fn kind(&self) -> Self::Kind {
+        PlayerCommand::TurnNeck
+    }
+
+    fn encode(&self) -> ArcStr {
+        format!("(turn_neck {})", self.angle)
+    }
+
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
+    }
+}
+
+pub type CommandTurnNeckOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandTurnNeckError {
+    #[error("The ANGLE argument was omitted or not a number.")]
+    IllegalCommandForm,
+}
+
+impl FromStr for CommandTurnNeckError {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <CommandTurnNeckError as FromStr>::Err> {
+        match s {
+            "illegal_command_form" => Ok(Self::IllegalCommandForm),
+            _ => Err(()),
+        }
+    }
+}
@@ -4,6 +4,8 @@ use crate::types;
 use arcstr::{ArcStr, format};
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+
 use super::{Command, CommandAny, TrainerCommand};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -24,16 +26,11 @@ impl Command for CommandChangeMode {
         format!("({} {})", self.kind().encode(), self.play_mode.encode())
     }
 
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error> {
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
         if tokens.len() != 1 {
             return None;
         }
-        let tokens = tokens.join(" ");
-        if tokens.is_empty() {
-            return None;
-        }
-
-        tokens.parse().ok()
+        tokens[0].parse_atom()
     }
 }
 pub type CommandChangeModeOk = ();
@@ -56,3 +53,19 @@ impl FromStr for CommandChangeModeError {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::PlayMode;
+
+    /// rcssserver looks the mode up by name and answers anything else with
+    /// `illegal_mode`.
+    #[test]
+    fn test_encode_by_name() {
+        let cmd = CommandChangeMode { play_mode: PlayMode::PM_BeforeKickOff };
+        assert_eq!(cmd.encode(), "(change_mode before_kick_off)");
+        let cmd = CommandChangeMode { play_mode: PlayMode::PM_FreeKick_Right };
+        assert_eq!(cmd.encode(), "(change_mode free_kick_r)");
+    }
+}
@@ -0,0 +1,38 @@
+use std::str::FromStr;
+
+use arcstr::{ArcStr, literal};
+use serde::{Deserialize, Serialize};
+
+use super::{Command, TrainerCommand};
+
+/// `(done)`, ends the trainer's turn in synch mode. No reply.
+#[derive(Serialize, Deserialize, Clone, Debug)]
+pub struct CommandDone;
+
+impl Command for CommandDone {
+    type Kind = TrainerCommand;
+    type Ok = CommandDoneOk;
+    type Error = CommandDoneError;
+
+    fn kind(&self) -> Self::Kind {
+        TrainerCommand::Done
+    }
+
+    fn encode(&self) -> ArcStr {
+        literal!("(done)")
+    }
+
+    // never ok, never error
+}
+
+pub type CommandDoneOk = ();
+
+#[derive(thiserror::Error, Debug)]
+pub enum CommandDoneError {}
+
+impl FromStr for CommandDoneError {
+    type Err = ();
+    fn from_str(_: &str) -> Result<Self, <CommandDoneError as FromStr>::Err> {
+        Err(())
+    }
+}
@@ -4,6 +4,8 @@ use crate::types::EarMode;
 use arcstr::{ArcStr, format};
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+
 use super::{Command, CommandAny, TrainerCommand};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -23,18 +25,18 @@ impl Command for CommandEar {
         format!("(ear {})", self.mode.encode())
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
         if tokens.len() != 1 {
             return None;
         }
-        tokens[0].parse().ok()
+        tokens[0].parse_atom()
     }
 
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error> {
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
         if tokens.len() != 1 {
             return None;
         }
-        tokens[0].parse().ok()
+        tokens[0].parse_atom()
     }
 }
 
@@ -5,6 +5,8 @@ use crate::types::EyeMode;
 use arcstr::{ArcStr, format};
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+
 use super::{Command, CommandAny, TrainerCommand};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -24,24 +26,19 @@ impl Command for CommandEye {
         format!("(eye {})", self.mode.encode())
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
         if tokens.len() != 1 {
             return None;
         }
-        let eye_mode = tokens[0].parse().ok();
+        let eye_mode = tokens[0].parse_atom();
         eye_mode.map(|mode| CommandEarOk { mode })
     }
 
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error> {
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
         if tokens.len() != 1 {
             return None;
         }
-        let tokens = tokens.join(" ");
-        if tokens.is_empty() {
-            return None;
-        }
-
-        tokens.parse().ok()
+        tokens[0].parse_atom()
     }
 }
 
@@ -4,6 +4,8 @@ use super::{Command, TrainerCommand};
 use arcstr::{ArcStr, format, literal};
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandInit {
     pub version: Option<u8>,
@@ -26,7 +28,7 @@ impl Command for CommandInit {
         }
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
         tokens.is_empty().then_some(())
     }
 
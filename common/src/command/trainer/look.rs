@@ -4,6 +4,9 @@ use super::{Command, TrainerCommand};
 use arcstr::{ArcStr, literal};
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+use crate::types::WorldSnapshot;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandLook;
 impl Command for CommandLook {
@@ -19,14 +22,15 @@ impl Command for CommandLook {
         literal!("(look)")
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
-        todo!("really complex to implement")
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        // (ok look TIME OBJECT...)
+        WorldSnapshot::from_tokens(tokens)
     }
 
     // never error
 }
 
-pub type CommandLookOk = ();
+pub type CommandLookOk = WorldSnapshot;
 
 #[derive(thiserror::Error, Debug)]
 pub enum CommandLookError {}
@@ -1,5 +1,6 @@
 pub mod change_mode;
 pub mod check_ball;
+pub mod done;
 pub mod ear;
 pub mod eye;
 pub mod init;
@@ -11,11 +12,12 @@ pub mod team_names;
 
 pub use change_mode::CommandChangeMode as ChangeMode;
 pub use check_ball::CommandCheckBall as CheckBall;
+pub use done::CommandDone as Done;
 pub use ear::CommandEar as Ear;
 pub use eye::CommandEye as Eye;
 pub use init::CommandInit as Init;
 pub use look::CommandLook as Look;
-pub use r#move::CommandMove as Move;
+pub use r#move::{CommandMove as Move, MoveObject};
 pub use recover::CommandRecover as Recover;
 pub use start::CommandStart as Start;
 pub use team_names::CommandTeamNames as TeamNames;
@@ -24,12 +26,14 @@ use arcstr::{ArcStr, literal};
 use std::any::Any;
 
 use super::{Command, CommandAny};
+use crate::sexpr::Sexpr;
 
 #[derive(Copy, Clone, Hash, Eq, PartialEq, Debug)]
 pub enum TrainerCommand {
     ChangeMode,
     Move,
     CheckBall,
+    Done,
     Start,
     Recover,
     Ear,
@@ -45,6 +49,7 @@ impl CommandAny for TrainerCommand {
             TrainerCommand::ChangeMode => literal!("change_mode"),
             TrainerCommand::Move => literal!("move"),
             TrainerCommand::CheckBall => literal!("check_ball"),
+            TrainerCommand::Done => literal!("done"),
             TrainerCommand::Start => literal!("start"),
             TrainerCommand::Recover => literal!("recover"),
             TrainerCommand::Ear => literal!("ear"),
@@ -59,6 +64,7 @@ impl CommandAny for TrainerCommand {
             "change_mode" => Some(TrainerCommand::ChangeMode),
             "move" => Some(TrainerCommand::Move),
             "check_ball" => Some(TrainerCommand::CheckBall),
+            "done" => Some(TrainerCommand::Done),
             "start" => Some(TrainerCommand::Start),
             "recover" => Some(TrainerCommand::Recover),
             "ear" => Some(TrainerCommand::Ear),
@@ -69,7 +75,7 @@ impl CommandAny for TrainerCommand {
             _ => None,
         }
     }
-    fn parse_ret_ok(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>> {
+    fn parse_ret_ok(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>> {
         match self {
             TrainerCommand::ChangeMode => {
                 ChangeMode::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
@@ -80,6 +86,9 @@ impl CommandAny for TrainerCommand {
             TrainerCommand::CheckBall => {
                 CheckBall::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
             }
+            TrainerCommand::Done => {
+                Done::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
+            }
             TrainerCommand::Start => {
                 Start::parse_ret_ok(tokens).map(|r| Box::new(r) as Box<dyn Any + Send>)
             }
@@ -103,7 +112,7 @@ impl CommandAny for TrainerCommand {
             }
         }
     }
-    fn parse_ret_err(&self, tokens: &[&str]) -> Option<Box<dyn Any + Send>> {
+    fn parse_ret_err(&self, tokens: &[Sexpr]) -> Option<Box<dyn Any + Send>> {
         match self {
             TrainerCommand::ChangeMode => {
                 ChangeMode::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
@@ -114,6 +123,9 @@ impl CommandAny for TrainerCommand {
             TrainerCommand::CheckBall => {
                 CheckBall::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
             }
+            TrainerCommand::Done => {
+                Done::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
+            }
             TrainerCommand::Start => {
                 Start::parse_ret_err(tokens).map(|e| Box::new(e) as Box<dyn Any + Send>)
             }
@@ -3,11 +3,102 @@ use std::str::FromStr;
 use arcstr::ArcStr;
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+use crate::types::{Side, Vec2};
+
+use super::team_names::CommandTeamNamesOk;
 use super::{Command, TrainerCommand};
 
+/// The object a trainer `move` applies to.
+///
+/// rcssserver identifies players by their team name rather than by side,
+/// `team_names` maps one to the other, see [`MoveObject::resolve`].
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum MoveObject {
+    Ball,
+    Player { team: String, unum: u8 },
+    /// A player by side, sent as [`MoveObject::Player`] once resolved,
+    /// rcssserver rejects it as it is.
+    PlayerOnSide { side: Side, unum: u8 },
+}
+
+impl MoveObject {
+    pub fn encode(&self) -> String {
+        match self {
+            MoveObject::Ball => "(ball)".to_string(),
+            MoveObject::Player { team, unum } => std::format!("(player {team} {unum})"),
+            MoveObject::PlayerOnSide { side, unum } => std::format!("(player {} {unum})", side.encode()),
+        }
+    }
+
+    /// Names the team of a player addressed by side after the `team_names`
+    /// reply, fails with the side if no team plays there.
+    pub fn resolve(&mut self, names: &CommandTeamNamesOk) -> Result<(), Side> {
+        let MoveObject::PlayerOnSide { side, unum } = *self else { return Ok(()) };
+        let team = match side {
+            Side::LEFT => names.left.as_ref(),
+            Side::RIGHT => names.right.as_ref(),
+            Side::NEUTRAL => None,
+        };
+        *self = MoveObject::Player { team: team.ok_or(side)?.clone(), unum };
+        Ok(())
+    }
+}
+
+/// `(move OBJECT X Y [DIR [VEL_X VEL_Y]])`
+///
+/// `dir` is the body direction in degrees and is ignored for the ball.
+/// The protocol only accepts a velocity after a direction, so a player moved
+/// with `vel` but without `dir` is turned to 0 degrees.
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandMove {
-    pub todo: (),
+    pub object: MoveObject,
+    pub pos: Vec2,
+    #[serde(default)]
+    pub dir: Option<f64>,
+    #[serde(default)]
+    pub vel: Option<Vec2>,
+}
+
+impl CommandMove {
+    pub fn ball(pos: Vec2) -> Self {
+        Self {
+            object: MoveObject::Ball,
+            pos,
+            dir: None,
+            vel: None,
+        }
+    }
+
+    pub fn player(team: String, unum: u8, pos: Vec2) -> Self {
+        Self {
+            object: MoveObject::Player { team, unum },
+            pos,
+            dir: None,
+            vel: None,
+        }
+    }
+
+    /// A player by side, see [`MoveObject::PlayerOnSide`].
+    pub fn player_on_side(side: Side, unum: u8, pos: Vec2) -> Self {
+        Self {
+            object: MoveObject::PlayerOnSide { side, unum },
+            pos,
+            dir: None,
+            vel: None,
+        }
+    }
+
+    pub fn with_dir(&mut self, dir: f64) -> &mut Self {
+        self.dir = Some(dir);
+        self
+    }
+
+    pub fn with_vel(&mut self, vel: Vec2) -> &mut Self {
+        self.vel = Some(vel);
+        self
+    }
 }
 
 impl Command for CommandMove {
@@ -20,15 +111,29 @@ impl Command for CommandMove {
     }
 
     fn encode(&self) -> ArcStr {
-        todo!()
+        let mut ret = std::format!("(move {} {} {}", self.object.encode(), self.pos.x, self.pos.y);
+
+        match (self.dir, self.vel) {
+            (None, None) => {}
+            (Some(dir), None) => ret += &std::format!(" {dir}"),
+            (dir, Some(vel)) => {
+                ret += &std::format!(" {} {} {}", dir.unwrap_or(0.0), vel.x, vel.y)
+            }
+        }
+
+        ret.push(')');
+        ret.into()
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
         tokens.is_empty().then_some(())
     }
 
-    fn parse_ret_err(tokens: &[&str]) -> Option<Self::Error> {
-        todo!("really complex too")
+    fn parse_ret_err(tokens: &[Sexpr]) -> Option<Self::Error> {
+        if tokens.len() != 1 {
+            return None;
+        }
+        tokens[0].parse_atom()
     }
 }
 
@@ -36,9 +141,9 @@ pub type CommandMoveOk = ();
 
 #[derive(thiserror::Error, Debug)]
 pub enum CommandMoveError {
-    #[error("The specified mode was not valid.")]
-    IllegalMode,
-    #[error("The PLAY_MODE argument was omitted")]
+    #[error("The OBJECT did not name the ball or a connected player.")]
+    IllegalObjectForm,
+    #[error("The position was omitted or the argument count was invalid.")]
     IllegalCommandForm,
 }
 
@@ -46,9 +151,78 @@ impl FromStr for CommandMoveError {
     type Err = ();
     fn from_str(s: &str) -> Result<Self, <CommandMoveError as FromStr>::Err> {
         match s {
-            "illegal_mode" => Ok(Self::IllegalMode),
+            "illegal_object_form" => Ok(Self::IllegalObjectForm),
             "illegal_command_form" => Ok(Self::IllegalCommandForm),
             _ => Err(()),
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sexpr;
+
+    #[test]
+    fn test_encode_ball() {
+        let mut cmd = CommandMove::ball(Vec2::new(-10.5, 3.0));
+        assert_eq!(cmd.encode(), "(move (ball) -10.5 3)");
+
+        cmd.with_vel(Vec2::new(1.2, 0.0));
+        assert_eq!(cmd.encode(), "(move (ball) -10.5 3 0 1.2 0)");
+    }
+
+    #[test]
+    fn test_encode_player() {
+        let mut cmd = CommandMove::player("HELIOS".to_string(), 7, Vec2::new(0.0, -20.0));
+        assert_eq!(cmd.encode(), "(move (player HELIOS 7) 0 -20)");
+
+        cmd.with_dir(90.0);
+        assert_eq!(cmd.encode(), "(move (player HELIOS 7) 0 -20 90)");
+
+        cmd.with_vel(Vec2::new(0.5, 0.5));
+        assert_eq!(cmd.encode(), "(move (player HELIOS 7) 0 -20 90 0.5 0.5)");
+    }
+
+    #[test]
+    fn test_parse_ret() {
+        let ok = sexpr::parse("(ok move)").unwrap();
+        assert!(CommandMove::parse_ret_ok(&ok.args()[1..]).is_some());
+
+        let err = sexpr::parse("(error illegal_object_form)").unwrap();
+        assert!(matches!(
+            CommandMove::parse_ret_err(err.args()),
+            Some(CommandMoveError::IllegalObjectForm)
+        ));
+        assert!(CommandMove::parse_ret_err(&[]).is_none());
+    }
+
+    #[test]
+    fn test_deserialize() {
+        let cmd: CommandMove = serde_json::from_str(
+            r#"{"object": {"kind": "player", "team": "HELIOS", "unum": 1}, "pos": {"x": -50, "y": 0}, "dir": 0}"#,
+        )
+        .unwrap();
+        assert_eq!(cmd.encode(), "(move (player HELIOS 1) -50 0 0)");
+    }
+
+    #[test]
+    fn test_resolve_side() {
+        let mut cmd: CommandMove = serde_json::from_str(
+            r#"{"object": {"kind": "player_on_side", "side": "right", "unum": 4}, "pos": {"x": 10, "y": 5}}"#,
+        )
+        .unwrap();
+        assert_eq!(cmd.object, MoveObject::PlayerOnSide { side: Side::RIGHT, unum: 4 });
+
+        let names = CommandTeamNamesOk { left: Some("HELIOS".to_string()), right: None };
+        assert_eq!(cmd.object.resolve(&names), Err(Side::RIGHT));
+
+        let names = CommandTeamNamesOk { right: Some("CYRUS".to_string()), ..names };
+        cmd.object.resolve(&names).unwrap();
+        assert_eq!(cmd.encode(), "(move (player CYRUS 4) 10 5)");
+
+        let mut ball = MoveObject::Ball;
+        ball.resolve(&names).unwrap();
+        assert_eq!(ball, MoveObject::Ball);
+    }
+}
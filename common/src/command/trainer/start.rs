@@ -3,6 +3,8 @@ use std::str::FromStr;
 use arcstr::{ArcStr, literal};
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+
 use super::{Command, TrainerCommand};
 
 #[derive(Serialize, Deserialize, Clone, Debug)]
@@ -20,7 +22,7 @@ impl Command for CommandStart {
         literal!("(start)")
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
         tokens.is_empty().then_some(())
     }
 
@@ -4,6 +4,8 @@ use super::{Command, TrainerCommand};
 use arcstr::{ArcStr, literal};
 use serde::{Deserialize, Serialize};
 
+use crate::sexpr::Sexpr;
+
 #[derive(Serialize, Deserialize, Clone, Debug)]
 pub struct CommandTeamNames;
 impl Command for CommandTeamNames {
@@ -19,38 +21,30 @@ impl Command for CommandTeamNames {
         literal!("(team_names)")
     }
 
-    fn parse_ret_ok(tokens: &[&str]) -> Option<Self::Ok> {
-        let parse_team = |team_tokens: &[&str]| {
-            if team_tokens.len() != 3 {
+    fn parse_ret_ok(tokens: &[Sexpr]) -> Option<Self::Ok> {
+        let mut ret = CommandTeamNamesOk {
+            left: None,
+            right: None,
+        };
+
+        // (ok team_names [(team l NAME)] [(team r NAME)])
+        for team in tokens {
+            if team.head() != Some("team") {
                 return None;
             }
-            if team_tokens[0] != "team" {
+            let [side, name] = team.args() else {
                 return None;
-            }
+            };
 
-            let team_name = team_tokens[2].to_string();
-            match team_tokens[1] {
-                "l" => Some((Some(team_name), None)),
-                "r" => Some((None, Some(team_name))),
-                _ => None,
+            let name = Some(name.text()?.to_string());
+            match side.atom()? {
+                "l" => ret.left = name,
+                "r" => ret.right = name,
+                _ => return None,
             }
-        };
-
-        let teams = match tokens.len() {
-            6 => {
-                let team_1 = parse_team(&tokens[0..3])?;
-                let team_2 = parse_team(&tokens[3..6])?;
-                (team_1.0.or(team_2.0), team_1.1.or(team_2.1))
-            }
-            3 => parse_team(tokens)?,
-            0 => (None, None),
-            _ => return None,
-        };
+        }
 
-        Some(CommandTeamNamesOk {
-            left: teams.0,
-            right: teams.1,
-        })
+        Some(ret)
     }
 
     // never error
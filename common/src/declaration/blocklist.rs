@@ -0,0 +1,27 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use super::Unum;
+
+/// Player commands the game server drops, default all allowed.
+#[derive(Deserialize, Serialize, Default, Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ActionList {
+    #[serde(default)]
+    pub dash: bool,
+    #[serde(default)]
+    pub r#catch: bool,
+}
+
+impl ActionList {
+    pub fn is_empty(&self) -> bool {
+        !self.dash && !self.r#catch
+    }
+}
+
+/// Only players with at least one blocked command are listed.
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct Blocklist {
+    #[serde(default)]
+    pub players_l: BTreeMap<Unum, ActionList>,
+    #[serde(default)]
+    pub players_r: BTreeMap<Unum, ActionList>,
+}
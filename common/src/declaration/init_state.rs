@@ -0,0 +1,20 @@
+use std::collections::BTreeMap;
+use serde::{Deserialize, Serialize};
+use super::{Position, Unum};
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct InitState {
+    pub ball: Option<Position>,
+    /// Every player of the left team, including those without an initial state.
+    #[serde(default)]
+    pub players_l: BTreeMap<Unum, PlayerInitState>,
+    #[serde(default)]
+    pub players_r: BTreeMap<Unum, PlayerInitState>,
+}
+
+/// Default all unset
+#[derive(Deserialize, Serialize, Default, Debug, Clone)]
+pub struct PlayerInitState {
+    pub pos: Option<Position>,
+    pub stamina: Option<u16>,
+}
@@ -0,0 +1,12 @@
+//! Declarations the allocator annotates a game server with and the service
+//! reads back, as JSON.
+
+mod blocklist;
+mod init_state;
+mod position;
+mod unum;
+
+pub use blocklist::{ActionList, Blocklist};
+pub use init_state::{InitState, PlayerInitState};
+pub use position::Position;
+pub use unum::{Unum, unum};
@@ -1,6 +1,11 @@
 pub mod client;
 pub mod command;
+pub mod declaration;
+pub mod perception;
 pub mod process;
+pub mod scenario;
+pub mod sexpr;
+pub mod stopping;
 pub mod types;
 pub mod udp;
 pub mod utils;
@@ -0,0 +1,111 @@
+use serde::{Deserialize, Serialize};
+
+use crate::command::clang;
+use crate::sexpr::Sexpr;
+use crate::types::Side;
+
+/// `(hear TIME SENDER MESSAGE)`, the player's aural sensor.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Hear {
+    pub time: u16,
+    pub sender: HearSender,
+    /// Referee messages are bare atoms such as `goal_l_1`, players' say
+    /// without its quotes, coach messages (CLang) as a written s-expression.
+    pub message: String,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum HearSender {
+    Referee,
+    /// Our own `say`, echoed back.
+    #[serde(rename = "self")]
+    Myself,
+    /// `online_coach_left` / `online_coach_right`.
+    OnlineCoach { side: Side },
+    /// Any other coach or trainer.
+    Coach,
+    /// `DIR our UNUM` or `DIR opp`, direction in degrees.
+    Player {
+        dir: f64,
+        team: HearTeam,
+        unum: Option<u8>,
+    },
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum HearTeam {
+    Our,
+    Opp,
+}
+
+impl Hear {
+    /// Parse the part after `hear`.
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        let [time, rest @ ..] = tokens else {
+            return None;
+        };
+        let time = time.parse_atom()?;
+
+        let (sender, message) = match rest {
+            [sender, message] => {
+                let sender = match sender.atom()? {
+                    "referee" => HearSender::Referee,
+                    "self" => HearSender::Myself,
+                    "online_coach_left" => HearSender::OnlineCoach { side: Side::LEFT },
+                    "online_coach_right" => HearSender::OnlineCoach { side: Side::RIGHT },
+                    "coach" => HearSender::Coach,
+                    _ => return None,
+                };
+                (sender, message)
+            }
+            [dir, team, message] => {
+                let sender = HearSender::Player {
+                    dir: dir.parse_atom()?,
+                    team: HearTeam::parse(team)?,
+                    unum: None,
+                };
+                (sender, message)
+            }
+            [dir, team, unum, message] => {
+                let sender = HearSender::Player {
+                    dir: dir.parse_atom()?,
+                    team: HearTeam::parse(team)?,
+                    unum: Some(unum.parse_atom()?),
+                };
+                (sender, message)
+            }
+            _ => return None,
+        };
+
+        let message = match message {
+            Sexpr::List(_) => message.to_string(),
+            _ => message.text()?.to_string(),
+        };
+
+        Some(Hear {
+            time,
+            sender,
+            message,
+        })
+    }
+
+    /// Decode a message from an online coach as CLang.
+    pub fn clang(&self) -> Option<clang::Message> {
+        match self.sender {
+            HearSender::OnlineCoach { .. } => clang::Message::parse(&self.message),
+            _ => None,
+        }
+    }
+}
+
+impl HearTeam {
+    fn parse(team: &Sexpr) -> Option<Self> {
+        match team.atom()? {
+            "our" => Some(HearTeam::Our),
+            "opp" => Some(HearTeam::Opp),
+            _ => None,
+        }
+    }
+}
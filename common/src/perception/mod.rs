@@ -0,0 +1,162 @@
+//! Typed sensor messages a player receives without asking for them.
+
+mod hear;
+mod param;
+mod player_type;
+mod see;
+mod sense_body;
+
+pub use hear::{Hear, HearSender, HearTeam};
+pub use param::{Params, PlayerParam, ServerParam};
+pub use player_type::PlayerType;
+pub use see::{See, SeenCard, SeenKind, SeenObject};
+pub use sense_body::{ArmState, Collision, SenseBody, SenseBodyCounts};
+
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::{self, Sexpr};
+
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", content = "data", rename_all = "snake_case")]
+pub enum PlayerMessage {
+    See(See),
+    Hear(Hear),
+    SenseBody(SenseBody),
+    ServerParam(ServerParam),
+    PlayerParam(PlayerParam),
+    PlayerType(PlayerType),
+}
+
+impl PlayerMessage {
+    /// `None` for anything that is not one of the messages above, e.g. command
+    /// replies, or a message that failed to parse.
+    pub fn parse(msg: &Sexpr) -> Option<Self> {
+        let args = msg.args();
+        match msg.head()? {
+            "see" => See::from_tokens(args).map(Self::See),
+            "hear" => Hear::from_tokens(args).map(Self::Hear),
+            "sense_body" => SenseBody::from_tokens(args).map(Self::SenseBody),
+            "server_param" => ServerParam::from_tokens(args).map(Self::ServerParam),
+            "player_param" => PlayerParam::from_tokens(args).map(Self::PlayerParam),
+            "player_type" => PlayerType::from_tokens(args).map(Self::PlayerType),
+            _ => None,
+        }
+    }
+
+    pub fn parse_str(raw: &str) -> Option<Self> {
+        Self::parse(&sexpr::parse(raw).ok()?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::types::{Side, ViewQuality, ViewWidth};
+
+    #[test]
+    fn test_parse_see() {
+        let raw = "(see 42 ((f c) 12.2 -4) ((g r) 60.3 10) ((b) 5.5 -10 0.1 0.4) \
+            ((p \"HELIOS\" 7) 10 20 0 0 45 30 t) ((p \"CYRUS\") 40 5) ((l r) 55 -80) ((B) 1.2 170) \
+            ((f p l t) 30))";
+        let Some(PlayerMessage::See(see)) = PlayerMessage::parse_str(raw) else {
+            panic!("failed to parse see");
+        };
+
+        assert_eq!(see.time, 42);
+        assert_eq!(see.objects.len(), 8);
+
+        let ball = see.ball().unwrap();
+        assert_eq!((ball.dist, ball.dir), (Some(5.5), -10.0));
+        assert_eq!(ball.dir_change, Some(0.4));
+
+        let players: Vec<_> = see.players().collect();
+        assert_eq!(players.len(), 2);
+        assert_eq!(
+            players[0].kind,
+            SeenKind::Player { team: Some("HELIOS".to_string()), unum: Some(7), goalie: false }
+        );
+        assert_eq!(players[0].head_dir, Some(30.0));
+        assert!(players[0].tackling);
+
+        assert_eq!(see.objects[1].kind, SeenKind::Goal { side: Some(Side::RIGHT) });
+        assert!(see.objects[6].vague);
+        assert_eq!(see.objects[7].kind, SeenKind::Flag { name: "p l t".to_string() });
+        assert_eq!(see.objects[7].dist, None);
+    }
+
+    #[test]
+    fn test_parse_hear() {
+        let Some(PlayerMessage::Hear(hear)) = PlayerMessage::parse_str("(hear 100 referee goal_l_1)") else {
+            panic!("failed to parse hear");
+        };
+        assert_eq!(hear.sender, HearSender::Referee);
+        assert_eq!(hear.message, "goal_l_1");
+
+        let Some(PlayerMessage::Hear(hear)) = PlayerMessage::parse_str("(hear 7 -30 our 9 \"pass me\")") else {
+            panic!("failed to parse hear");
+        };
+        assert_eq!(
+            hear.sender,
+            HearSender::Player { dir: -30.0, team: HearTeam::Our, unum: Some(9) }
+        );
+        assert_eq!(hear.message, "pass me");
+
+        let raw = "(hear 7 online_coach_right (info (6000 (true) (do our {1} (pos (rec (pt 0 0) (pt 1 1)))))))";
+        let Some(PlayerMessage::Hear(hear)) = PlayerMessage::parse_str(raw) else {
+            panic!("failed to parse hear");
+        };
+        assert_eq!(hear.sender, HearSender::OnlineCoach { side: Side::RIGHT });
+        assert!(hear.message.starts_with("(info (6000"));
+        let clang = hear.clang().unwrap();
+        assert_eq!(clang.to_string(), hear.message);
+    }
+
+    #[test]
+    fn test_parse_sense_body() {
+        let raw = "(sense_body 0 (view_mode high normal) (stamina 8000 1 130600) (speed 0 0) \
+            (head_angle 0) (kick 0) (dash 3) (turn 0) (say 0) (turn_neck 0) (catch 0) (move 0) \
+            (change_view 0) (change_focus 0) (arm (movable 0) (expires 0) (target 0 0) (count 0)) \
+            (focus (target l 10) (count 1)) (tackle (expires 0) (count 0)) (collision none) \
+            (foul (charged 0) (card yellow)) (focus_point 0 0))";
+        let Some(PlayerMessage::SenseBody(body)) = PlayerMessage::parse_str(raw) else {
+            panic!("failed to parse sense_body");
+        };
+
+        assert_eq!(body.view_quality, ViewQuality::High);
+        assert_eq!(body.view_width, ViewWidth::Normal);
+        assert_eq!(body.stamina, 8000.0);
+        assert_eq!(body.stamina_capacity, Some(130600.0));
+        assert_eq!(body.counts.dash, 3);
+        assert_eq!(body.focus_target, Some((Side::LEFT, 10)));
+        assert_eq!(body.counts.focus, 1);
+        assert!(body.collisions.is_empty());
+        assert_eq!(body.card, Some(SeenCard::Yellow));
+        assert_eq!(body.focus_point, Some((0.0, 0.0)));
+    }
+
+    #[test]
+    fn test_parse_params() {
+        let raw = "(server_param (goal_width 14.02) (half_time 300) (synch_mode 1) (team_l_start \"\"))";
+        let Some(PlayerMessage::ServerParam(param)) = PlayerMessage::parse_str(raw) else {
+            panic!("failed to parse server_param");
+        };
+        assert_eq!(param.get::<f64>("goal_width"), Some(14.02));
+        assert_eq!(param.half_time(), Some(300));
+        assert_eq!(param.synch_mode(), Some(true));
+        assert_eq!(param.raw("team_l_start"), Some(""));
+
+        let raw = "(player_type (id 3) (player_speed_max 1.05) (stamina_inc_max 45.1) (player_decay 0.42) \
+            (inertia_moment 5.5) (dash_power_rate 0.0059) (player_size 0.3) (kickable_margin 0.68) \
+            (kick_rand 0.08) (extra_stamina 62.3) (effort_max 0.95) (effort_min 0.55) \
+            (kick_power_rate 0.027) (foul_detect_probability 0.5) (catchable_area_l_stretch 1.07))";
+        let Some(PlayerMessage::PlayerType(ty)) = PlayerMessage::parse_str(raw) else {
+            panic!("failed to parse player_type");
+        };
+        assert_eq!(ty.id, 3);
+        assert_eq!(ty.kickable_margin, 0.68);
+        assert_eq!(ty.catchable_area_l_stretch, 1.07);
+
+        assert!(PlayerMessage::parse_str("(player_type (player_decay 0.4))").is_none());
+        assert!(PlayerMessage::parse_str("(ok look 0)").is_none());
+    }
+}
@@ -0,0 +1,126 @@
+use std::collections::BTreeMap;
+use std::ops::Deref;
+use std::str::FromStr;
+
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+/// `(NAME VALUE)` pairs as sent in `server_param` and `player_param`.
+///
+/// The set of names grows with every server release, so values are kept as
+/// text and converted on access instead of being mapped onto fixed fields.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(transparent)]
+pub struct Params {
+    values: BTreeMap<String, String>,
+}
+
+impl Params {
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        let values = tokens
+            .iter()
+            .map(|pair| match pair.list()? {
+                [name, value] => Some((name.atom()?.to_string(), value.text()?.to_string())),
+                _ => None,
+            })
+            .collect::<Option<_>>()?;
+
+        Some(Params { values })
+    }
+
+    pub fn raw(&self, name: &str) -> Option<&str> {
+        self.values.get(name).map(String::as_str)
+    }
+
+    pub fn get<T: FromStr>(&self, name: &str) -> Option<T> {
+        self.raw(name)?.parse().ok()
+    }
+
+    /// Booleans are sent as `0`/`1` by older servers and `true`/`false` by newer ones.
+    pub fn get_bool(&self, name: &str) -> Option<bool> {
+        match self.raw(name)? {
+            "1" | "true" | "on" => Some(true),
+            "0" | "false" | "off" => Some(false),
+            _ => None,
+        }
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &str)> {
+        self.values.iter().map(|(k, v)| (k.as_str(), v.as_str()))
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+}
+
+/// `(server_param (NAME VALUE)...)`, sent once after `init`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(transparent)]
+pub struct ServerParam(pub Params);
+
+impl ServerParam {
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        Params::from_tokens(tokens).map(Self)
+    }
+
+    pub fn half_time(&self) -> Option<u32> {
+        self.get("half_time")
+    }
+
+    pub fn synch_mode(&self) -> Option<bool> {
+        self.get_bool("synch_mode")
+    }
+
+    pub fn visible_distance(&self) -> Option<f64> {
+        self.get("visible_distance")
+    }
+
+    pub fn stamina_max(&self) -> Option<f64> {
+        self.get("stamina_max")
+    }
+}
+
+impl Deref for ServerParam {
+    type Target = Params;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
+
+/// `(player_param (NAME VALUE)...)`, sent once after `init`.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+#[serde(transparent)]
+pub struct PlayerParam(pub Params);
+
+impl PlayerParam {
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        Params::from_tokens(tokens).map(Self)
+    }
+
+    pub fn player_types(&self) -> Option<u8> {
+        self.get("player_types")
+    }
+
+    pub fn subs_max(&self) -> Option<u8> {
+        self.get("subs_max")
+    }
+
+    pub fn pt_max(&self) -> Option<u8> {
+        self.get("pt_max")
+    }
+}
+
+impl Deref for PlayerParam {
+    type Target = Params;
+
+    fn deref(&self) -> &Self::Target {
+        &self.0
+    }
+}
@@ -0,0 +1,62 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+/// `(player_type (id N) (player_speed_max ...) ...)`, one per heterogeneous type.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct PlayerType {
+    pub id: u8,
+    pub player_speed_max: f64,
+    pub stamina_inc_max: f64,
+    pub player_decay: f64,
+    pub inertia_moment: f64,
+    pub dash_power_rate: f64,
+    pub player_size: f64,
+    pub kickable_margin: f64,
+    pub kick_rand: f64,
+    pub extra_stamina: f64,
+    pub effort_max: f64,
+    pub effort_min: f64,
+    pub kick_power_rate: f64,
+    pub foul_detect_probability: f64,
+    pub catchable_area_l_stretch: f64,
+}
+
+impl PlayerType {
+    /// Parse the part after `player_type`, unknown parameters are skipped.
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        let mut ret = PlayerType::default();
+        let mut has_id = false;
+
+        for pair in tokens {
+            let [name, value] = pair.list()? else {
+                return None;
+            };
+            let field = match name.atom()? {
+                "id" => {
+                    ret.id = value.parse_atom()?;
+                    has_id = true;
+                    continue;
+                }
+                "player_speed_max" => &mut ret.player_speed_max,
+                "stamina_inc_max" => &mut ret.stamina_inc_max,
+                "player_decay" => &mut ret.player_decay,
+                "inertia_moment" => &mut ret.inertia_moment,
+                "dash_power_rate" => &mut ret.dash_power_rate,
+                "player_size" => &mut ret.player_size,
+                "kickable_margin" => &mut ret.kickable_margin,
+                "kick_rand" => &mut ret.kick_rand,
+                "extra_stamina" => &mut ret.extra_stamina,
+                "effort_max" => &mut ret.effort_max,
+                "effort_min" => &mut ret.effort_min,
+                "kick_power_rate" => &mut ret.kick_power_rate,
+                "foul_detect_probability" => &mut ret.foul_detect_probability,
+                "catchable_area_l_stretch" => &mut ret.catchable_area_l_stretch,
+                _ => continue,
+            };
+            *field = value.parse_atom()?;
+        }
+
+        has_id.then_some(ret)
+    }
+}
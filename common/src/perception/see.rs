@@ -0,0 +1,173 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+use crate::types::Side;
+
+/// `(see TIME OBJECT...)`, the player's visual sensor.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct See {
+    pub time: u16,
+    pub objects: Vec<SeenObject>,
+}
+
+/// What a seen object is, from its `(NAME ...)` list.
+///
+/// Objects too close or too far to be identified come with an upper-case
+/// name, `(B)`, `(P)`, `(F)` or `(G)`, and fewer details filled in.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum SeenKind {
+    Ball,
+    Player {
+        team: Option<String>,
+        unum: Option<u8>,
+        goalie: bool,
+    },
+    Goal {
+        side: Option<Side>,
+    },
+    /// Flag name without the leading `f`, e.g. `p l t`.
+    Flag {
+        name: String,
+    },
+    /// `l`, `r`, `t` or `b`.
+    Line {
+        name: String,
+    },
+}
+
+/// `((NAME) DIST DIR [DIST_CHG DIR_CHG [BODY HEAD [POINT_DIR]]] [t|k|f] [y|r])`
+///
+/// Only the direction is sent for objects beyond the unum/team range.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SeenObject {
+    pub kind: SeenKind,
+    /// Whether the name was upper-case, i.e. the object is only sensed.
+    pub vague: bool,
+    pub dist: Option<f64>,
+    pub dir: f64,
+    pub dist_change: Option<f64>,
+    pub dir_change: Option<f64>,
+    pub body_dir: Option<f64>,
+    pub head_dir: Option<f64>,
+    pub point_dir: Option<f64>,
+    pub tackling: bool,
+    pub kicking: bool,
+    pub fouled: bool,
+    pub card: Option<SeenCard>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum SeenCard {
+    Yellow,
+    Red,
+}
+
+impl See {
+    /// Parse the part after `see`.
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        let (time, objects) = tokens.split_first()?;
+        Some(See {
+            time: time.parse_atom()?,
+            objects: objects
+                .iter()
+                .map(SeenObject::parse)
+                .collect::<Option<_>>()?,
+        })
+    }
+
+    pub fn ball(&self) -> Option<&SeenObject> {
+        self.objects.iter().find(|o| o.kind == SeenKind::Ball)
+    }
+
+    pub fn players(&self) -> impl Iterator<Item = &SeenObject> {
+        self.objects
+            .iter()
+            .filter(|o| matches!(o.kind, SeenKind::Player { .. }))
+    }
+}
+
+impl SeenObject {
+    fn parse(object: &Sexpr) -> Option<Self> {
+        let [name, values @ ..] = object.list()? else {
+            return None;
+        };
+        let (kind, vague) = SeenKind::parse(name)?;
+
+        let numbers: Vec<f64> = values.iter().map_while(|v| v.parse_atom()).collect();
+        let (dist, dir) = match numbers[..] {
+            [dir] => (None, dir),
+            [dist, dir, ..] => (Some(dist), dir),
+            [] => return None,
+        };
+
+        let mut ret = SeenObject {
+            kind,
+            vague,
+            dist,
+            dir,
+            dist_change: numbers.get(2).copied(),
+            dir_change: numbers.get(3).copied(),
+            body_dir: numbers.get(4).copied(),
+            head_dir: numbers.get(5).copied(),
+            point_dir: numbers.get(6).copied(),
+            tackling: false,
+            kicking: false,
+            fouled: false,
+            card: None,
+        };
+
+        for flag in &values[numbers.len()..] {
+            match flag.atom()? {
+                "t" => ret.tackling = true,
+                "k" => ret.kicking = true,
+                "f" => ret.fouled = true,
+                "y" => ret.card = Some(SeenCard::Yellow),
+                "r" => ret.card = Some(SeenCard::Red),
+                _ => return None,
+            }
+        }
+
+        Some(ret)
+    }
+}
+
+impl SeenKind {
+    fn parse(name: &Sexpr) -> Option<(Self, bool)> {
+        let head = name.head()?;
+        let args = name.args();
+
+        let kind = match head {
+            "b" | "B" => SeenKind::Ball,
+            "p" | "P" => {
+                let team = args.first().and_then(|t| t.text()).map(str::to_string);
+                let unum = match args.get(1) {
+                    Some(unum) => Some(unum.parse_atom()?),
+                    None => None,
+                };
+                let goalie = args.get(2).and_then(|g| g.atom()) == Some("goalie");
+                SeenKind::Player { team, unum, goalie }
+            }
+            "g" | "G" => SeenKind::Goal {
+                side: match args.first() {
+                    Some(side) => Some(side.parse_atom()?),
+                    None => None,
+                },
+            },
+            "f" | "F" => SeenKind::Flag {
+                name: args
+                    .iter()
+                    .map(|a| a.text())
+                    .collect::<Option<Vec<_>>>()?
+                    .join(" "),
+            },
+            "l" => SeenKind::Line {
+                name: args.first()?.atom()?.to_string(),
+            },
+            _ => return None,
+        };
+
+        Some((kind, head.chars().all(|c| c.is_ascii_uppercase())))
+    }
+}
@@ -0,0 +1,169 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+use crate::types::{Side, ViewQuality, ViewWidth};
+
+use super::see::SeenCard;
+
+/// `(sense_body TIME (view_mode ...) (stamina ...) ...)`, the player's body sensor.
+///
+/// Sections missing from older protocol versions keep their default.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct SenseBody {
+    pub time: u16,
+    pub view_quality: ViewQuality,
+    pub view_width: ViewWidth,
+    pub stamina: f64,
+    pub effort: f64,
+    pub stamina_capacity: Option<f64>,
+    pub speed: f64,
+    pub speed_dir: f64,
+    pub head_angle: f64,
+    pub counts: SenseBodyCounts,
+    pub arm: ArmState,
+    /// `(focus (target SIDE UNUM))`, `None` for `(target none)`.
+    pub focus_target: Option<(Side, u8)>,
+    pub tackle_expires: u32,
+    pub collisions: Vec<Collision>,
+    pub foul_charged: u32,
+    pub card: Option<SeenCard>,
+    /// `(focus_point DIST DIR)`, version 19 and later.
+    pub focus_point: Option<(f64, f64)>,
+}
+
+/// How many of each command the server has executed so far.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct SenseBodyCounts {
+    pub kick: u32,
+    pub dash: u32,
+    pub turn: u32,
+    pub say: u32,
+    pub turn_neck: u32,
+    pub catch: u32,
+    pub r#move: u32,
+    pub change_view: u32,
+    pub change_focus: u32,
+    pub arm: u32,
+    pub focus: u32,
+    pub tackle: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct ArmState {
+    pub movable: u32,
+    pub expires: u32,
+    pub target_dist: f64,
+    pub target_dir: f64,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Collision {
+    Ball,
+    Player,
+    Post,
+}
+
+impl SenseBody {
+    /// Parse the part after `sense_body`.
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        let (time, sections) = tokens.split_first()?;
+        let mut ret = SenseBody {
+            time: time.parse_atom()?,
+            view_quality: ViewQuality::High,
+            view_width: ViewWidth::Normal,
+            stamina: 0.0,
+            effort: 0.0,
+            stamina_capacity: None,
+            speed: 0.0,
+            speed_dir: 0.0,
+            head_angle: 0.0,
+            counts: SenseBodyCounts::default(),
+            arm: ArmState::default(),
+            focus_target: None,
+            tackle_expires: 0,
+            collisions: Vec::new(),
+            foul_charged: 0,
+            card: None,
+            focus_point: None,
+        };
+
+        for section in sections {
+            let args = section.args();
+            let num = |idx: usize| args.get(idx).and_then(|a| a.parse_atom::<f64>());
+            let count = || args.first().and_then(|a| a.parse_atom::<u32>());
+            // (NAME (KEY VALUE) ...), e.g. (arm (movable 0) (expires 0) ...)
+            let sub = |key: &str| section.find(key).map(|s| s.args());
+
+            match section.head()? {
+                "view_mode" => {
+                    ret.view_quality = args.first()?.parse_atom()?;
+                    ret.view_width = args.get(1)?.parse_atom()?;
+                }
+                "stamina" => {
+                    ret.stamina = num(0)?;
+                    ret.effort = num(1)?;
+                    ret.stamina_capacity = num(2);
+                }
+                "speed" => {
+                    ret.speed = num(0)?;
+                    ret.speed_dir = num(1)?;
+                }
+                "head_angle" => ret.head_angle = num(0)?,
+                "kick" => ret.counts.kick = count()?,
+                "dash" => ret.counts.dash = count()?,
+                "turn" => ret.counts.turn = count()?,
+                "say" => ret.counts.say = count()?,
+                "turn_neck" => ret.counts.turn_neck = count()?,
+                "catch" => ret.counts.catch = count()?,
+                "move" => ret.counts.r#move = count()?,
+                "change_view" => ret.counts.change_view = count()?,
+                "change_focus" => ret.counts.change_focus = count()?,
+                "arm" => {
+                    ret.arm.movable = sub("movable")?.first()?.parse_atom()?;
+                    ret.arm.expires = sub("expires")?.first()?.parse_atom()?;
+                    let target = sub("target")?;
+                    ret.arm.target_dist = target.first()?.parse_atom()?;
+                    ret.arm.target_dir = target.get(1)?.parse_atom()?;
+                    ret.counts.arm = sub("count")?.first()?.parse_atom()?;
+                }
+                "focus" => {
+                    ret.focus_target = match sub("target")? {
+                        [side, unum] => Some((side.parse_atom()?, unum.parse_atom()?)),
+                        _ => None,
+                    };
+                    ret.counts.focus = sub("count")?.first()?.parse_atom()?;
+                }
+                "tackle" => {
+                    ret.tackle_expires = sub("expires")?.first()?.parse_atom()?;
+                    ret.counts.tackle = sub("count")?.first()?.parse_atom()?;
+                }
+                "collision" => {
+                    // (collision none) or (collision (ball) (player) ...)
+                    ret.collisions = args
+                        .iter()
+                        .filter_map(|c| match c.head() {
+                            Some("ball") => Some(Collision::Ball),
+                            Some("player") => Some(Collision::Player),
+                            Some("post") => Some(Collision::Post),
+                            _ => None,
+                        })
+                        .collect();
+                }
+                "foul" => {
+                    ret.foul_charged = sub("charged")?.first()?.parse_atom()?;
+                    ret.card = match sub("card")?.first()?.atom()? {
+                        "yellow" => Some(SeenCard::Yellow),
+                        "red" => Some(SeenCard::Red),
+                        _ => None,
+                    };
+                }
+                "focus_point" => ret.focus_point = Some((num(0)?, num(1)?)),
+                // unknown sections from newer servers are not an error
+                _ => {}
+            }
+        }
+
+        Some(ret)
+    }
+}
@@ -0,0 +1,105 @@
+//! rcssserver's native `NAMESPACE::name : value` configuration files, as in
+//! `~/.rcssserver/server.conf`.
+
+use std::path::Path;
+
+#[derive(thiserror::Error, Debug)]
+pub enum ConfigError {
+    #[error("Failed to read configuration file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Line {line}: expected 'NAMESPACE::name : value'")]
+    InvalidLine { line: usize },
+    #[error("Line {line}: invalid value '{value}' for {key}")]
+    InvalidValue { line: usize, key: String, value: String },
+}
+
+pub type ConfigResult<T> = Result<T, ConfigError>;
+
+/// A value as it appears right of the colon.
+pub trait ConfValue: Sized {
+    fn from_conf(s: &str) -> Option<Self>;
+    fn to_conf(&self) -> String;
+
+    /// The value [`Rule`](super::Rule)s check, `None` for non-numeric fields.
+    fn number(&self) -> Option<f64> {
+        None
+    }
+}
+
+macro_rules! conf_value_by_parse {
+    ($($ty:ty),+) => {
+        $(
+            impl ConfValue for $ty {
+                fn from_conf(s: &str) -> Option<Self> {
+                    s.parse().ok()
+                }
+
+                fn to_conf(&self) -> String {
+                    self.to_string()
+                }
+
+                fn number(&self) -> Option<f64> {
+                    Some(f64::from(*self))
+                }
+            }
+        )+
+    };
+}
+
+conf_value_by_parse!(i32, u16, f32, f64);
+
+impl ConfValue for bool {
+    fn from_conf(s: &str) -> Option<Self> {
+        match s {
+            "true" | "on" | "1" => Some(true),
+            "false" | "off" | "0" => Some(false),
+            _ => None,
+        }
+    }
+
+    fn to_conf(&self) -> String {
+        self.to_string()
+    }
+}
+
+impl ConfValue for String {
+    fn from_conf(s: &str) -> Option<Self> {
+        let unquoted = ['\'', '"'].iter()
+            .find_map(|q| s.strip_prefix(*q).and_then(|s| s.strip_suffix(*q)))
+            .unwrap_or(s);
+        Some(unquoted.to_string())
+    }
+
+    fn to_conf(&self) -> String {
+        format!("'{self}'")
+    }
+}
+
+/// The `(line number, name, value)` entries of `namespace` in a configuration
+/// file, comments and entries of other namespaces are skipped.
+pub(crate) fn entries<'a>(content: &'a str, namespace: &str) -> ConfigResult<Vec<(usize, &'a str, &'a str)>> {
+    let prefix = format!("{namespace}::");
+    let mut ret = vec![];
+    for (idx, line) in content.lines().enumerate() {
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('#') {
+            continue;
+        }
+        let (key, value) = line.split_once(" : ")
+            .or_else(|| line.rsplit_once(':').filter(|(key, _)| !key.ends_with(':')))
+            .ok_or(ConfigError::InvalidLine { line: idx + 1 })?;
+        let Some(name) = key.trim().strip_prefix(&prefix) else {
+            continue;
+        };
+        ret.push((idx + 1, name, value.trim()));
+    }
+    Ok(ret)
+}
+
+pub(crate) fn read(path: &Path) -> ConfigResult<Option<String>> {
+    match std::fs::read_to_string(path) {
+        Ok(content) => Ok(Some(content)),
+        Err(e) if e.kind() == std::io::ErrorKind::NotFound => Ok(None),
+        Err(e) => Err(e.into()),
+    }
+}
@@ -0,0 +1,263 @@
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use super::{conf, ConfigChange, ConfigResult, ConfigViolation, CsvSaverConfig, PlayerConfig, ServerConfig};
+
+pub const LOG_DIR: &str = "./log";
+
+/// File names rcssserver reads its configuration from, e.g. in `~/.rcssserver`.
+pub const SERVER_CONF: &str = "server.conf";
+pub const PLAYER_CONF: &str = "player.conf";
+pub const CSV_SAVER_CONF: &str = "CSVSaver.conf";
+
+/// Parts missing when deserializing are left empty rather than taking the log
+/// dirs of [`Config::default`], so a partial config can be merged as overrides.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Config {
+    #[serde(default)]
+    pub server: ServerConfig,
+    #[serde(default)]
+    pub player: PlayerConfig,
+    #[serde(default)]
+    pub csv_saver: CsvSaverConfig,
+}
+
+impl Default for Config {
+    fn default() -> Self {
+        let mut ret = Self::empty();
+
+        ret.with_all_log_dir(LOG_DIR);
+
+        ret
+    }
+}
+
+impl Config {
+    pub fn to_args(&self) -> Vec<String> {
+        let mut args = vec![];
+        args.append(&mut self.server.to_args());
+        args.append(&mut self.player.to_args());
+        args.append(&mut self.csv_saver.to_args());
+        args
+    }
+
+    /// Every field left to rcssserver, unlike [`Config::default`] which sets
+    /// the log dirs.
+    pub fn empty() -> Self {
+        Self {
+            server: ServerConfig::default(),
+            player: PlayerConfig::default(),
+            csv_saver: CsvSaverConfig::default(),
+        }
+    }
+
+    pub fn default_trainer_on() -> Self {
+        let mut ret = Self::default();
+        ret.server_then(|cfg| {
+            cfg.coach(true).coach_w_referee(true).synch_mode(true);
+        });
+        ret
+    }
+
+    pub fn with_ports(&mut self, port: u16, coach_port: u16, olcoach_port: u16) -> &mut Self {
+        self.server_then(|c| {
+            c.port(port)
+                .coach_port(coach_port)
+                .olcoach_port(olcoach_port);
+        })
+    }
+
+    pub fn with_sync(&mut self, sync: bool) -> &mut Self {
+        self.server_then(|c| {
+            c.synch_mode(sync);
+        })
+    }
+
+    pub fn with_log_dir(&mut self, log_dir: &str) -> &mut Self {
+        self.server_then(|c| {
+            c.game_log_dir(log_dir.to_string());
+        })
+    }
+
+    pub fn with_all_log_dir(&mut self, log_dir: &str) -> &mut Self {
+        self.server_then(|c| {
+            c.game_log_dir(log_dir.to_string());
+            c.text_log_dir(log_dir.to_string());
+            c.keepaway_log_dir(log_dir.to_string());
+        })
+    }
+
+    /// Takes over every field `other` sets.
+    pub fn merge(&mut self, other: &Config) -> &mut Self {
+        self.server.merge(&other.server);
+        self.player.merge(&other.player);
+        self.csv_saver.merge(&other.csv_saver);
+        self
+    }
+
+    /// Every field rule and rule across fields the config breaks.
+    pub fn validate(&self) -> Result<(), Vec<ConfigViolation>> {
+        let mut violations = self.server.violations();
+        violations.extend(self.server.cross_violations());
+        violations.extend(self.player.violations());
+        violations.extend(self.player.cross_violations());
+        violations.extend(self.csv_saver.violations());
+        match violations.is_empty() {
+            true => Ok(()),
+            false => Err(violations),
+        }
+    }
+
+    /// Fields `other` sets differently, server first.
+    pub fn diff(&self, other: &Config) -> Vec<ConfigChange> {
+        let mut ret = self.server.diff(&other.server);
+        ret.extend(self.player.diff(&other.player));
+        ret.extend(self.csv_saver.diff(&other.csv_saver));
+        ret
+    }
+
+    /// Reads `server.conf`, `player.conf` and `CSVSaver.conf` from `dir`,
+    /// missing files leave their part empty but `dir` itself must be readable.
+    pub fn load_dir(dir: impl AsRef<Path>) -> ConfigResult<Self> {
+        let dir = dir.as_ref();
+        std::fs::read_dir(dir)?;
+        let mut ret = Self::empty();
+        if let Some(content) = conf::read(&dir.join(SERVER_CONF))? {
+            ret.server = ServerConfig::from_conf(&content)?;
+        }
+        if let Some(content) = conf::read(&dir.join(PLAYER_CONF))? {
+            ret.player = PlayerConfig::from_conf(&content)?;
+        }
+        if let Some(content) = conf::read(&dir.join(CSV_SAVER_CONF))? {
+            ret.csv_saver = CsvSaverConfig::from_conf(&content)?;
+        }
+        Ok(ret)
+    }
+
+    /// Writes the three files [`Config::load_dir`] reads.
+    pub fn save_dir(&self, dir: impl AsRef<Path>) -> ConfigResult<()> {
+        let dir = dir.as_ref();
+        std::fs::create_dir_all(dir)?;
+        std::fs::write(dir.join(SERVER_CONF), self.server.to_conf())?;
+        std::fs::write(dir.join(PLAYER_CONF), self.player.to_conf())?;
+        std::fs::write(dir.join(CSV_SAVER_CONF), self.csv_saver.to_conf())?;
+        Ok(())
+    }
+
+    #[inline]
+    pub fn with_server(&mut self, server: ServerConfig) -> &mut Self {
+        self.server = server;
+        self
+    }
+    #[inline]
+    pub fn with_player(&mut self, player: PlayerConfig) -> &mut Self {
+        self.player = player;
+        self
+    }
+    #[inline]
+    pub fn with_csv_saver(&mut self, csv_saver: CsvSaverConfig) -> &mut Self {
+        self.csv_saver = csv_saver;
+        self
+    }
+
+    #[inline]
+    pub fn server_then(&mut self, f: impl FnOnce(&mut ServerConfig)) -> &mut Self {
+        f(&mut self.server);
+        self
+    }
+    #[inline]
+    pub fn player_then(&mut self, f: impl FnOnce(&mut PlayerConfig)) -> &mut Self {
+        f(&mut self.player);
+        self
+    }
+    #[inline]
+    pub fn csv_saver_then(&mut self, f: impl FnOnce(&mut CsvSaverConfig)) -> &mut Self {
+        f(&mut self.csv_saver);
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conf_round_trip() {
+        let mut config = Config::default_trainer_on();
+        config.server_then(|c| {
+            c.half_time(300).keepaway_length(20.5).team_l_start("./start.sh 'left'".to_string());
+        });
+        config.csv_saver_then(|c| {
+            c.save(true);
+        });
+
+        let dir = std::env::temp_dir().join(format!("rcss-conf-{}", std::process::id()));
+        config.save_dir(&dir).unwrap();
+        assert_eq!(Config::load_dir(&dir).unwrap(), config);
+        std::fs::remove_dir_all(&dir).unwrap();
+        assert!(matches!(Config::load_dir(&dir), Err(crate::process::config::ConfigError::Io(_))));
+
+        let json = serde_json::to_string(&config).unwrap();
+        assert!(json.contains(r#""game_log_dir":"./log""#));
+        assert_eq!(serde_json::from_str::<Config>(&json).unwrap(), config);
+    }
+
+    #[test]
+    fn test_validate_and_diff() {
+        let mut config = Config::default_trainer_on();
+        config.with_ports(6000, 6001, 6002);
+        assert_eq!(config.validate(), Ok(()));
+        // rcssserver's own synch offsets
+        let mut stock = config.clone();
+        stock.server_then(|c| {
+            c.synch_offset(60).synch_see_offset(0);
+        });
+        assert_eq!(stock.validate(), Ok(()));
+
+        let mut broken = config.clone();
+        broken.server_then(|c| {
+            c.coach_port(6000).game_log_version(7).minpower(100.0).maxpower(-100.0).ball_decay(0.94);
+        });
+        broken.player_then(|c| {
+            c.player_decay_delta_min(0.2).player_decay_delta_max(-0.1);
+        });
+        let fields: Vec<_> = broken.validate().unwrap_err().into_iter().map(|v| v.field).collect();
+        assert_eq!(fields, vec![
+            "server::game_log_version",
+            "server::coach_port",
+            "server::minpower",
+            "player::player_decay_delta_min",
+        ]);
+
+        let diff = config.diff(&broken);
+        assert_eq!(diff.len(), 7);
+        assert_eq!(diff[0], ConfigChange {
+            field: "server::coach_port".to_string(),
+            old: Some("6001".to_string()),
+            new: Some("6000".to_string()),
+        });
+        assert!(diff.iter().any(|c| c.field == "server::ball_decay" && c.old.is_none()));
+    }
+
+    #[test]
+    fn test_read_rcssserver_conf() {
+        let server = ServerConfig::from_conf("\
+# server::auto_mode
+server::auto_mode : on
+
+server::game_log_dir : './'
+server::half_time : 300
+server::no_such_param : 1
+player::pt_max : 1
+").unwrap();
+        assert_eq!(server.auto_mode, Some(true));
+        assert_eq!(server.game_log_dir.as_deref(), Some("./"));
+        assert_eq!(server.half_time, Some(300));
+
+        assert!(matches!(
+            ServerConfig::from_conf("server::half_time : half"),
+            Err(crate::process::config::ConfigError::InvalidValue { line: 1, .. })
+        ));
+    }
+}
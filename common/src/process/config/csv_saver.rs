@@ -3,9 +3,9 @@
 use crate::create_config;
 
 create_config! (CsvSaverConfig, "CSVSaver", {
-    version: &'static str,
+    version: String,
     save: bool,
-    filename: &'static str,
+    filename: String,
 });
 
 // impl Default for CsvSaverConfig {
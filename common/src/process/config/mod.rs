@@ -0,0 +1,131 @@
+mod config;
+pub mod conf;
+pub mod csv_saver;
+pub mod player;
+pub mod preset;
+pub mod server;
+pub mod validate;
+
+pub use csv_saver::CsvSaverConfig;
+pub use player::PlayerConfig;
+pub use server::ServerConfig;
+
+pub use config::Config;
+pub use preset::Presets;
+pub use conf::{ConfValue, ConfigError, ConfigResult};
+pub use validate::{ConfigChange, ConfigViolation, Rule};
+
+#[macro_export]
+macro_rules! create_config {
+    ($ident:ident, $namespace:literal, {$($field:ident: $value:ty $(=> $rule:expr)?),+$(,)?}) => {
+        /// Every field left `None` keeps rcssserver's default.
+        #[derive(Clone, Debug, Default, PartialEq, serde::Serialize, serde::Deserialize)]
+        #[serde(default)]
+        pub struct $ident {
+            $(
+                #[serde(skip_serializing_if = "Option::is_none")]
+                pub $field: Option<$value>,
+            )*
+        }
+
+        impl $ident {
+            $(
+                pub fn $field(&mut self, value: $value) -> &mut Self {
+                    self.$field = Some(value);
+                    self
+                }
+            )*
+        }
+
+        impl $ident {
+            pub const NAMESPACE: &'static str = $namespace;
+
+            pub fn to_args(&self) -> Vec<String> {
+                let mut args = vec![];
+                $(
+                    if let Some(value) = &self.$field {
+                        args.push(format!("{}::{}={value}", $namespace, stringify!($field)));
+                    }
+                )*
+                args
+            }
+
+            /// Takes over every field `other` sets.
+            pub fn merge(&mut self, other: &Self) -> &mut Self {
+                $(
+                    if let Some(value) = &other.$field {
+                        self.$field = Some(value.clone());
+                    }
+                )*
+                self
+            }
+
+            /// Reads rcssserver's configuration file format, names this
+            /// config does not know are skipped.
+            pub fn from_conf(content: &str) -> $crate::process::config::ConfigResult<Self> {
+                use $crate::process::config::{ConfValue, ConfigError};
+
+                let mut ret = Self::default();
+                for (line, name, value) in $crate::process::config::conf::entries(content, $namespace)? {
+                    match name {
+                        $(
+                            stringify!($field) => {
+                                let parsed = <$value as ConfValue>::from_conf(value)
+                                    .ok_or_else(|| ConfigError::InvalidValue {
+                                        line,
+                                        key: format!("{}::{name}", $namespace),
+                                        value: value.to_string(),
+                                    })?;
+                                ret.$field = Some(parsed);
+                            }
+                        )*
+                        _ => log::debug!("[Config] Skipped unknown {}::{name}", $namespace),
+                    }
+                }
+                Ok(ret)
+            }
+
+            /// Fields set outside what their [`Rule`](crate::process::config::Rule) allows.
+            pub fn violations(&self) -> Vec<$crate::process::config::ConfigViolation> {
+                use $crate::process::config::ConfigViolation;
+
+                let checks: Vec<Option<ConfigViolation>> = vec![$($(
+                    self.$field.as_ref()
+                        .and_then(|value| $crate::process::config::Rule::check(&$rule, value).err())
+                        .map(|message| ConfigViolation::new(concat!($namespace, "::", stringify!($field)), message)),
+                )?)*];
+                checks.into_iter().flatten().collect()
+            }
+
+            /// Fields `other` sets differently, in declaration order.
+            pub fn diff(&self, other: &Self) -> Vec<$crate::process::config::ConfigChange> {
+                use $crate::process::config::{ConfValue, ConfigChange};
+
+                let mut ret = vec![];
+                $(
+                    if self.$field != other.$field {
+                        ret.push(ConfigChange {
+                            field: concat!($namespace, "::", stringify!($field)).to_string(),
+                            old: self.$field.as_ref().map(|v| v.to_conf()),
+                            new: other.$field.as_ref().map(|v| v.to_conf()),
+                        });
+                    }
+                )*
+                ret
+            }
+
+            /// Writes the fields set in rcssserver's configuration file format.
+            pub fn to_conf(&self) -> String {
+                use $crate::process::config::ConfValue;
+
+                let mut ret = format!("# {} configuration file\n", $namespace);
+                $(
+                    if let Some(value) = &self.$field {
+                        ret.push_str(&format!("{}::{} : {}\n", $namespace, stringify!($field), value.to_conf()));
+                    }
+                )*
+                ret
+            }
+        }
+    }
+}
@@ -1,11 +1,13 @@
 use crate::create_config;
+use super::{ConfigViolation, Rule};
+use super::validate::order;
 
 create_config!(PlayerConfig, "player", {
-    version: &'static str,
-    player_types: i32,
-    pt_max: i32,
+    version: String,
+    player_types: i32 => Rule::at_least(1.0),
+    pt_max: i32 => Rule::at_least(1.0),
     random_seed: i32,
-    subs_max: i32,
+    subs_max: i32 => Rule::at_least(0.0),
     allow_mult_default_type: bool,
     catchable_area_l_stretch_max: f32,
     catchable_area_l_stretch_min: f32,
@@ -33,6 +35,22 @@ create_config!(PlayerConfig, "player", {
     stamina_inc_max_delta_factor: f32,
 });
 
+impl PlayerConfig {
+    /// Every `*_min` of a heterogeneous player parameter at most its `*_max`.
+    pub fn cross_violations(&self) -> Vec<ConfigViolation> {
+        [
+            order(("player::catchable_area_l_stretch_min", self.catchable_area_l_stretch_min), ("player::catchable_area_l_stretch_max", self.catchable_area_l_stretch_max), false),
+            order(("player::dash_power_rate_delta_min", self.dash_power_rate_delta_min), ("player::dash_power_rate_delta_max", self.dash_power_rate_delta_max), false),
+            order(("player::extra_stamina_delta_min", self.extra_stamina_delta_min), ("player::extra_stamina_delta_max", self.extra_stamina_delta_max), false),
+            order(("player::kick_power_rate_delta_min", self.kick_power_rate_delta_min), ("player::kick_power_rate_delta_max", self.kick_power_rate_delta_max), false),
+            order(("player::kickable_margin_delta_min", self.kickable_margin_delta_min), ("player::kickable_margin_delta_max", self.kickable_margin_delta_max), false),
+            order(("player::new_dash_power_rate_delta_min", self.new_dash_power_rate_delta_min), ("player::new_dash_power_rate_delta_max", self.new_dash_power_rate_delta_max), false),
+            order(("player::player_decay_delta_min", self.player_decay_delta_min), ("player::player_decay_delta_max", self.player_decay_delta_max), false),
+            order(("player::player_speed_max_delta_min", self.player_speed_max_delta_min), ("player::player_speed_max_delta_max", self.player_speed_max_delta_max), false),
+        ].into_iter().flatten().collect()
+    }
+}
+
 // impl Default for PlayerConfig {
 //     fn default() -> Self {
 //         Self {
@@ -0,0 +1,142 @@
+//! Named sets of rcssserver parameters merged over the spawner's [`Config`]
+//! with [`Config::merge`], and overridden the same way.
+
+use std::collections::BTreeMap;
+
+use super::Config;
+
+/// Presets by name, starting with [`Presets::BUILTIN`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Presets {
+    presets: BTreeMap<String, Config>,
+}
+
+impl Default for Presets {
+    fn default() -> Self {
+        let presets = Self::BUILTIN.iter()
+            .filter_map(|name| Some((name.to_string(), Self::builtin(name)?)))
+            .collect();
+        Self { presets }
+    }
+}
+
+impl Presets {
+    pub const BUILTIN: [&'static str; 5] = ["official", "fast_training", "keepaway", "penalty", "debug"];
+    /// Environment variable naming the preset a containerised server starts from.
+    pub const ENV: &'static str = "RCSS_PRESET";
+    /// Environment variable holding a JSON [`Config`] merged over the preset.
+    pub const OVERRIDES_ENV: &'static str = "RCSS_OVERRIDES";
+
+    /// A built-in preset, setting only the fields the profile is about so
+    /// ports, log dirs and the trainer stay as spawned.
+    pub fn builtin(name: &str) -> Option<Config> {
+        let mut ret = Config::empty();
+        match name {
+            // the RoboCup competition setup, real time with full logs
+            "official" => ret.server_then(|c| {
+                c.synch_mode(false)
+                    .half_time(300)
+                    .nr_normal_halfs(2)
+                    .extra_half_time(100)
+                    .nr_extra_halfs(2)
+                    .penalty_shoot_outs(true)
+                    .use_offside(true)
+                    .game_logging(true)
+                    .text_logging(true);
+            }).player_then(|c| {
+                c.player_types(18).pt_max(1).subs_max(3);
+            }),
+            // as fast as the clients step, nothing written to disk
+            "fast_training" => ret.server_then(|c| {
+                c.synch_mode(true)
+                    .game_logging(false)
+                    .text_logging(false)
+                    .keepaway_logging(false)
+                    .record_messages(false)
+                    .profile(false);
+            }),
+            "keepaway" => ret.server_then(|c| {
+                c.synch_mode(true)
+                    .keepaway(true)
+                    .keepaway_length(20.0)
+                    .keepaway_width(20.0)
+                    .keepaway_logging(true)
+                    .use_offside(false)
+                    .game_logging(false)
+                    .text_logging(false);
+            }),
+            // penalties only, set up by the trainer rather than after a drawn match
+            "penalty" => ret.server_then(|c| {
+                c.synch_mode(true)
+                    .penalty_shoot_outs(true)
+                    .pen_nr_kicks(5)
+                    .pen_max_extra_kicks(5)
+                    .pen_coach_moves_players(true)
+                    .use_offside(false)
+                    .game_logging(false)
+                    .text_logging(false);
+            }),
+            "debug" => ret.server_then(|c| {
+                c.synch_mode(true)
+                    .game_logging(true)
+                    .text_logging(true)
+                    .keepaway_logging(true)
+                    .record_messages(true)
+                    .log_times(true)
+                    .profile(true)
+                    .verbose(true);
+            }),
+            _ => return None,
+        };
+        Some(ret)
+    }
+
+    pub fn insert(&mut self, name: String, config: Config) -> Option<Config> {
+        self.presets.insert(name, config)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Config> {
+        self.presets.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Config> {
+        self.presets.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Config)> + '_ {
+        self.presets.iter().map(|(name, config)| (name.as_str(), config))
+    }
+
+    /// The preset `name` with every field `overrides` sets taken over.
+    pub fn resolve(&self, name: &str, overrides: &Config) -> Option<Config> {
+        let mut ret = self.get(name)?.clone();
+        ret.merge(overrides);
+        Some(ret)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_builtin_presets() {
+        let presets = Presets::default();
+        assert_eq!(presets.iter().count(), Presets::BUILTIN.len());
+        for (name, config) in presets.iter() {
+            assert_eq!(config.validate(), Ok(()), "preset {name}");
+            assert_eq!(config.server.game_log_dir, None, "preset {name}");
+        }
+
+        let overrides: Config = serde_json::from_str(r#"{"server":{"keepaway_width":30.0}}"#).unwrap();
+        assert_eq!(overrides.server.game_log_dir, None);
+        let keepaway = presets.resolve("keepaway", &overrides).unwrap();
+        assert_eq!((keepaway.server.keepaway, keepaway.server.keepaway_width), (Some(true), Some(30.0)));
+
+        let mut spawned = Config::default_trainer_on();
+        spawned.merge(presets.get("official").unwrap());
+        assert_eq!((spawned.server.coach, spawned.server.synch_mode), (Some(true), Some(false)));
+
+        assert!(presets.resolve("friendly", &overrides).is_none());
+    }
+}
@@ -1,9 +1,11 @@
 //! https://github.com/rcsoccersim/rcssserver/blob/master/src/playerparam.cpp
 
 use crate::create_config;
+use super::{ConfigViolation, Rule};
+use super::validate::{order, unique};
 
 create_config! (ServerConfig, "server", {
-    version: &'static str,
+    version: String,
     catch_ban_cycle: i32,
     clang_advice_win: i32,
     clang_define_win: i32,
@@ -14,15 +16,15 @@ create_config! (ServerConfig, "server", {
     clang_meta_win: i32,
     clang_rule_win: i32,
     clang_win_size: i32,
-    coach_port: u16,
+    coach_port: u16 => Rule::at_least(1.0),
     connect_wait: i32,
     drop_ball_time: i32,
     extra_half_time: i32,
     foul_cycles: i32,
     freeform_send_period: i32,
     freeform_wait_period: i32,
-    game_log_compression: i32,
-    game_log_version: i32,
+    game_log_compression: i32 => Rule::between(0.0, 9.0),
+    game_log_version: i32 => Rule::OneOf(&[1.0, 2.0, 3.0, 4.0, 5.0, 6.0]),
     game_over_wait: i32,
     goalie_max_moves: i32,
     half_time: i32,
@@ -34,35 +36,35 @@ create_config! (ServerConfig, "server", {
     keepaway_start: i32,
     kick_off_wait: i32,
     max_goal_kicks: i32,
-    max_monitors: i32,
-    nr_extra_halfs: i32,
-    nr_normal_halfs: i32,
-    olcoach_port: u16,
+    max_monitors: i32 => Rule::at_least(-1.0),
+    nr_extra_halfs: i32 => Rule::at_least(0.0),
+    nr_normal_halfs: i32 => Rule::at_least(0.0),
+    olcoach_port: u16 => Rule::at_least(1.0),
     pen_before_setup_wait: i32,
-    pen_max_extra_kicks: i32,
-    pen_nr_kicks: i32,
+    pen_max_extra_kicks: i32 => Rule::at_least(0.0),
+    pen_nr_kicks: i32 => Rule::at_least(1.0),
     pen_ready_wait: i32,
     pen_setup_wait: i32,
     pen_taken_wait: i32,
     point_to_ban: i32,
     point_to_duration: i32,
-    port: u16,
-    recv_step: i32,
+    port: u16 => Rule::at_least(1.0),
+    recv_step: i32 => Rule::at_least(1.0),
     say_coach_cnt_max: i32,
     say_coach_msg_size: i32,
-    say_msg_size: i32,
-    send_step: i32,
-    send_vi_step: i32,
-    sense_body_step: i32,
-    simulator_step: i32,
-    slow_down_factor: i32,
+    say_msg_size: i32 => Rule::at_least(1.0),
+    send_step: i32 => Rule::at_least(1.0),
+    send_vi_step: i32 => Rule::at_least(1.0),
+    sense_body_step: i32 => Rule::at_least(1.0),
+    simulator_step: i32 => Rule::at_least(1.0),
+    slow_down_factor: i32 => Rule::at_least(1.0),
     start_goal_l: i32,
     start_goal_r: i32,
     synch_micro_sleep: i32,
-    synch_offset: i32,
-    synch_see_offset: i32,
+    synch_offset: i32 => Rule::at_least(0.0),
+    synch_see_offset: i32 => Rule::at_least(0.0),
     tackle_cycles: i32,
-    text_log_compression: i32,
+    text_log_compression: i32 => Rule::between(0.0, 9.0),
     auto_mode: bool,
     back_passes: bool,
     coach: bool,
@@ -101,13 +103,13 @@ create_config! (ServerConfig, "server", {
     audio_cut_dist: f64,
     back_dash_rate: f64,
     ball_accel_max: f64,
-    ball_decay: f64,
+    ball_decay: f64 => Rule::unit(),
     ball_rand: f64,
-    ball_size: f64,
-    ball_speed_max: f64,
+    ball_size: f64 => Rule::at_least(0.0),
+    ball_speed_max: f64 => Rule::at_least(0.0),
     ball_stuck_area: f64,
     ball_weight: f64,
-    catch_probability: f64,
+    catch_probability: f64 => Rule::unit(),
     catchable_area_l: f64,
     catchable_area_w: f64,
     ckick_margin: f64,
@@ -119,18 +121,18 @@ create_config! (ServerConfig, "server", {
     effort_dec_thr: f64,
     effort_inc: f64,
     effort_inc_thr: f64,
-    effort_init: f64,
-    effort_min: f64,
+    effort_init: f64 => Rule::unit(),
+    effort_min: f64 => Rule::unit(),
     extra_stamina: f64,
     focus_dist_noise_rate: f64,
-    foul_detect_probability: f64,
+    foul_detect_probability: f64 => Rule::unit(),
     foul_exponent: f64,
-    goal_width: f64,
+    goal_width: f64 => Rule::at_least(0.0),
     illegal_defense_dist_x: f64,
     illegal_defense_width: f64,
     inertia_moment: f64,
-    keepaway_length: f64,
-    keepaway_width: f64,
+    keepaway_length: f64 => Rule::between(0.0, 105.0),
+    keepaway_width: f64 => Rule::between(0.0, 68.0),
     kick_power_rate: f64,
     kick_rand: f64,
     kick_rand_factor_l: f64,
@@ -139,19 +141,19 @@ create_config! (ServerConfig, "server", {
     land_dist_noise_rate: f64,
     land_focus_dist_noise_rate: f64,
     max_back_tackle_power: f64,
-    max_catch_angle: f64,
-    max_dash_angle: f64,
+    max_catch_angle: f64 => Rule::angle(),
+    max_dash_angle: f64 => Rule::angle(),
     max_dash_power: f64,
     max_tackle_power: f64,
-    maxmoment: f64,
-    maxneckang: f64,
+    maxmoment: f64 => Rule::angle(),
+    maxneckang: f64 => Rule::angle(),
     maxneckmoment: f64,
     maxpower: f64,
-    min_catch_angle: f64,
-    min_dash_angle: f64,
+    min_catch_angle: f64 => Rule::angle(),
+    min_dash_angle: f64 => Rule::angle(),
     min_dash_power: f64,
-    minmoment: f64,
-    minneckang: f64,
+    minmoment: f64 => Rule::angle(),
+    minneckang: f64 => Rule::angle(),
     minneckmoment: f64,
     minpower: f64,
     offside_active_area_size: f64,
@@ -159,10 +161,10 @@ create_config! (ServerConfig, "server", {
     pen_dist_x: f64,
     pen_max_goalie_dist_x: f64,
     player_accel_max: f64,
-    player_decay: f64,
+    player_decay: f64 => Rule::unit(),
     player_rand: f64,
-    player_size: f64,
-    player_speed_max: f64,
+    player_size: f64 => Rule::at_least(0.0),
+    player_speed_max: f64 => Rule::at_least(0.0),
     player_speed_max_min: f64,
     player_weight: f64,
     prand_factor_l: f64,
@@ -171,15 +173,15 @@ create_config! (ServerConfig, "server", {
     quantize_step_l: f64,
     recover_dec: f64,
     recover_dec_thr: f64,
-    recover_init: f64,
-    recover_min: f64,
-    red_card_probability: f64,
+    recover_init: f64 => Rule::unit(),
+    recover_min: f64 => Rule::unit(),
+    red_card_probability: f64 => Rule::unit(),
     side_dash_rate: f64,
     slowness_on_top_for_left_team: f64,
     slowness_on_top_for_right_team: f64,
     stamina_capacity: f64,
     stamina_inc_max: f64,
-    stamina_max: f64,
+    stamina_max: f64 => Rule::at_least(0.0),
     stopped_ball_vel: f64,
     tackle_back_dist: f64,
     tackle_dist: f64,
@@ -187,27 +189,49 @@ create_config! (ServerConfig, "server", {
     tackle_power_rate: f64,
     tackle_rand_factor: f64,
     tackle_width: f64,
-    visible_angle: f64,
-    visible_distance: f64,
+    visible_angle: f64 => Rule::between(0.0, 360.0),
+    visible_distance: f64 => Rule::at_least(0.0),
     wind_ang: f64,
     wind_dir: f64,
     wind_force: f64,
     wind_rand: f64,
-    coach_msg_file: &'static str,
-    fixed_teamname_l: &'static str,
-    fixed_teamname_r: &'static str,
-    game_log_dir: &'static str,
-    game_log_fixed_name: &'static str,
-    keepaway_log_dir: &'static str,
-    keepaway_log_fixed_name: &'static str,
-    landmark_file: &'static str,
-    log_date_format: &'static str,
-    team_l_start: &'static str,
-    team_r_start: &'static str,
-    text_log_dir: &'static str,
-    text_log_fixed_name: &'static str,
+    coach_msg_file: String,
+    fixed_teamname_l: String,
+    fixed_teamname_r: String,
+    game_log_dir: String,
+    game_log_fixed_name: String,
+    keepaway_log_dir: String,
+    keepaway_log_fixed_name: String,
+    landmark_file: String,
+    log_date_format: String,
+    team_l_start: String,
+    team_r_start: String,
+    text_log_dir: String,
+    text_log_fixed_name: String,
 });
 
+impl ServerConfig {
+    /// Rules across fields, only checked once every field involved is set.
+    pub fn cross_violations(&self) -> Vec<ConfigViolation> {
+        let mut ret = unique(&[
+            ("server::port", self.port),
+            ("server::coach_port", self.coach_port),
+            ("server::olcoach_port", self.olcoach_port),
+        ]);
+        ret.extend([
+            order(("server::minpower", self.minpower), ("server::maxpower", self.maxpower), false),
+            order(("server::minmoment", self.minmoment), ("server::maxmoment", self.maxmoment), false),
+            order(("server::minneckang", self.minneckang), ("server::maxneckang", self.maxneckang), false),
+            order(("server::minneckmoment", self.minneckmoment), ("server::maxneckmoment", self.maxneckmoment), false),
+            order(("server::min_dash_power", self.min_dash_power), ("server::max_dash_power", self.max_dash_power), false),
+            order(("server::min_dash_angle", self.min_dash_angle), ("server::max_dash_angle", self.max_dash_angle), false),
+            order(("server::min_catch_angle", self.min_catch_angle), ("server::max_catch_angle", self.max_catch_angle), false),
+            order(("server::player_speed_max_min", self.player_speed_max_min), ("server::player_speed_max", self.player_speed_max), false),
+        ].into_iter().flatten());
+        ret
+    }
+}
+
 // impl Default for ServerConfig {
 //     fn default() -> Self {
 //         Self {
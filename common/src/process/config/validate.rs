@@ -0,0 +1,103 @@
+//! Rules attached to the fields of [`create_config!`](crate::create_config)
+//! configs and the checks across fields rcssserver silently misbehaves on.
+
+use std::fmt;
+
+use serde::Serialize;
+
+use super::ConfValue;
+
+/// A constraint on a numeric field, fields of other types have none.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Rule {
+    /// Inclusive bounds, `None` leaves that side open.
+    Range { min: Option<f64>, max: Option<f64> },
+    /// One of the listed values, for fields rcssserver treats as an enum.
+    OneOf(&'static [f64]),
+}
+
+impl Rule {
+    pub const fn at_least(min: f64) -> Self {
+        Rule::Range { min: Some(min), max: None }
+    }
+
+    pub const fn between(min: f64, max: f64) -> Self {
+        Rule::Range { min: Some(min), max: Some(max) }
+    }
+
+    /// Probabilities and decays.
+    pub const fn unit() -> Self {
+        Self::between(0.0, 1.0)
+    }
+
+    /// Angles in degrees.
+    pub const fn angle() -> Self {
+        Self::between(-180.0, 180.0)
+    }
+
+    pub fn check<V: ConfValue>(&self, value: &V) -> Result<(), String> {
+        let Some(number) = value.number() else {
+            return Ok(());
+        };
+        match *self {
+            Rule::Range { min: Some(min), .. } if number < min => Err(format!("{number} is below {min}")),
+            Rule::Range { max: Some(max), .. } if number > max => Err(format!("{number} is above {max}")),
+            Rule::OneOf(values) if !values.contains(&number) => Err(format!("{number} is not one of {values:?}")),
+            _ => Ok(()),
+        }
+    }
+}
+
+/// A field set to a value rcssserver would misbehave on.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ConfigViolation {
+    /// `NAMESPACE::name`, e.g. `server::half_time`.
+    pub field: String,
+    pub message: String,
+}
+
+impl ConfigViolation {
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self { field: field.into(), message: message.into() }
+    }
+}
+
+impl fmt::Display for ConfigViolation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.field, self.message)
+    }
+}
+
+/// A field set differently in two configs, values as in a `.conf` file and
+/// `None` where rcssserver's default applies.
+#[derive(Serialize, Clone, Debug, PartialEq)]
+pub struct ConfigChange {
+    pub field: String,
+    pub old: Option<String>,
+    pub new: Option<String>,
+}
+
+/// `low` above `high`, or not strictly below it when `strict`, once both are set.
+pub(crate) fn order<T: PartialOrd + fmt::Display>(
+    (low_field, low): (&str, Option<T>),
+    (high_field, high): (&str, Option<T>),
+    strict: bool,
+) -> Option<ConfigViolation> {
+    let (low, high) = (low?, high?);
+    let valid = if strict { low < high } else { low <= high };
+    let relation = if strict { "below" } else { "at most" };
+    (!valid).then(|| ConfigViolation::new(low_field, format!("{low} must be {relation} {high_field} ({high})")))
+}
+
+/// Fields of `fields` sharing a value, each reported once against the first.
+pub(crate) fn unique<T: PartialEq + fmt::Display>(fields: &[(&str, Option<T>)]) -> Vec<ConfigViolation> {
+    let mut ret = vec![];
+    for (idx, (field, value)) in fields.iter().enumerate() {
+        let Some(value) = value else { continue };
+        let first = fields[..idx].iter().find(|(_, other)| other.as_ref() == Some(value));
+        if let Some((other, _)) = first {
+            ret.push(ConfigViolation::new(*field, format!("{value} is already taken by {other}")));
+        }
+    }
+    ret
+}
@@ -1,3 +1,4 @@
+pub mod config;
 pub mod error;
 pub mod process;
 pub mod status;
@@ -0,0 +1,302 @@
+//! Declarative set-piece situations, written in TOML or JSON.
+//!
+//! ```toml
+//! play_mode = "corner_kick_l"
+//!
+//! [ball]
+//! pos = { x = 51.5, y = -33.0 }
+//!
+//! [[players]]
+//! side = "left"
+//! unum = 10
+//! pos = { x = 51.0, y = -34.0 }
+//! dir = 120.0
+//! ```
+//!
+//! Positions are rcssserver field coordinates, the left team attacks towards
+//! positive x whatever side the scenario is about.
+
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::command::trainer;
+use crate::types::{PlayMode, Side, Vec2, play_mode_name};
+
+#[derive(thiserror::Error, Debug)]
+pub enum ScenarioError {
+    #[error("Failed to read scenario file: {0}")]
+    Io(#[from] std::io::Error),
+    #[error("Invalid TOML scenario: {0}")]
+    Toml(#[from] toml::de::Error),
+    #[error("Invalid JSON scenario: {0}")]
+    Json(#[from] serde_json::Error),
+    #[error("Unknown scenario format '{0}', expected toml or json")]
+    UnknownFormat(String),
+}
+
+pub type ScenarioResult<T> = Result<T, ScenarioError>;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct BallState {
+    pub pos: Vec2,
+    #[serde(default)]
+    pub vel: Option<Vec2>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+pub struct PlayerState {
+    pub side: Side,
+    pub unum: u8,
+    pub pos: Vec2,
+    /// Body direction in degrees, kept as it is when omitted.
+    #[serde(default)]
+    pub dir: Option<f64>,
+    /// rcssserver can only recover stamina to its maximum, lower values are
+    /// kept for the record.
+    #[serde(default)]
+    pub stamina: Option<u16>,
+}
+
+/// The ball and players of a situation and the play mode it resumes in.
+///
+/// Players left out stay where they are.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Scenario {
+    #[serde(default)]
+    pub description: Option<String>,
+    #[serde(default = "Scenario::default_play_mode", with = "play_mode_name")]
+    pub play_mode: PlayMode,
+    /// The server cycle to set the situation up at, right away when omitted
+    /// or already past.
+    #[serde(default)]
+    pub cycle: Option<u16>,
+    #[serde(default)]
+    pub ball: Option<BallState>,
+    #[serde(default)]
+    pub players: Vec<PlayerState>,
+}
+
+impl Default for Scenario {
+    fn default() -> Self {
+        Self {
+            description: None,
+            play_mode: Self::default_play_mode(),
+            cycle: None,
+            ball: None,
+            players: vec![],
+        }
+    }
+}
+
+impl Scenario {
+    fn default_play_mode() -> PlayMode {
+        PlayMode::PM_PlayOn
+    }
+
+    pub fn from_toml(s: &str) -> ScenarioResult<Self> {
+        Ok(toml::from_str(s)?)
+    }
+
+    pub fn from_json(s: &str) -> ScenarioResult<Self> {
+        Ok(serde_json::from_str(s)?)
+    }
+
+    /// Reads a `.toml` or `.json` file.
+    pub fn load(path: impl AsRef<Path>) -> ScenarioResult<Self> {
+        let path = path.as_ref();
+        let content = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Self::from_toml(&content),
+            Some("json") => Self::from_json(&content),
+            ext => Err(ScenarioError::UnknownFormat(ext.unwrap_or_default().to_string())),
+        }
+    }
+
+    /// The trainer moves placing the ball and players, rcssserver names
+    /// players by team, hence the team names of both sides.
+    pub fn moves(&self, team_l: &str, team_r: &str) -> Vec<trainer::Move> {
+        let ball = self.ball.map(|ball| {
+            let mut cmd = trainer::Move::ball(ball.pos);
+            if let Some(vel) = ball.vel {
+                cmd.with_vel(vel);
+            }
+            cmd
+        });
+        let players = self.players.iter().map(|p| {
+            let team = match p.side {
+                Side::LEFT => team_l,
+                _ => team_r,
+            };
+            let mut cmd = trainer::Move::player(team.to_string(), p.unum, p.pos);
+            if let Some(dir) = p.dir {
+                cmd.with_dir(dir);
+            }
+            cmd
+        });
+        ball.into_iter().chain(players).collect()
+    }
+
+    /// The same situation with the sides swapped, positions are mirrored
+    /// through the centre spot.
+    pub fn mirrored(&self) -> Self {
+        let flip = |v: Vec2| Vec2::new(-v.x, -v.y);
+        Self {
+            description: self.description.clone(),
+            play_mode: mirror_play_mode(self.play_mode),
+            cycle: self.cycle,
+            ball: self.ball.map(|ball| BallState {
+                pos: flip(ball.pos),
+                vel: ball.vel.map(flip),
+            }),
+            players: self.players.iter().map(|p| PlayerState {
+                side: match p.side {
+                    Side::LEFT => Side::RIGHT,
+                    Side::RIGHT => Side::LEFT,
+                    Side::NEUTRAL => Side::NEUTRAL,
+                },
+                pos: flip(p.pos),
+                dir: p.dir.map(|dir| if dir > 0.0 { dir - 180.0 } else { dir + 180.0 }),
+                ..*p
+            }).collect(),
+        }
+    }
+
+    /// A corner kick for `side` from the top corner of the goal it attacks.
+    pub fn corner_kick(side: Side) -> Self {
+        let left = Self {
+            description: Some("Corner kick from the top corner".to_string()),
+            play_mode: PlayMode::PM_CornerKick_Left,
+            cycle: None,
+            ball: Some(BallState { pos: Vec2::new(51.5, -33.0), vel: None }),
+            players: vec![
+                player(Side::LEFT, 10, 51.0, -34.0, 120.0),
+                player(Side::LEFT, 9, 44.0, -5.0, -90.0),
+                player(Side::LEFT, 11, 42.0, 6.0, -90.0),
+                player(Side::RIGHT, 1, 51.5, 0.0, 180.0),
+                player(Side::RIGHT, 2, 47.0, -4.0, 180.0),
+                player(Side::RIGHT, 3, 46.0, 4.0, 180.0),
+            ],
+        };
+        left.for_side(side)
+    }
+
+    /// A penalty kick for `side`, the kicker behind the ball and the
+    /// opposing goalie on the line.
+    pub fn penalty_kick(side: Side) -> Self {
+        let left = Self {
+            description: Some("Penalty kick".to_string()),
+            play_mode: PlayMode::PM_PK_Left,
+            cycle: None,
+            ball: Some(BallState { pos: Vec2::new(41.5, 0.0), vel: None }),
+            players: vec![
+                player(Side::LEFT, 10, 40.0, 0.0, 0.0),
+                player(Side::RIGHT, 1, 52.0, 0.0, 180.0),
+            ],
+        };
+        left.for_side(side)
+    }
+
+    /// A striker of `side` through on goal with a defender chasing.
+    pub fn breakaway(side: Side) -> Self {
+        let left = Self {
+            description: Some("Breakaway, one on one with the goalie".to_string()),
+            play_mode: PlayMode::PM_PlayOn,
+            cycle: None,
+            ball: Some(BallState { pos: Vec2::new(25.0, 0.0), vel: None }),
+            players: vec![
+                player(Side::LEFT, 9, 24.0, 0.0, 0.0),
+                player(Side::RIGHT, 1, 50.0, 0.0, 180.0),
+                player(Side::RIGHT, 2, 18.0, 4.0, 0.0),
+            ],
+        };
+        left.for_side(side)
+    }
+
+    /// The scenarios every service knows, by name.
+    pub fn builtin() -> Vec<(&'static str, Self)> {
+        vec![
+            ("corner_kick_l", Self::corner_kick(Side::LEFT)),
+            ("corner_kick_r", Self::corner_kick(Side::RIGHT)),
+            ("penalty_kick_l", Self::penalty_kick(Side::LEFT)),
+            ("penalty_kick_r", Self::penalty_kick(Side::RIGHT)),
+            ("breakaway_l", Self::breakaway(Side::LEFT)),
+            ("breakaway_r", Self::breakaway(Side::RIGHT)),
+        ]
+    }
+
+    fn for_side(self, side: Side) -> Self {
+        match side {
+            Side::RIGHT => self.mirrored(),
+            _ => self,
+        }
+    }
+}
+
+fn player(side: Side, unum: u8, x: f64, y: f64, dir: f64) -> PlayerState {
+    PlayerState { side, unum, pos: Vec2::new(x, y), dir: Some(dir), stamina: None }
+}
+
+/// `corner_kick_l` and `corner_kick_r` swapped, modes without a side stay.
+fn mirror_play_mode(play_mode: PlayMode) -> PlayMode {
+    let name = play_mode.encode();
+    let mirrored = if let Some(base) = name.strip_suffix("_l") {
+        format!("{base}_r")
+    } else if let Some(base) = name.strip_suffix("_r") {
+        format!("{base}_l")
+    } else {
+        return play_mode;
+    };
+    PlayMode::decode(&mirrored).unwrap_or(play_mode)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::command::Command;
+
+    #[test]
+    fn test_scenario_formats() {
+        let toml = r#"
+            play_mode = "free_kick_r"
+            cycle = 120
+
+            [ball]
+            pos = { x = -20.0, y = 10.0 }
+            vel = { x = 1.0, y = 0.0 }
+
+            [[players]]
+            side = "right"
+            unum = 7
+            pos = { x = -19.0, y = 10.0 }
+            dir = 180.0
+            stamina = 6000
+        "#;
+        let scenario = Scenario::from_toml(toml).unwrap();
+        assert_eq!(scenario.play_mode, PlayMode::PM_FreeKick_Right);
+        assert_eq!(scenario.cycle, Some(120));
+        assert_eq!(scenario.players[0].stamina, Some(6000));
+
+        let json = serde_json::to_string(&scenario).unwrap();
+        assert!(json.contains(r#""play_mode":"free_kick_r""#));
+        assert_eq!(Scenario::from_json(&json).unwrap(), scenario);
+
+        let moves: Vec<_> = scenario.moves("L", "R").iter().map(|m| m.encode()).collect();
+        assert_eq!(moves, vec!["(move (ball) -20 10 0 1 0)", "(move (player R 7) -19 10 180)"]);
+
+        assert!(Scenario::from_json(r#"{"play_mode": "corner"}"#).is_err());
+    }
+
+    #[test]
+    fn test_builtin_mirrored() {
+        let left = Scenario::corner_kick(Side::LEFT);
+        let right = Scenario::corner_kick(Side::RIGHT);
+        assert_eq!(right.play_mode, PlayMode::PM_CornerKick_Right);
+        assert_eq!(right.ball.unwrap().pos, Vec2::new(-51.5, 33.0));
+        assert_eq!(right.players[0].side, Side::RIGHT);
+        assert_eq!(right.players[0].dir, Some(-60.0));
+        assert_eq!(right.mirrored(), left);
+
+        assert_eq!(Scenario::builtin().len(), 6);
+    }
+}
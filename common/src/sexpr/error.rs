@@ -0,0 +1,19 @@
+#[derive(thiserror::Error, Debug, Clone, PartialEq, Eq)]
+pub enum Error {
+    #[error("Empty input")]
+    Empty,
+
+    #[error("Unexpected ')' at {pos}")]
+    UnexpectedClose { pos: usize },
+
+    #[error("Unclosed '(' opened at {pos}")]
+    UnclosedList { pos: usize },
+
+    #[error("Unterminated string starting at {pos}")]
+    UnterminatedString { pos: usize },
+
+    #[error("Trailing input at {pos}")]
+    TrailingInput { pos: usize },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
@@ -0,0 +1,89 @@
+use std::fmt::{Display, Formatter};
+use std::str::FromStr;
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum Sexpr<'a> {
+    /// A bare token, e.g. `ok`, `-12.5`, `goal_l_1`.
+    Atom(&'a str),
+    /// A double-quoted token, without the quotes, e.g. `"hello world"`.
+    Str(&'a str),
+    List(Vec<Sexpr<'a>>),
+}
+
+impl<'a> Sexpr<'a> {
+    pub fn parse(input: &'a str) -> super::Result<Self> {
+        super::parse(input)
+    }
+
+    pub fn is_atom(&self) -> bool {
+        matches!(self, Sexpr::Atom(_))
+    }
+
+    pub fn is_list(&self) -> bool {
+        matches!(self, Sexpr::List(_))
+    }
+
+    pub fn atom(&self) -> Option<&'a str> {
+        match self {
+            Sexpr::Atom(s) => Some(s),
+            _ => None,
+        }
+    }
+
+    /// Either a bare or a quoted token.
+    pub fn text(&self) -> Option<&'a str> {
+        match self {
+            Sexpr::Atom(s) | Sexpr::Str(s) => Some(s),
+            Sexpr::List(_) => None,
+        }
+    }
+
+    pub fn list(&self) -> Option<&[Sexpr<'a>]> {
+        match self {
+            Sexpr::List(items) => Some(items),
+            _ => None,
+        }
+    }
+
+    /// Parse an atom into `T`, `None` if this is not an atom or parsing fails.
+    pub fn parse_atom<T: FromStr>(&self) -> Option<T> {
+        self.atom()?.parse().ok()
+    }
+
+    /// The leading atom of a list, e.g. `see_global` for `(see_global 0 ...)`.
+    pub fn head(&self) -> Option<&'a str> {
+        self.list()?.first()?.atom()
+    }
+
+    /// Everything after the head of a list, empty for atoms and `()`.
+    pub fn args(&self) -> &[Sexpr<'a>] {
+        match self {
+            Sexpr::List(items) if !items.is_empty() => &items[1..],
+            _ => &[],
+        }
+    }
+
+    /// The first child list whose head is `name`.
+    pub fn find(&self, name: &str) -> Option<&Sexpr<'a>> {
+        self.args().iter().find(|item| item.head() == Some(name))
+    }
+}
+
+impl Display for Sexpr<'_> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Sexpr::Atom(s) => f.write_str(s),
+            Sexpr::Str(s) => write!(f, "\"{s}\""),
+            Sexpr::List(items) => {
+                f.write_str("(")?;
+                for (idx, item) in items.iter().enumerate() {
+                    if idx > 0 {
+                        f.write_str(" ")?;
+                    }
+                    write!(f, "{item}")?;
+                }
+                f.write_str(")")
+            }
+        }
+    }
+}
@@ -0,0 +1,14 @@
+//! S-expression parsing for rcssserver messages.
+//!
+//! Every message exchanged with rcssserver is a single s-expression, e.g.
+//! `(ok check_ball 0 in_field)` or `(see_global 0 ((b) 0 0 0 0) ...)`.
+//! [`parse`] turns such a message into a borrowed [`Sexpr`] tree: atoms are
+//! slices of the input, only lists allocate.
+
+mod error;
+mod parser;
+mod expr;
+
+pub use error::{Error, Result};
+pub use parser::parse;
+pub use expr::Sexpr;
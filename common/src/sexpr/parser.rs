@@ -0,0 +1,172 @@
+use super::{Error, Result, Sexpr};
+
+/// Parse exactly one s-expression out of `input`.
+///
+/// Surrounding whitespace and the trailing `'\0'` rcssserver appends to its
+/// datagrams are ignored; anything else after the expression is an error.
+pub fn parse(input: &str) -> Result<Sexpr<'_>> {
+    let mut parser = Parser::new(input);
+    let expr = parser.next_expr()?.ok_or(Error::Empty)?;
+
+    parser.skip_ws();
+    if parser.pos < parser.bytes.len() {
+        return Err(Error::TrailingInput { pos: parser.pos });
+    }
+
+    Ok(expr)
+}
+
+struct Parser<'a> {
+    src: &'a str,
+    bytes: &'a [u8],
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    fn new(src: &'a str) -> Self {
+        Self {
+            src,
+            bytes: src.as_bytes(),
+            pos: 0,
+        }
+    }
+
+    fn skip_ws(&mut self) {
+        while let Some(b) = self.bytes.get(self.pos)
+            && (b.is_ascii_whitespace() || *b == b'\0')
+        {
+            self.pos += 1;
+        }
+    }
+
+    /// Iterative on purpose, `see_global` frames nest a few levels deep and
+    /// hold ~25 lists, no need to pay for recursion.
+    fn next_expr(&mut self) -> Result<Option<Sexpr<'a>>> {
+        let mut stack: Vec<(usize, Vec<Sexpr<'a>>)> = Vec::new();
+
+        loop {
+            self.skip_ws();
+            let Some(&b) = self.bytes.get(self.pos) else {
+                return match stack.last() {
+                    Some((open, _)) => Err(Error::UnclosedList { pos: *open }),
+                    None => Ok(None),
+                };
+            };
+
+            let expr = match b {
+                b'(' => {
+                    stack.push((self.pos, Vec::new()));
+                    self.pos += 1;
+                    continue;
+                }
+                b')' => {
+                    let (_, items) = stack
+                        .pop()
+                        .ok_or(Error::UnexpectedClose { pos: self.pos })?;
+                    self.pos += 1;
+                    Sexpr::List(items)
+                }
+                b'"' => self.quoted()?,
+                _ => self.atom(),
+            };
+
+            match stack.last_mut() {
+                Some((_, items)) => items.push(expr),
+                None => return Ok(Some(expr)),
+            }
+        }
+    }
+
+    fn quoted(&mut self) -> Result<Sexpr<'a>> {
+        let open = self.pos;
+        let start = open + 1;
+        let len = self.bytes[start..]
+            .iter()
+            .position(|b| *b == b'"')
+            .ok_or(Error::UnterminatedString { pos: open })?;
+
+        self.pos = start + len + 1;
+        Ok(Sexpr::Str(&self.src[start..start + len]))
+    }
+
+    fn atom(&mut self) -> Sexpr<'a> {
+        let start = self.pos;
+        while let Some(b) = self.bytes.get(self.pos)
+            && !(b.is_ascii_whitespace() || matches!(b, b'(' | b')' | b'"' | b'\0'))
+        {
+            self.pos += 1;
+        }
+        Sexpr::Atom(&self.src[start..self.pos])
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use Sexpr::*;
+
+    #[test]
+    fn test_parse_flat() {
+        let expr = parse("(ok check_ball 12 in_field)").unwrap();
+        assert_eq!(
+            expr,
+            List(vec![Atom("ok"), Atom("check_ball"), Atom("12"), Atom("in_field")])
+        );
+        assert_eq!(expr.head(), Some("ok"));
+        assert_eq!(expr.args().len(), 3);
+        assert_eq!(expr.args()[1].parse_atom::<u16>(), Some(12));
+    }
+
+    #[test]
+    fn test_parse_nested() {
+        let raw = "(ok team_names (team l HELIOS) (team r CYRUS))\0";
+        let expr = parse(raw).unwrap();
+        let teams = &expr.args()[1..];
+        assert_eq!(teams.len(), 2);
+        assert_eq!(teams[0].head(), Some("team"));
+        assert_eq!(teams[1].args()[1].atom(), Some("CYRUS"));
+        assert_eq!(expr.to_string(), raw.trim_end_matches('\0'));
+    }
+
+    #[test]
+    fn test_parse_see_global() {
+        let raw = "(see_global 0 ((g r) 52.5 0) ((b) 0 0 0 0) ((p \"HELIOS\" 1 goalie) -50 0 0 0 0 0))";
+        let expr = parse(raw).unwrap();
+        assert_eq!(expr.head(), Some("see_global"));
+
+        let objects = &expr.args()[1..];
+        assert_eq!(objects.len(), 3);
+        assert_eq!(objects[1].list().unwrap()[0].head(), Some("b"));
+
+        let player = objects[2].list().unwrap();
+        assert_eq!(player[0].args()[0], Str("HELIOS"));
+        assert_eq!(player[0].args()[2].atom(), Some("goalie"));
+        assert_eq!(player[1].parse_atom::<f64>(), Some(-50.0));
+    }
+
+    #[test]
+    fn test_parse_quoted() {
+        let expr = parse("(hear 10 self \"pass (to) me\")").unwrap();
+        assert_eq!(expr.args()[2], Str("pass (to) me"));
+        assert_eq!(expr.args()[2].text(), Some("pass (to) me"));
+        assert_eq!(expr.args()[2].atom(), None);
+    }
+
+    #[test]
+    fn test_find() {
+        let expr = parse("(sense_body 0 (view_mode high normal) (stamina 8000 1 130600))").unwrap();
+        let stamina = expr.find("stamina").unwrap();
+        assert_eq!(stamina.args()[0].parse_atom::<f64>(), Some(8000.0));
+        assert!(expr.find("speed").is_none());
+    }
+
+    #[test]
+    fn test_parse_errors() {
+        assert_eq!(parse(""), Err(Error::Empty));
+        assert_eq!(parse("  \0"), Err(Error::Empty));
+        assert_eq!(parse("(ok (look)"), Err(Error::UnclosedList { pos: 0 }));
+        assert_eq!(parse("(ok))"), Err(Error::TrailingInput { pos: 4 }));
+        assert_eq!(parse(")"), Err(Error::UnexpectedClose { pos: 0 }));
+        assert_eq!(parse("(say \"oops)"), Err(Error::UnterminatedString { pos: 5 }));
+    }
+}
@@ -0,0 +1,35 @@
+//! The stopping declaration the allocator annotates a game server with and
+//! the service reads back.
+
+use serde::{Deserialize, Serialize};
+
+#[derive(Deserialize, Serialize, Default, Debug, Clone, PartialEq, Eq)]
+pub struct StoppingEvent {
+    pub timeup: Option<u16>,
+    pub goal_l: Option<u32>,
+    pub goal_r: Option<u32>,
+}
+
+impl StoppingEvent {
+    /// A goal limit of 0 is reached before the kick-off.
+    pub fn verify(&self) -> Result<(), &'static str> {
+        if self.goal_l == Some(0) || self.goal_r == Some(0) {
+            return Err("goal limit must be at least 1");
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_verify_goal_limits() {
+        let stopping: StoppingEvent = serde_json::from_str(r#"{"timeup":6000,"goal_l":3}"#).unwrap();
+        assert_eq!(stopping.verify(), Ok(()));
+
+        let stopping = StoppingEvent { goal_r: Some(0), ..stopping };
+        assert!(stopping.verify().is_err());
+    }
+}
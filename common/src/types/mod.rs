@@ -3,12 +3,18 @@ mod ear_mode;
 mod eye_mode;
 mod play_mode;
 mod side;
+mod vec2;
+mod view_mode;
+mod world;
 
 pub use ball_position::BallPosition;
 pub use ear_mode::EarMode;
 pub use eye_mode::EyeMode;
-pub use play_mode::PlayMode;
+pub use play_mode::{PlayMode, play_mode_name};
 pub use side::Side;
+pub use vec2::Vec2;
+pub use view_mode::{ViewQuality, ViewWidth};
+pub use world::{BallSnapshot, PlayerSnapshot, WorldSnapshot};
 
 pub static STR_HAY: [&str; 100] = [
     "0", "1", "2", "3", "4", "5", "6", "7", "8", "9", "10", "11", "12", "13", "14", "15", "16",
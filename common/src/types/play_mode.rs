@@ -2,7 +2,7 @@
 
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 #[repr(C)]
 #[serde(rename_all = "snake_case")]
@@ -63,8 +63,211 @@ pub enum PlayMode {
     PM_MAX,
 }
 
+/// Names as sent by rcssserver, indexed by the enum discriminant.
+const PLAY_MODE_STRINGS: [&str; PlayMode::PM_MAX as usize] = [
+    "",
+    "before_kick_off",
+    "time_over",
+    "play_on",
+    "kick_off_l",
+    "kick_off_r",
+    "kick_in_l",
+    "kick_in_r",
+    "free_kick_l",
+    "free_kick_r",
+    "corner_kick_l",
+    "corner_kick_r",
+    "goal_kick_l",
+    "goal_kick_r",
+    "goal_l",
+    "goal_r",
+    "drop_ball",
+    "offside_l",
+    "offside_r",
+    "penalty_kick_l",
+    "penalty_kick_r",
+    "first_half_over",
+    "pause",
+    "human_judge",
+    "foul_charge_l",
+    "foul_charge_r",
+    "foul_push_l",
+    "foul_push_r",
+    "foul_multiple_attack_l",
+    "foul_multiple_attack_r",
+    "foul_ballout_l",
+    "foul_ballout_r",
+    "back_pass_l",
+    "back_pass_r",
+    "free_kick_fault_l",
+    "free_kick_fault_r",
+    "catch_fault_l",
+    "catch_fault_r",
+    "indirect_free_kick_l",
+    "indirect_free_kick_r",
+    "penalty_setup_l",
+    "penalty_setup_r",
+    "penalty_ready_l",
+    "penalty_ready_r",
+    "penalty_taken_l",
+    "penalty_taken_r",
+    "penalty_miss_l",
+    "penalty_miss_r",
+    "penalty_score_l",
+    "penalty_score_r",
+    "illegal_defense_l",
+    "illegal_defense_r",
+];
+
 impl PlayMode {
     pub fn encode(self) -> &'static str {
-        super::usize_to_str(self as usize)
+        PLAY_MODE_STRINGS.get(self as usize).copied().unwrap_or_default()
+    }
+
+    pub fn decode(s: &str) -> Option<Self> {
+        if s.is_empty() {
+            return None;
+        }
+        let idx = PLAY_MODE_STRINGS.iter().position(|name| *name == s)?;
+        PlayMode::ALL.get(idx).copied()
+    }
+
+    const ALL: [PlayMode; PlayMode::PM_MAX as usize] = [
+        PlayMode::PM_Null,
+        PlayMode::PM_BeforeKickOff,
+        PlayMode::PM_TimeOver,
+        PlayMode::PM_PlayOn,
+        PlayMode::PM_KickOff_Left,
+        PlayMode::PM_KickOff_Right,
+        PlayMode::PM_KickIn_Left,
+        PlayMode::PM_KickIn_Right,
+        PlayMode::PM_FreeKick_Left,
+        PlayMode::PM_FreeKick_Right,
+        PlayMode::PM_CornerKick_Left,
+        PlayMode::PM_CornerKick_Right,
+        PlayMode::PM_GoalKick_Left,
+        PlayMode::PM_GoalKick_Right,
+        PlayMode::PM_AfterGoal_Left,
+        PlayMode::PM_AfterGoal_Right,
+        PlayMode::PM_Drop_Ball,
+        PlayMode::PM_OffSide_Left,
+        PlayMode::PM_OffSide_Right,
+        PlayMode::PM_PK_Left,
+        PlayMode::PM_PK_Right,
+        PlayMode::PM_FirstHalfOver,
+        PlayMode::PM_Pause,
+        PlayMode::PM_Human,
+        PlayMode::PM_Foul_Charge_Left,
+        PlayMode::PM_Foul_Charge_Right,
+        PlayMode::PM_Foul_Push_Left,
+        PlayMode::PM_Foul_Push_Right,
+        PlayMode::PM_Foul_MultipleAttacker_Left,
+        PlayMode::PM_Foul_MultipleAttacker_Right,
+        PlayMode::PM_Foul_BallOut_Left,
+        PlayMode::PM_Foul_BallOut_Right,
+        PlayMode::PM_Back_Pass_Left,
+        PlayMode::PM_Back_Pass_Right,
+        PlayMode::PM_Free_Kick_Fault_Left,
+        PlayMode::PM_Free_Kick_Fault_Right,
+        PlayMode::PM_CatchFault_Left,
+        PlayMode::PM_CatchFault_Right,
+        PlayMode::PM_IndFreeKick_Left,
+        PlayMode::PM_IndFreeKick_Right,
+        PlayMode::PM_PenaltySetup_Left,
+        PlayMode::PM_PenaltySetup_Right,
+        PlayMode::PM_PenaltyReady_Left,
+        PlayMode::PM_PenaltyReady_Right,
+        PlayMode::PM_PenaltyTaken_Left,
+        PlayMode::PM_PenaltyTaken_Right,
+        PlayMode::PM_PenaltyMiss_Left,
+        PlayMode::PM_PenaltyMiss_Right,
+        PlayMode::PM_PenaltyScore_Left,
+        PlayMode::PM_PenaltyScore_Right,
+        PlayMode::PM_Illegal_Defense_Left,
+        PlayMode::PM_Illegal_Defense_Right,
+    ];
+}
+
+impl std::str::FromStr for PlayMode {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <PlayMode as std::str::FromStr>::Err> {
+        Self::decode(s).ok_or(())
+    }
+}
+
+/// Play modes by their rcssserver name, e.g. `corner_kick_l`, for fields
+/// `#[serde(with = "play_mode_name")]` rather than the variant name.
+pub mod play_mode_name {
+    use serde::{Deserialize, Deserializer, Serializer, de};
+
+    use super::PlayMode;
+
+    pub fn serialize<S: Serializer>(play_mode: &PlayMode, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(play_mode.encode())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PlayMode, D::Error> {
+        let name = String::deserialize(deserializer)?;
+        PlayMode::decode(&name)
+            .ok_or_else(|| de::Error::custom(format!("unknown play mode '{name}'")))
+    }
+
+    /// The same for `Option<PlayMode>`, `null` for `None`.
+    pub mod option {
+        use serde::{Deserialize, Deserializer, Serializer};
+
+        use super::PlayMode;
+
+        pub fn serialize<S: Serializer>(play_mode: &Option<PlayMode>, serializer: S) -> Result<S::Ok, S::Error> {
+            match play_mode {
+                Some(play_mode) => super::serialize(play_mode, serializer),
+                None => serializer.serialize_none(),
+            }
+        }
+
+        pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Option<PlayMode>, D::Error> {
+            #[derive(Deserialize)]
+            struct Named(#[serde(with = "super")] PlayMode);
+
+            Ok(Option::<Named>::deserialize(deserializer)?.map(|Named(play_mode)| play_mode))
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_encode_decode() {
+        assert_eq!(PlayMode::PM_PlayOn.encode(), "play_on");
+        assert_eq!(PlayMode::PM_IndFreeKick_Right.encode(), "indirect_free_kick_r");
+        assert_eq!(PlayMode::PM_MAX.encode(), "");
+
+        for mode in PlayMode::ALL.iter().skip(1) {
+            assert_eq!(PlayMode::decode(mode.encode()), Some(*mode));
+        }
+        assert_eq!(PlayMode::decode(""), None);
+        assert_eq!(PlayMode::decode("goal_l_1"), None);
+    }
+
+    #[test]
+    fn test_play_mode_name_serde() {
+        #[derive(Serialize, Deserialize, Debug, PartialEq)]
+        struct Modes {
+            #[serde(with = "play_mode_name")]
+            current: PlayMode,
+            #[serde(with = "play_mode_name::option")]
+            last: Option<PlayMode>,
+        }
+
+        let modes = Modes { current: PlayMode::PM_PlayOn, last: Some(PlayMode::PM_CornerKick_Left) };
+        let json = serde_json::to_string(&modes).unwrap();
+        assert_eq!(json, r#"{"current":"play_on","last":"corner_kick_l"}"#);
+        assert_eq!(serde_json::from_str::<Modes>(&json).unwrap(), modes);
+
+        let none: Modes = serde_json::from_str(r#"{"current":"before_kick_off","last":null}"#).unwrap();
+        assert_eq!(none.last, None);
+        assert!(serde_json::from_str::<Modes>(r#"{"current":"PM_PlayOn","last":null}"#).is_err());
     }
 }
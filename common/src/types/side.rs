@@ -1,11 +1,36 @@
 use serde::{Deserialize, Serialize};
 
-#[derive(Serialize, Deserialize, Debug, Copy, Clone)]
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, PartialEq, Eq, Hash)]
 #[allow(non_camel_case_types)]
 #[repr(i8)]
 #[serde(rename_all = "lowercase")]
 pub enum Side {
     LEFT = 1,
     NEUTRAL = 0,
-    RIGHT = -1
+    RIGHT = -1,
+}
+
+impl Side {
+    pub fn encode(self) -> &'static str {
+        match self {
+            Side::LEFT => "l",
+            Side::NEUTRAL => "n",
+            Side::RIGHT => "r",
+        }
+    }
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "l" => Some(Side::LEFT),
+            "n" => Some(Side::NEUTRAL),
+            "r" => Some(Side::RIGHT),
+            _ => None,
+        }
+    }
+}
+
+impl std::str::FromStr for Side {
+    type Err = ();
+    fn from_str(s: &str) -> Result<Self, <Side as std::str::FromStr>::Err> {
+        Self::decode(s).ok_or(())
+    }
 }
@@ -0,0 +1,20 @@
+use serde::{Deserialize, Serialize};
+
+/// A point or vector on the pitch, in rcssserver field coordinates.
+#[derive(Serialize, Deserialize, Debug, Copy, Clone, Default, PartialEq)]
+pub struct Vec2 {
+    pub x: f64,
+    pub y: f64,
+}
+
+impl Vec2 {
+    pub const fn new(x: f64, y: f64) -> Self {
+        Self { x, y }
+    }
+}
+
+impl From<(f64, f64)> for Vec2 {
+    fn from((x, y): (f64, f64)) -> Self {
+        Self { x, y }
+    }
+}
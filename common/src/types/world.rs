@@ -0,0 +1,201 @@
+use serde::{Deserialize, Serialize};
+
+use crate::sexpr::Sexpr;
+
+use super::{Side, Vec2};
+
+/// The whole field as seen by a trainer, from `(ok look ...)` or `(see_global ...)`.
+///
+/// Both messages carry the same body: `TIME` followed by one `(NAME VALUES...)`
+/// list per object. Goals are skipped, they never move.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct WorldSnapshot {
+    pub time: u16,
+    pub ball: BallSnapshot,
+    pub players: Vec<PlayerSnapshot>,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq)]
+pub struct BallSnapshot {
+    pub pos: Vec2,
+    pub vel: Vec2,
+}
+
+/// A player in a [`WorldSnapshot`].
+///
+/// rcssserver names players by team rather than by side, `side` stays `None`
+/// until [`WorldSnapshot::assign_sides`] is given the `team_names` reply.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct PlayerSnapshot {
+    pub team: String,
+    pub side: Option<Side>,
+    pub unum: u8,
+    pub goalie: bool,
+    pub pos: Vec2,
+    pub vel: Vec2,
+    /// Body direction in degrees.
+    pub body: f64,
+    /// Neck direction relative to the body, in degrees.
+    pub neck: f64,
+    /// Arm pointing direction, only sent while the player is pointing.
+    pub point_dir: Option<f64>,
+}
+
+impl WorldSnapshot {
+    /// Parse `TIME OBJECT...`, the part after `(ok look` or `(see_global`.
+    pub fn from_tokens(tokens: &[Sexpr]) -> Option<Self> {
+        let (time, objects) = tokens.split_first()?;
+        let mut ret = WorldSnapshot {
+            time: time.parse_atom()?,
+            ..Default::default()
+        };
+
+        for object in objects {
+            let [name, values @ ..] = object.list()? else {
+                return None;
+            };
+            let values = numbers(values);
+
+            match name.head()? {
+                "b" | "ball" | "Ball" => {
+                    let [x, y, vx, vy, ..] = values[..] else {
+                        return None;
+                    };
+                    ret.ball = BallSnapshot {
+                        pos: Vec2::new(x, y),
+                        vel: Vec2::new(vx, vy),
+                    };
+                }
+                "p" | "player" => ret.players.push(PlayerSnapshot::parse(name, &values)?),
+                "g" | "goal" => {}
+                _ => return None,
+            }
+        }
+
+        Some(ret)
+    }
+
+    /// Parse a whole unsolicited `(see_global TIME ...)` frame.
+    pub fn parse_see_global(msg: &Sexpr) -> Option<Self> {
+        if msg.head()? != "see_global" {
+            return None;
+        }
+        Self::from_tokens(msg.args())
+    }
+
+    /// Fill in [`PlayerSnapshot::side`] from the team names of both sides.
+    pub fn assign_sides(&mut self, left: Option<&str>, right: Option<&str>) -> &mut Self {
+        for player in self.players.iter_mut() {
+            player.side = if Some(player.team.as_str()) == left {
+                Some(Side::LEFT)
+            } else if Some(player.team.as_str()) == right {
+                Some(Side::RIGHT)
+            } else {
+                None
+            };
+        }
+        self
+    }
+
+    pub fn player(&self, side: Side, unum: u8) -> Option<&PlayerSnapshot> {
+        self.players
+            .iter()
+            .find(|p| p.side == Some(side) && p.unum == unum)
+    }
+
+    pub fn team<'a>(&'a self, team: &'a str) -> impl Iterator<Item = &'a PlayerSnapshot> {
+        self.players.iter().filter(move |p| p.team == team)
+    }
+}
+
+impl PlayerSnapshot {
+    /// `(p "TEAM" UNUM [goalie]) X Y VX VY BODY NECK [POINT_DIR] [FLAGS...]`
+    fn parse(name: &Sexpr, values: &[f64]) -> Option<Self> {
+        let [team, unum, rest @ ..] = name.args() else {
+            return None;
+        };
+        let [x, y, vx, vy, body, neck, ref point_dir @ ..] = values[..] else {
+            return None;
+        };
+
+        Some(PlayerSnapshot {
+            team: team.text()?.to_string(),
+            side: None,
+            unum: unum.parse_atom()?,
+            goalie: rest.iter().any(|s| s.atom() == Some("goalie")),
+            pos: Vec2::new(x, y),
+            vel: Vec2::new(vx, vy),
+            body,
+            neck,
+            point_dir: point_dir.first().copied(),
+        })
+    }
+}
+
+/// The leading numeric values of an object, newer protocols append flags such
+/// as `k` (kicking) or `t` (tackling) behind them.
+fn numbers(values: &[Sexpr]) -> Vec<f64> {
+    values.iter().map_while(|v| v.parse_atom()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sexpr;
+
+    #[test]
+    fn test_parse_see_global_v7() {
+        let raw = "(see_global 120 ((g l) -52.5 0) ((g r) 52.5 0) ((b) 1.5 -2 0.3 0) \
+            ((p \"HELIOS\" 1 goalie) -50 0 0 0 0 0) \
+            ((p \"CYRUS\" 9) 10.25 -3 0.1 0.2 -90 45 30 k))";
+        let msg = sexpr::parse(raw).unwrap();
+        let mut world = WorldSnapshot::parse_see_global(&msg).unwrap();
+
+        assert_eq!(world.time, 120);
+        assert_eq!(world.ball.pos, Vec2::new(1.5, -2.0));
+        assert_eq!(world.ball.vel, Vec2::new(0.3, 0.0));
+        assert_eq!(world.players.len(), 2);
+
+        let goalie = &world.players[0];
+        assert_eq!(goalie.team, "HELIOS");
+        assert!(goalie.goalie);
+        assert_eq!(goalie.point_dir, None);
+
+        let cyrus = &world.players[1];
+        assert_eq!(cyrus.unum, 9);
+        assert_eq!(cyrus.pos, Vec2::new(10.25, -3.0));
+        assert_eq!((cyrus.body, cyrus.neck), (-90.0, 45.0));
+        assert_eq!(cyrus.point_dir, Some(30.0));
+
+        world.assign_sides(Some("HELIOS"), Some("CYRUS"));
+        assert_eq!(world.player(Side::LEFT, 1).unwrap().team, "HELIOS");
+        assert_eq!(world.player(Side::RIGHT, 9).unwrap().team, "CYRUS");
+        assert!(world.player(Side::RIGHT, 1).is_none());
+    }
+
+    #[test]
+    fn test_parse_look_v1() {
+        let raw = "(ok look 0 ((goal l) -52.5 0) ((ball) 0 0 0 0) \
+            ((player HELIOS 2) -20 5 0 0 30 0))";
+        let msg = sexpr::parse(raw).unwrap();
+        let world = WorldSnapshot::from_tokens(&msg.args()[1..]).unwrap();
+
+        assert_eq!(world.time, 0);
+        assert_eq!(world.players.len(), 1);
+        assert_eq!(world.team("HELIOS").count(), 1);
+        assert_eq!(world.players[0].side, None);
+        assert_eq!(world.players[0].body, 30.0);
+    }
+
+    #[test]
+    fn test_parse_malformed() {
+        let msg = sexpr::parse("(see_global 1 ((b) 0 0))").unwrap();
+        assert!(WorldSnapshot::parse_see_global(&msg).is_none());
+
+        let msg = sexpr::parse("(see_global 1 ((p \"A\" 1) 0 0 0 0 0))").unwrap();
+        assert!(WorldSnapshot::parse_see_global(&msg).is_none());
+
+        let msg = sexpr::parse("(ok look 1)").unwrap();
+        assert!(WorldSnapshot::parse_see_global(&msg).is_none());
+    }
+}
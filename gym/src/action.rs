@@ -0,0 +1,89 @@
+use std::collections::HashMap;
+
+use serde::{Deserialize, Serialize};
+
+use common::command::player;
+use common::types::{Side, Vec2};
+use process::Player;
+
+use crate::{Error, Result};
+
+/// An agent as rcssserver numbers it, taken from its `init` reply.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct AgentId {
+    pub side: Side,
+    pub unum: u8,
+}
+
+impl AgentId {
+    pub fn new(side: Side, unum: u8) -> Self {
+        Self { side, unum }
+    }
+}
+
+/// One player command, rcssserver executes at most one body command per
+/// cycle, `turn_neck` may come along with it.
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Action {
+    Dash { power: f64, dir: Option<f64> },
+    Turn { moment: f64 },
+    TurnNeck { angle: f64 },
+    Kick { power: f64, dir: f64 },
+    Tackle { power_or_dir: f64, foul: bool },
+    Catch { dir: f64 },
+    /// Only honoured before kick-off and after goals.
+    Move { pos: Vec2 },
+}
+
+impl Action {
+    pub(crate) async fn send(&self, player: &Player) -> Result<()> {
+        let res = match *self {
+            Action::Dash { power, dir } => player.send(player::Dash { power, dir }).await,
+            Action::Turn { moment } => player.send(player::Turn { moment }).await,
+            Action::TurnNeck { angle } => player.send(player::TurnNeck { angle }).await,
+            Action::Kick { power, dir } => player.send(player::Kick { power, dir }).await,
+            Action::Tackle { power_or_dir, foul } => {
+                player.send(player::Tackle { power_or_dir, foul }).await
+            }
+            Action::Catch { dir } => player.send(player::Catch { dir }).await,
+            Action::Move { pos } => player.send(player::Move { pos }).await,
+        };
+        res.map_err(|e| Error::PlayerCommandFailed(e.to_string()))
+    }
+}
+
+/// The actions of every agent for one step, agents left out do nothing.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Actions {
+    agents: HashMap<AgentId, Vec<Action>>,
+}
+
+impl Actions {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn push(&mut self, agent: AgentId, action: Action) -> &mut Self {
+        self.agents.entry(agent).or_default().push(action);
+        self
+    }
+
+    pub fn get(&self, agent: AgentId) -> &[Action] {
+        self.agents.get(&agent).map(Vec::as_slice).unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.agents.values().all(Vec::is_empty)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (AgentId, &[Action])> + '_ {
+        self.agents.iter().map(|(&agent, actions)| (agent, actions.as_slice()))
+    }
+}
+
+impl From<HashMap<AgentId, Vec<Action>>> for Actions {
+    fn from(agents: HashMap<AgentId, Vec<Action>>) -> Self {
+        Self { agents }
+    }
+}
@@ -0,0 +1,489 @@
+use std::collections::HashMap;
+use std::time::Duration;
+
+use log::{debug, info, warn};
+use tokio::sync::{broadcast, watch};
+
+use common::command::Command;
+use common::command::player::CommandInit;
+use common::command::trainer::{self, TrainerCommand};
+use common::types::{EarMode, EyeMode, PlayMode, Side, WorldSnapshot};
+use process::addon::{Score, TrainerEvent};
+use process::{CoachedProcess, CoachedProcessSpawner, Player};
+
+use crate::{Actions, AgentId, Error, GoalReward, Info, Observation, Result, Reward, Scenario};
+
+const DEFAULT_PLAYER_PORT: u16 = 6000;
+const DEFAULT_STEP_TIMEOUT: Duration = Duration::from_secs(5);
+/// Idle cycles the clock may stand still for while a reset waits on it.
+const MAX_STALLED_CYCLES: u32 = 50;
+
+#[derive(Clone, Debug)]
+struct AgentSpec {
+    side: Side,
+    goalie: bool,
+}
+
+#[derive(Clone, Debug)]
+pub struct EnvBuilder {
+    /// Synch mode and the trainer are on by default, keep them on.
+    pub spawner: CoachedProcessSpawner,
+    agents: Vec<AgentSpec>,
+    team_l: String,
+    team_r: String,
+    max_steps: Option<u32>,
+    step_timeout: Duration,
+}
+
+impl EnvBuilder {
+    pub async fn new() -> Self {
+        let mut spawner = CoachedProcessSpawner::new().await;
+        spawner.with_sync_mode(true);
+
+        Self {
+            spawner,
+            agents: vec![],
+            team_l: "Left".to_string(),
+            team_r: "Right".to_string(),
+            max_steps: None,
+            step_timeout: DEFAULT_STEP_TIMEOUT,
+        }
+    }
+
+    /// Adds an agent to `side`, unums are handed out in the order agents are added.
+    pub fn with_agent(&mut self, side: Side, goalie: bool) -> &mut Self {
+        self.agents.push(AgentSpec { side, goalie });
+        self
+    }
+
+    pub fn with_team_names(&mut self, team_l: String, team_r: String) -> &mut Self {
+        self.team_l = team_l;
+        self.team_r = team_r;
+        self
+    }
+
+    /// Ends an episode after this many steps, `info.truncated` tells it apart.
+    pub fn with_max_steps(&mut self, max_steps: Option<u32>) -> &mut Self {
+        self.max_steps = max_steps;
+        self
+    }
+
+    /// How long a step waits for the next cycle.
+    pub fn with_step_timeout(&mut self, step_timeout: Duration) -> &mut Self {
+        self.step_timeout = step_timeout;
+        self
+    }
+
+    /// Spawns rcssserver and connects every agent, the left team first so
+    /// it gets the left side. Rewards default to [`GoalReward`] for the left side.
+    pub async fn build(&self) -> Result<Env> {
+        let process = self.spawner.spawn().await
+            .map_err(Error::ProcessSpawnFailed)?;
+        info!("[Env] Process spawned");
+
+        let coach = process.coach();
+        let world_rx = coach.see_global();
+        let events = coach.events();
+        call(&process, trainer::Eye { mode: EyeMode::On }).await?;
+        call(&process, trainer::Ear { mode: EarMode::On }).await?;
+
+        // picked at spawn when the spawner has a port allocator
+        let port = process.ports().map(|ports| ports.player)
+            .or(self.spawner.process.config.server.port)
+            .unwrap_or(DEFAULT_PLAYER_PORT);
+        let mut agents = HashMap::new();
+        let mut play_mode = PlayMode::PM_BeforeKickOff;
+        let ordered = self.agents.iter().filter(|a| a.side == Side::LEFT)
+            .chain(self.agents.iter().filter(|a| a.side != Side::LEFT));
+        for spec in ordered {
+            let team = match spec.side {
+                Side::LEFT => &self.team_l,
+                _ => &self.team_r,
+            };
+            let (id, player, mode) = connect(port, team, spec.goalie).await?;
+            if id.side != spec.side {
+                warn!("[Env] Agent of {team} joined as {:?}, the left team connects first", id.side);
+            }
+            play_mode = mode;
+            agents.insert(id, player);
+        }
+        info!("[Env] {} agents connected", agents.len());
+
+        let last = Observation {
+            time: 0,
+            play_mode,
+            score: Score::default(),
+            world: WorldSnapshot::default(),
+        };
+
+        Ok(Env {
+            process,
+            agents,
+            team_l: self.team_l.clone(),
+            team_r: self.team_r.clone(),
+            world_rx,
+            events,
+            reward: Box::new(GoalReward::new(Side::LEFT)),
+            last,
+            episode: 0,
+            steps: 0,
+            max_steps: self.max_steps,
+            step_timeout: self.step_timeout,
+        })
+    }
+}
+
+/// A learner's view of rcssserver in synch mode, one [`Env::step`] per cycle.
+pub struct Env {
+    process: CoachedProcess,
+    agents: HashMap<AgentId, Player>,
+    team_l: String,
+    team_r: String,
+    world_rx: watch::Receiver<Option<WorldSnapshot>>,
+    events: broadcast::Receiver<TrainerEvent>,
+    reward: Box<dyn Reward>,
+    last: Observation,
+    episode: u64,
+    steps: u32,
+    max_steps: Option<u32>,
+    step_timeout: Duration,
+}
+
+impl Env {
+    pub async fn builder() -> EnvBuilder {
+        EnvBuilder::new().await
+    }
+
+    pub fn set_reward(&mut self, reward: impl Reward + 'static) -> &mut Self {
+        self.reward = Box::new(reward);
+        self
+    }
+
+    pub fn agents(&self) -> impl Iterator<Item = AgentId> + '_ {
+        self.agents.keys().copied()
+    }
+
+    /// The observation the last `reset` or `step` returned.
+    pub fn observation(&self) -> &Observation {
+        &self.last
+    }
+
+    /// Sets up `scenario` and returns the first observation of a new episode.
+    ///
+    /// rcssserver keeps its clock and score running across episodes, the
+    /// reset runs idle cycles up to the scenario's cycle, moves things into
+    /// place, recovers every player and sets the play mode. The clock stands
+    /// still before the kick-off, so the reset kicks off to reach the cycle.
+    pub async fn reset(&mut self, scenario: &Scenario) -> Result<Observation> {
+        if let Some(cycle) = scenario.cycle {
+            run_clock(self, cycle).await?;
+        }
+        for cmd in scenario.moves(&self.team_l, &self.team_r) {
+            call(&self.process, cmd).await?;
+        }
+        call(&self.process, trainer::Recover).await?;
+        call(&self.process, trainer::ChangeMode { play_mode: scenario.play_mode }).await?;
+
+        // the moves show in the next see_global
+        let (observation, _) = self.advance(&Actions::new()).await?;
+        self.episode += 1;
+        self.steps = 0;
+        self.reward.reset(&observation);
+        self.last = observation.clone();
+        debug!("[Env] Episode {} reset at time {}", self.episode, observation.time);
+
+        Ok(observation)
+    }
+
+    /// Runs one cycle with `actions`, returning the observation, the reward,
+    /// whether the episode is over and what else happened.
+    ///
+    /// An episode ends on a goal, at time over, or after `max_steps`.
+    pub async fn step(&mut self, actions: &Actions) -> Result<(Observation, f64, bool, Info)> {
+        let (observation, events) = self.advance(actions).await?;
+        self.steps += 1;
+
+        let reward = self.reward.reward(&self.last, &observation);
+        let (done, truncated) = episode_end(&self.last, &observation, self.steps, self.max_steps);
+
+        let info = Info {
+            steps: self.steps,
+            episode: self.episode,
+            truncated,
+            events,
+        };
+        self.last = observation.clone();
+
+        Ok((observation, reward, done, info))
+    }
+
+    /// Says goodbye to every agent and shuts rcssserver down.
+    pub async fn close(mut self) -> Result<()> {
+        for (id, mut player) in self.agents.drain() {
+            if let Err(e) = player.shutdown().await {
+                warn!("[Env] Agent {id:?} failed to shutdown: {e}");
+            }
+        }
+        self.process.shutdown().await.map_err(Error::ProcessFailedToShutdown)
+    }
+
+    /// Sends `actions`, ends the cycle for every client and waits for the
+    /// next `see_global`, referee messages heard meanwhile come along.
+    async fn advance(&mut self, actions: &Actions) -> Result<(Observation, Vec<TrainerEvent>)> {
+        for (id, agent_actions) in actions.iter() {
+            let player = self.agents.get(&id).ok_or(Error::UnknownAgent(id))?;
+            for action in agent_actions {
+                action.send(player).await?;
+            }
+        }
+
+        self.world_rx.mark_unchanged();
+        for player in self.agents.values() {
+            player.send(common::command::player::Done).await
+                .map_err(|e| Error::PlayerCommandFailed(e.to_string()))?;
+        }
+        self.process.coach().send(trainer::Done).await
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?;
+
+        observe(&mut self.world_rx, &mut self.events, &self.last, (&self.team_l, &self.team_r), self.step_timeout).await
+    }
+}
+
+/// What [`run_clock`] needs of an [`Env`].
+trait Clock {
+    fn last(&self) -> &Observation;
+
+    async fn kick_off(&mut self) -> Result<()>;
+
+    /// Runs one cycle without actions.
+    async fn idle(&mut self) -> Result<()>;
+}
+
+impl Clock for Env {
+    fn last(&self) -> &Observation {
+        &self.last
+    }
+
+    async fn kick_off(&mut self) -> Result<()> {
+        call(&self.process, trainer::Start).await
+    }
+
+    async fn idle(&mut self) -> Result<()> {
+        let (observation, _) = self.advance(&Actions::new()).await?;
+        self.last = observation;
+        Ok(())
+    }
+}
+
+/// Runs idle cycles until the time reaches `cycle`, kicking off first if the
+/// match has not started. Fails once the clock stood still for
+/// [`MAX_STALLED_CYCLES`], e.g. at time over.
+async fn run_clock(clock: &mut impl Clock, cycle: u16) -> Result<()> {
+    if clock.last().time >= cycle {
+        return Ok(());
+    }
+    if clock.last().play_mode == PlayMode::PM_BeforeKickOff {
+        clock.kick_off().await?;
+    }
+
+    let mut stalled = 0;
+    while clock.last().time < cycle {
+        let time = clock.last().time;
+        clock.idle().await?;
+        stalled = if clock.last().time > time { 0 } else { stalled + 1 };
+        if stalled >= MAX_STALLED_CYCLES {
+            let last = clock.last();
+            return Err(Error::ClockStopped { time: last.time, play_mode: last.play_mode });
+        }
+    }
+    Ok(())
+}
+
+/// Waits for the next `see_global` and folds the trainer events heard since
+/// into the play mode and score of `last`.
+async fn observe(
+    world_rx: &mut watch::Receiver<Option<WorldSnapshot>>,
+    events_rx: &mut broadcast::Receiver<TrainerEvent>,
+    last: &Observation,
+    (team_l, team_r): (&str, &str),
+    step_timeout: Duration,
+) -> Result<(Observation, Vec<TrainerEvent>)> {
+    tokio::time::timeout(step_timeout, world_rx.changed()).await
+        .map_err(|_| Error::StepTimeout(step_timeout))?
+        .map_err(|_| Error::WorldChannelClosed)?;
+    let mut world = world_rx.borrow_and_update().clone().unwrap_or_default();
+    world.assign_sides(Some(team_l), Some(team_r));
+
+    let mut play_mode = last.play_mode;
+    let mut score = last.score;
+    let mut events = vec![];
+    loop {
+        match events_rx.try_recv() {
+            Ok(event) => {
+                match &event {
+                    TrainerEvent::PlayMode { play_mode: mode, .. } => play_mode = *mode,
+                    TrainerEvent::Goal { score: s, .. } => score = *s,
+                    _ => {}
+                }
+                events.push(event);
+            }
+            Err(broadcast::error::TryRecvError::Lagged(n)) => {
+                warn!("[Env] Missed {n} trainer events");
+            }
+            Err(_) => break,
+        }
+    }
+
+    let observation = Observation { time: world.time, play_mode, score, world };
+    Ok((observation, events))
+}
+
+/// Whether the step from `last` to `next` ends the episode, and whether it
+/// ended on the step limit alone: a goal or time over is no truncation.
+fn episode_end(last: &Observation, next: &Observation, steps: u32, max_steps: Option<u32>) -> (bool, bool) {
+    let goal = next.score != last.score;
+    let time_over = next.play_mode == PlayMode::PM_TimeOver;
+    let truncated = max_steps.is_some_and(|max| steps >= max);
+    (goal || time_over || truncated, truncated && !goal && !time_over)
+}
+
+async fn connect(port: u16, team: &str, goalie: bool) -> Result<(AgentId, Player, PlayMode)> {
+    let connect_failed = |msg: String| Error::AgentConnectFailed { team: team.to_string(), msg };
+
+    let mut builder = Player::builder();
+    builder.enable_resolver();
+    builder.rich_client
+        .with_name(format!("{team} Agent"))
+        .with_local_peer(port);
+    let player = builder.build_into().await.map_err(connect_failed)?;
+
+    let init = CommandInit {
+        team_name: team.to_string(),
+        is_goalie: goalie,
+        ..Default::default()
+    };
+    let ok = player.connect_and_init(init).await
+        .map_err(|e| connect_failed(e.to_string()))?;
+    debug!("[Env] Agent of {team} joined as {:?} {}", ok.side, ok.unum);
+
+    Ok((AgentId::new(ok.side, ok.unum), player, ok.play_mode))
+}
+
+async fn call<C>(process: &CoachedProcess, command: C) -> Result<C::Ok>
+where C: Command<Kind = TrainerCommand> {
+    process.coach().call(command).await
+        .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?
+        .map_err(|e| Error::TrainerCommandFailed(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use common::types::{PlayerSnapshot, Vec2};
+
+    use super::*;
+
+    fn observation(time: u16, play_mode: PlayMode, score: Score) -> Observation {
+        Observation { time, play_mode, score, world: WorldSnapshot::default() }
+    }
+
+    #[tokio::test]
+    async fn test_observe_folds_events() {
+        let (world_tx, mut world_rx) = watch::channel(None);
+        let (events_tx, mut events_rx) = broadcast::channel(2);
+        let last = observation(10, PlayMode::PM_PlayOn, Score::default());
+        let timeout = Duration::from_millis(20);
+
+        let ret = observe(&mut world_rx, &mut events_rx, &last, ("L", "R"), timeout).await;
+        assert!(matches!(ret, Err(Error::StepTimeout(_))));
+
+        let player = PlayerSnapshot {
+            team: "R".to_string(),
+            side: None,
+            unum: 1,
+            goalie: true,
+            pos: Vec2::default(),
+            vel: Vec2::default(),
+            body: 0.0,
+            neck: 0.0,
+            point_dir: None,
+        };
+        world_tx.send_replace(Some(WorldSnapshot { time: 11, players: vec![player], ..Default::default() }));
+        // the first event overflows the stream and is missed
+        events_tx.send(TrainerEvent::Referee { time: 10, message: "foul_charge_l".to_string() }).unwrap();
+        events_tx.send(TrainerEvent::Goal { time: 11, side: Side::LEFT, score: Score { left: 1, right: 0 } }).unwrap();
+        events_tx.send(TrainerEvent::PlayMode { time: 11, play_mode: PlayMode::PM_KickOff_Right }).unwrap();
+
+        let (next, events) = observe(&mut world_rx, &mut events_rx, &last, ("L", "R"), timeout).await.unwrap();
+        assert_eq!((next.time, next.play_mode, next.score), (11, PlayMode::PM_KickOff_Right, Score { left: 1, right: 0 }));
+        assert_eq!(next.world.players[0].side, Some(Side::RIGHT));
+        assert_eq!(events.len(), 2);
+
+        // nothing heard keeps the play mode and score of the last observation
+        world_tx.send_replace(Some(WorldSnapshot { time: 12, ..Default::default() }));
+        let (after, events) = observe(&mut world_rx, &mut events_rx, &next, ("L", "R"), timeout).await.unwrap();
+        assert_eq!((after.time, after.play_mode, after.score), (12, next.play_mode, next.score));
+        assert!(events.is_empty());
+
+        drop(world_tx);
+        let ret = observe(&mut world_rx, &mut events_rx, &after, ("L", "R"), timeout).await;
+        assert!(matches!(ret, Err(Error::WorldChannelClosed)));
+    }
+
+    #[test]
+    fn test_episode_end() {
+        let last = observation(10, PlayMode::PM_PlayOn, Score::default());
+        let play_on = observation(11, PlayMode::PM_PlayOn, Score::default());
+        let goal = observation(11, PlayMode::PM_KickOff_Right, Score { left: 1, right: 0 });
+        let time_over = observation(11, PlayMode::PM_TimeOver, Score::default());
+
+        assert_eq!(episode_end(&last, &play_on, 1, None), (false, false));
+        assert_eq!(episode_end(&last, &goal, 1, None), (true, false));
+        assert_eq!(episode_end(&last, &time_over, 1, Some(100)), (true, false));
+        assert_eq!(episode_end(&last, &play_on, 99, Some(100)), (false, false));
+        assert_eq!(episode_end(&last, &play_on, 100, Some(100)), (true, true));
+        // the limit reached on a goal step ends the episode on the goal
+        assert_eq!(episode_end(&last, &goal, 100, Some(100)), (true, false));
+    }
+
+    /// A server whose clock runs in every play mode but before the kick-off
+    /// and at time over.
+    struct FakeClock {
+        last: Observation,
+        kick_offs: usize,
+    }
+
+    impl Clock for FakeClock {
+        fn last(&self) -> &Observation {
+            &self.last
+        }
+
+        async fn kick_off(&mut self) -> Result<()> {
+            self.kick_offs += 1;
+            self.last.play_mode = PlayMode::PM_KickOff_Left;
+            Ok(())
+        }
+
+        async fn idle(&mut self) -> Result<()> {
+            if !matches!(self.last.play_mode, PlayMode::PM_BeforeKickOff | PlayMode::PM_TimeOver) {
+                self.last.time += 1;
+            }
+            Ok(())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run_clock_from_before_kick_off() {
+        let mut clock = FakeClock { last: observation(0, PlayMode::PM_BeforeKickOff, Score::default()), kick_offs: 0 };
+        run_clock(&mut clock, 20).await.unwrap();
+        assert_eq!((clock.last.time, clock.kick_offs), (20, 1));
+
+        // already past the cycle, nothing to run
+        run_clock(&mut clock, 5).await.unwrap();
+        assert_eq!((clock.last.time, clock.kick_offs), (20, 1));
+
+        let mut clock = FakeClock { last: observation(6000, PlayMode::PM_TimeOver, Score::default()), kick_offs: 0 };
+        let ret = run_clock(&mut clock, 6100).await;
+        assert!(matches!(ret, Err(Error::ClockStopped { time: 6000, play_mode: PlayMode::PM_TimeOver })));
+        assert_eq!(clock.kick_offs, 0);
+    }
+}
@@ -0,0 +1,33 @@
+use crate::AgentId;
+
+#[derive(thiserror::Error, Debug)]
+pub enum Error {
+    #[error("Failed to spawn process: {0}")]
+    ProcessSpawnFailed(#[source] process::Error),
+
+    #[error("Failed to shutdown the process: {0}")]
+    ProcessFailedToShutdown(#[source] process::Error),
+
+    #[error("An agent of team '{team}' failed to connect: {msg}")]
+    AgentConnectFailed { team: String, msg: String },
+
+    #[error("Player client failed: {0}")]
+    PlayerCommandFailed(String),
+
+    #[error("Failed to send trainer command: {0}")]
+    TrainerCommandFailed(String),
+
+    #[error("No agent {0:?} in this environment")]
+    UnknownAgent(AgentId),
+
+    #[error("No see_global within {0:?}, is the server in synch mode?")]
+    StepTimeout(std::time::Duration),
+
+    #[error("see_global channel closed unexpectedly")]
+    WorldChannelClosed,
+
+    #[error("The clock stands still at {time} in {play_mode:?}")]
+    ClockStopped { time: u16, play_mode: common::types::PlayMode },
+}
+
+pub type Result<T> = std::result::Result<T, Error>;
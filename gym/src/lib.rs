@@ -0,0 +1,18 @@
+//! Step/reset environment over rcssserver's synch mode.
+//!
+//! An [`Env`] owns a trainer-coached rcssserver and one [`process::Player`]
+//! client per agent. Every [`Env::step`] sends the agents' actions, ends the
+//! cycle with `(done)` from every client and waits for the next `see_global`.
+
+mod action;
+mod env;
+mod error;
+mod observation;
+mod reward;
+
+pub use action::{Action, Actions, AgentId};
+pub use env::{Env, EnvBuilder};
+pub use error::{Error, Result};
+pub use observation::{Info, Observation};
+pub use reward::{BallProgress, GoalReward, Reward, Weighted};
+pub use common::scenario::{BallState, PlayerState, Scenario};
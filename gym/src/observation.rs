@@ -0,0 +1,27 @@
+use serde::{Deserialize, Serialize};
+
+use common::types::{PlayMode, WorldSnapshot};
+use process::addon::{Score, TrainerEvent};
+
+/// The whole field after a step, as the trainer sees it.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+pub struct Observation {
+    pub time: u16,
+    pub play_mode: PlayMode,
+    pub score: Score,
+    /// Players have their sides assigned.
+    pub world: WorldSnapshot,
+}
+
+/// What happened during a step besides the observation.
+#[derive(Serialize, Deserialize, Clone, Debug, Default, PartialEq)]
+pub struct Info {
+    /// Steps since the last reset.
+    pub steps: u32,
+    /// Resets so far, 0 before the first one.
+    pub episode: u64,
+    /// The episode hit the step limit rather than ending on its own.
+    pub truncated: bool,
+    /// Referee messages heard during the step.
+    pub events: Vec<TrainerEvent>,
+}
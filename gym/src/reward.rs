@@ -0,0 +1,134 @@
+use common::types::{Side, Vec2};
+
+use crate::Observation;
+
+/// Half the pitch length, where the goals stand on the x axis.
+const GOAL_X: f64 = 52.5;
+
+/// Scores the transition of one step, the same value goes to every agent.
+pub trait Reward: Send {
+    /// Called with the first observation of an episode.
+    fn reset(&mut self, _observation: &Observation) {}
+
+    fn reward(&mut self, prev: &Observation, next: &Observation) -> f64;
+}
+
+impl<F> Reward for F
+where F: FnMut(&Observation, &Observation) -> f64 + Send {
+    fn reward(&mut self, prev: &Observation, next: &Observation) -> f64 {
+        self(prev, next)
+    }
+}
+
+/// +1 for every goal of `side`, -1 for every goal against it.
+#[derive(Clone, Copy, Debug)]
+pub struct GoalReward {
+    pub side: Side,
+}
+
+impl GoalReward {
+    pub fn new(side: Side) -> Self {
+        Self { side }
+    }
+}
+
+impl Reward for GoalReward {
+    fn reward(&mut self, prev: &Observation, next: &Observation) -> f64 {
+        let left = next.score.left as f64 - prev.score.left as f64;
+        let right = next.score.right as f64 - prev.score.right as f64;
+        match self.side {
+            Side::LEFT => left - right,
+            Side::RIGHT => right - left,
+            Side::NEUTRAL => 0.0,
+        }
+    }
+}
+
+/// How much closer the ball got to the goal `side` attacks, in pitch lengths.
+#[derive(Clone, Copy, Debug)]
+pub struct BallProgress {
+    pub side: Side,
+}
+
+impl BallProgress {
+    pub fn new(side: Side) -> Self {
+        Self { side }
+    }
+
+    fn distance(&self, ball: Vec2) -> f64 {
+        let goal_x = match self.side {
+            Side::RIGHT => -GOAL_X,
+            _ => GOAL_X,
+        };
+        (goal_x - ball.x).hypot(ball.y)
+    }
+}
+
+impl Reward for BallProgress {
+    fn reward(&mut self, prev: &Observation, next: &Observation) -> f64 {
+        let before = self.distance(prev.world.ball.pos);
+        let after = self.distance(next.world.ball.pos);
+        (before - after) / (2.0 * GOAL_X)
+    }
+}
+
+/// The weighted sum of several rewards.
+#[derive(Default)]
+pub struct Weighted {
+    rewards: Vec<(f64, Box<dyn Reward>)>,
+}
+
+impl Weighted {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn with(&mut self, weight: f64, reward: impl Reward + 'static) -> &mut Self {
+        self.rewards.push((weight, Box::new(reward)));
+        self
+    }
+}
+
+impl Reward for Weighted {
+    fn reset(&mut self, observation: &Observation) {
+        for (_, reward) in self.rewards.iter_mut() {
+            reward.reset(observation);
+        }
+    }
+
+    fn reward(&mut self, prev: &Observation, next: &Observation) -> f64 {
+        self.rewards
+            .iter_mut()
+            .map(|(weight, reward)| *weight * reward.reward(prev, next))
+            .sum()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::{PlayMode, WorldSnapshot};
+    use process::addon::Score;
+
+    fn observation(ball: Vec2, score: Score) -> Observation {
+        let mut world = WorldSnapshot::default();
+        world.ball.pos = ball;
+        Observation { time: 0, play_mode: PlayMode::PM_PlayOn, score, world }
+    }
+
+    #[test]
+    fn test_weighted_rewards() {
+        let prev = observation(Vec2::new(0.0, 0.0), Score::default());
+        let next = observation(Vec2::new(10.5, 0.0), Score { left: 1, right: 0 });
+
+        assert_eq!(GoalReward::new(Side::LEFT).reward(&prev, &next), 1.0);
+        assert_eq!(GoalReward::new(Side::RIGHT).reward(&prev, &next), -1.0);
+        assert!((BallProgress::new(Side::LEFT).reward(&prev, &next) - 0.1).abs() < 1e-9);
+
+        let mut weighted = Weighted::new();
+        weighted
+            .with(1.0, GoalReward::new(Side::RIGHT))
+            .with(0.5, |_: &Observation, _: &Observation| 2.0);
+        assert_eq!(weighted.reward(&prev, &next), 0.0);
+    }
+}
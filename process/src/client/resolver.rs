@@ -1,157 +1,85 @@
 use std::any::Any;
 use std::collections::VecDeque;
 use std::fmt::Debug;
-use std::sync::Arc;
-use std::time::Duration;
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+use std::time::{Duration, Instant};
 
 use arcstr::ArcStr;
-use dashmap::DashMap;
 use log::debug;
 use tokio::sync::{mpsc, oneshot};
-use tokio::time::error::Elapsed;
 
 use common::client::{RxData, TxData, TxSignal};
+use common::command::coach::CoachCommand;
 use common::command::player::PlayerCommand;
 use common::command::trainer::TrainerCommand;
 use common::command::{Command, CommandAny};
+use common::sexpr::{self, Sexpr};
 
 use super::addon::{Addon, RawAddon};
 
 pub const TIMEOUT: Duration = Duration::from_millis(2000);
 
-impl CallResolver<PlayerCommand, RxData> {
-    pub fn from_rx(receiver: mpsc::Receiver<RxData>) -> Self {
-        let rx = Arc::new(Receiver::<PlayerCommand, RxData>::new(receiver));
-        Self {
-            rx,
-            rx_ingest: None,
-        }
+/// How the replies of one kind of client are framed, the receive loop is
+/// shared by all of them.
+pub trait ReplyKind: CommandAny {
+    /// Cleans up a raw message before it is parsed.
+    fn normalize(raw: &str) -> &str {
+        raw
     }
-    pub fn new(buffer: usize) -> Self {
-        let (tx, rx) = mpsc::channel(buffer);
-        let rx = Arc::new(Receiver::<PlayerCommand, RxData>::new(rx));
-        Self {
-            rx,
-            rx_ingest: Some(tx),
+
+    /// Heads of replies refusing the oldest call that can produce them.
+    fn is_error(head: &str) -> bool {
+        head == "error"
+    }
+
+    /// The kind a successful reply is for and the tokens following it,
+    /// `(ok KIND ...)` unless the client answers otherwise.
+    fn split_ok<'a, 's>(head: &'a str, args: &'a [Sexpr<'s>]) -> Option<(&'a str, &'a [Sexpr<'s>])> {
+        match (head, args) {
+            ("ok", [kind, rest @ ..]) => Some((kind.atom()?, rest)),
+            _ => None,
         }
     }
 }
 
-impl Receiver<PlayerCommand, RxData> {
-    fn new(mut receiver: mpsc::Receiver<RxData>) -> Self {
-        let tasks: Arc<
-            DashMap<
-                PlayerCommand,
-                VecDeque<oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>>,
-            >,
-        > = Arc::new(DashMap::new());
-
-        let tasks_ = Arc::clone(&tasks);
-        let recv_task = tokio::spawn(async move {
-            while let Some(raw_msg) = receiver.recv().await {
-                let msg = raw_msg.trim().trim_end_matches('\0');
-                if msg.is_empty() || !msg.starts_with('(') || !msg.ends_with(')') {
-                    debug!("{:?}", msg.chars().take(msg.len() - 1));
-                    debug!("ignoring peer ret, not matching '(.+)': '{msg}'.");
-                    continue;
-                }
-
-
-                let mut msg = msg[1..msg.len() - 1].split(' ');
-                let (kind, ret) = match msg.next() {
-                    Some("error") => {
-                        let mut ret = None;
-
-                        let rest: Vec<_> = msg.collect();
-
-                        let map_keys = tasks_.iter().map(|entry| *entry.key());
-
-                        for sig_kind in map_keys {
-                            if let Some(err) = sig_kind.parse_ret_err(&rest) {
-                                ret = Some((sig_kind, Err(err)));
-                                break;
-                            }
-                        }
-
-                        match ret {
-                            Some(error) => error,
-                            None => {
-                                debug!(
-                                    "[CallResolver] Ignore \"error\" for unknown Sig: {raw_msg:?}"
-                                );
-                                continue;
-                            }
-                        }
-                    },
-                    Some(maybe_ok) => {
-                        let kind_str = if maybe_ok != "ok" {
-                            Some(maybe_ok)
-                        } else {
-                            msg.next()
-                        };
-
-                        if let Some(kind_str) = kind_str
-                            && let Some(sig_kind) = PlayerCommand::decode(kind_str)
-                        {
-                            let rest: Vec<_> = msg.collect();
-                            let ret = sig_kind.parse_ret_ok(&rest);
-                            match ret {
-                                Some(ok) => (sig_kind, Ok(ok)),
-                                None => {
-                                    debug!(
-                                        "[CallResolver] Ignore \"ok\" for [{}]: {raw_msg:?}",
-                                        sig_kind.encode()
-                                    );
-                                    continue;
-                                }
-                            }
-                        } else {
-                            debug!("[CallResolver] Ignore \"ok\" for unknown Sig: {raw_msg:?}");
-                            continue;
-                        }
-                    }
-                    _ => {
-                        debug!("[CallResolver] Ignore unknown msg: {raw_msg:?}");
-                        continue;
-                    }
-                };
-
-                if let Some(mut queue) = tasks_.get_mut(&kind)
-                    && let Some(tx) = queue.value_mut().pop_front()
-                    && tx.send(ret).is_err()
-                {
-                    debug!(
-                        "[CallResolver] Failed to send return to caller for [{}]",
-                        kind.encode()
-                    );
-                }
-            }
-        });
-
-        Self {
-            recv_task,
-            queue: tasks,
-            _phantom: Default::default(),
+impl ReplyKind for PlayerCommand {
+    /// Players get most replies headed by the command itself, e.g.
+    /// `(change_view ...)`, rather than `(ok KIND ...)`.
+    fn split_ok<'a, 's>(head: &'a str, args: &'a [Sexpr<'s>]) -> Option<(&'a str, &'a [Sexpr<'s>])> {
+        match (head, args) {
+            ("ok", [kind, rest @ ..]) => Some((kind.atom()?, rest)),
+            ("ok", []) => None,
+            (kind, rest) => Some((kind, rest)),
         }
     }
 }
 
-impl RawAddon for CallResolver<PlayerCommand, RxData> {
-    fn from_raw(
-        _: mpsc::Sender<TxSignal>,
-        _: mpsc::Sender<TxData>,
-        data_rx: mpsc::Receiver<RxData>,
-    ) -> Self
-    where
-        Self: Sized,
-    {
-        Self::from_rx(data_rx)
+impl ReplyKind for TrainerCommand {
+    fn normalize(raw: &str) -> &str {
+        let msg = raw.trim().trim_end_matches('\0');
+        if msg == "(init ok)" { "(ok init)" } else { msg }
     }
 }
 
-impl CallResolver<TrainerCommand, RxData> {
+impl ReplyKind for CoachCommand {
+    /// The coach is refused with warnings as well, e.g. `(warning no_subs_left)`.
+    fn is_error(head: &str) -> bool {
+        matches!(head, "error" | "warning")
+    }
+
+    /// `(init SIDE ok)` answers the coach's init.
+    fn split_ok<'a, 's>(head: &'a str, args: &'a [Sexpr<'s>]) -> Option<(&'a str, &'a [Sexpr<'s>])> {
+        match (head, args) {
+            ("init", args) => Some(("init", args)),
+            ("ok", [kind, rest @ ..]) => Some((kind.atom()?, rest)),
+            _ => None,
+        }
+    }
+}
+
+impl<CMD: ReplyKind> CallResolver<CMD, RxData> {
     pub fn from_rx(receiver: mpsc::Receiver<RxData>) -> Self {
-        let rx = Arc::new(Receiver::<TrainerCommand, RxData>::new(receiver));
+        let rx = Arc::new(Receiver::<CMD, RxData>::new(receiver));
         Self {
             rx,
             rx_ingest: None,
@@ -159,7 +87,7 @@ impl CallResolver<TrainerCommand, RxData> {
     }
     pub fn new(buffer: usize) -> Self {
         let (tx, rx) = mpsc::channel(buffer);
-        let rx = Arc::new(Receiver::<TrainerCommand, RxData>::new(rx));
+        let rx = Arc::new(Receiver::<CMD, RxData>::new(rx));
         Self {
             rx,
             rx_ingest: Some(tx),
@@ -167,101 +95,67 @@ impl CallResolver<TrainerCommand, RxData> {
     }
 }
 
-impl Receiver<TrainerCommand, RxData> {
+impl<CMD: ReplyKind> Receiver<CMD, RxData> {
     fn new(mut receiver: mpsc::Receiver<RxData>) -> Self {
-        let tasks: Arc<
-            DashMap<
-                TrainerCommand,
-                VecDeque<oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>>,
-            >,
-        > = Arc::new(DashMap::new());
-
-        let tasks_ = Arc::clone(&tasks);
+        let waiters: Arc<Mutex<Waiters<CMD>>> = Arc::default();
+
+        let waiters_ = Arc::clone(&waiters);
         let recv_task = tokio::spawn(async move {
             while let Some(raw_msg) = receiver.recv().await {
-                let msg = raw_msg.trim().trim_end_matches('\0');
-                if msg.is_empty() || !msg.starts_with('(') || !msg.ends_with(')') {
-                    debug!("{:?}", msg.chars().take(msg.len() - 1));
-                    debug!("ignoring peer ret, not matching '(.+)': '{msg}'.");
-                    continue;
-                }
-
-                let msg = if msg == "(init ok)" { "(ok init)" } else { msg };
-
-                let mut msg = msg[1..msg.len() - 1].split(' ');
-                let (kind, ret) = match msg.next() {
-                    Some("ok") => {
-                        if let Some(kind_str) = msg.next()
-                            && let Some(sig_kind) = TrainerCommand::decode(kind_str)
-                        {
-                            let rest: Vec<_> = msg.collect();
-                            let ret = sig_kind.parse_ret_ok(&rest);
-                            match ret {
-                                Some(ok) => (sig_kind, Ok(ok)),
-                                None => {
-                                    debug!(
-                                        "[CallResolver] Ignore \"ok\" for [{}]: {raw_msg:?}",
-                                        sig_kind.encode()
-                                    );
-                                    continue;
-                                }
-                            }
-                        } else {
-                            debug!("[CallResolver] Ignore \"ok\" for unknown Sig: {raw_msg:?}");
-                            continue;
-                        }
-                    }
-                    Some("error") => {
-                        let mut ret = None;
-
-                        let rest: Vec<_> = msg.collect();
-
-                        let map_keys = tasks_.iter().map(|entry| *entry.key());
-
-                        for sig_kind in map_keys {
-                            if let Some(err) = sig_kind.parse_ret_err(&rest) {
-                                ret = Some((sig_kind, Err(err)));
-                                break;
-                            }
-                        }
-
-                        match ret {
-                            Some(error) => error,
-                            None => {
-                                debug!(
-                                    "[CallResolver] Ignore \"error\" for unknown Sig: {raw_msg:?}"
-                                );
-                                continue;
-                            }
-                        }
+                let msg = match sexpr::parse(CMD::normalize(&raw_msg)) {
+                    Ok(msg) if msg.is_list() => msg,
+                    Ok(_) => {
+                        debug!("ignoring peer ret, not matching '(.+)': {raw_msg:?}.");
+                        continue;
                     }
-                    _ => {
-                        debug!("[CallResolver] Ignore unknown msg: {raw_msg:?}");
+                    Err(e) => {
+                        debug!("ignoring peer ret, malformed s-expression ({e}): {raw_msg:?}.");
                         continue;
                     }
                 };
 
-                if let Some(mut queue) = tasks_.get_mut(&kind)
-                    && let Some(tx) = queue.value_mut().pop_front()
-                    && tx.send(ret).is_err()
-                {
+                let Some(head) = msg.head() else {
+                    debug!("[CallResolver] Ignore unknown msg: {raw_msg:?}");
+                    continue;
+                };
+                if CMD::is_error(head) {
+                    let rest = msg.args();
+                    if !lock(&waiters_).resolve_err(|kind| kind.parse_ret_err(rest)) {
+                        debug!("[CallResolver] Ignore \"error\" for unknown Sig: {raw_msg:?}");
+                    }
+                    continue;
+                }
+
+                let Some((kind_str, rest)) = CMD::split_ok(head, msg.args()) else {
+                    debug!("[CallResolver] Ignore unknown msg: {raw_msg:?}");
+                    continue;
+                };
+                let Some(sig_kind) = CMD::decode(kind_str) else {
+                    debug!("[CallResolver] Ignore \"ok\" for unknown Sig: {raw_msg:?}");
+                    continue;
+                };
+                let Some(ok) = sig_kind.parse_ret_ok(rest) else {
                     debug!(
-                        "[CallResolver] Failed to send return to caller for [{}]",
-                        kind.encode()
+                        "[CallResolver] Ignore \"ok\" for [{}]: {raw_msg:?}",
+                        sig_kind.encode()
                     );
-                }
+                    continue;
+                };
+
+                lock(&waiters_).resolve(sig_kind, Ok(ok));
             }
         });
 
         Self {
             recv_task,
-            queue: tasks,
+            waiters,
+            send_lock: tokio::sync::Mutex::new(()),
             _phantom: Default::default(),
         }
     }
 }
 
-impl RawAddon for CallResolver<TrainerCommand, RxData> {
+impl<CMD: ReplyKind> RawAddon for CallResolver<CMD, RxData> {
     fn from_raw(
         _: mpsc::Sender<TxSignal>,
         _: mpsc::Sender<TxData>,
@@ -308,14 +202,14 @@ where
     where
         TX: From<ArcStr> + Debug + Send + Sync + 'static,
     {
-        Sender::new(tx, Arc::clone(&self.rx))
+        Sender::new(tx, Arc::clone(&self.rx), TIMEOUT)
     }
 
     pub fn weak<TX>(&self, tx: mpsc::WeakSender<TX>) -> WeakSender<CMD, TX, RX>
     where
         TX: From<ArcStr> + Debug + Send + Sync + 'static,
     {
-        WeakSender::new(tx, Arc::clone(&self.rx))
+        WeakSender::new(tx, Arc::clone(&self.rx), TIMEOUT)
     }
 
     pub fn close(&self) {
@@ -333,6 +227,137 @@ where
     }
 }
 
+type Reply = Result<Box<dyn Any + Send>, Box<dyn Any + Send>>;
+
+/// A call waiting for its reply. `tx` is taken once the caller gave up, the
+/// entry then stays around for the call's own timeout to swallow a late reply
+/// instead of handing it to the next caller of the same kind.
+#[derive(Debug)]
+struct Waiter<CMD> {
+    id: u64,
+    kind: CMD,
+    tx: Option<oneshot::Sender<Reply>>,
+    timeout: Duration,
+    abandoned_at: Option<Instant>,
+}
+
+/// Outstanding calls of one client, in the order they were put on the wire.
+#[derive(Debug)]
+struct Waiters<CMD> {
+    next_id: u64,
+    queue: VecDeque<Waiter<CMD>>,
+}
+
+impl<CMD> Default for Waiters<CMD> {
+    fn default() -> Self {
+        Self {
+            next_id: 0,
+            queue: VecDeque::new(),
+        }
+    }
+}
+
+impl<CMD: CommandAny> Waiters<CMD> {
+    fn push(&mut self, kind: CMD, timeout: Duration) -> (u64, oneshot::Receiver<Reply>) {
+        let (tx, rx) = oneshot::channel();
+        let id = self.next_id;
+        self.next_id += 1;
+        self.queue.push_back(Waiter {
+            id,
+            kind,
+            tx: Some(tx),
+            timeout,
+            abandoned_at: None,
+        });
+        (id, rx)
+    }
+
+    fn remove(&mut self, id: u64) {
+        self.queue.retain(|w| w.id != id);
+    }
+
+    fn abandon(&mut self, id: u64) {
+        if let Some(waiter) = self.queue.iter_mut().find(|w| w.id == id) {
+            waiter.tx = None;
+            waiter.abandoned_at = Some(Instant::now());
+        }
+    }
+
+    fn prune(&mut self) {
+        self.queue
+            .retain(|w| w.abandoned_at.is_none_or(|at| at.elapsed() < w.timeout));
+    }
+
+    fn deliver(&mut self, idx: usize, ret: Reply) {
+        let Some(waiter) = self.queue.remove(idx) else {
+            return;
+        };
+        match waiter.tx {
+            Some(tx) => {
+                if tx.send(ret).is_err() {
+                    debug!(
+                        "[CallResolver] Failed to send return to caller for [{}]",
+                        waiter.kind.encode()
+                    );
+                }
+            }
+            None => debug!(
+                "[CallResolver] Drop late return for [{}]",
+                waiter.kind.encode()
+            ),
+        }
+    }
+
+    /// Hand an `ok` reply to the oldest call of its kind.
+    fn resolve(&mut self, kind: CMD, ret: Reply) -> bool {
+        self.prune();
+        let Some(idx) = self.queue.iter().position(|w| w.kind == kind) else {
+            return false;
+        };
+        self.deliver(idx, ret);
+        true
+    }
+
+    /// `(error ...)` does not name the command, it belongs to the oldest call
+    /// whose kind can produce it.
+    fn resolve_err(&mut self, parse: impl Fn(&CMD) -> Option<Box<dyn Any + Send>>) -> bool {
+        self.prune();
+        let Some((idx, err)) = self
+            .queue
+            .iter()
+            .enumerate()
+            .find_map(|(idx, w)| parse(&w.kind).map(|err| (idx, err)))
+        else {
+            return false;
+        };
+        self.deliver(idx, Err(err));
+        true
+    }
+}
+
+fn lock<CMD>(waiters: &Mutex<Waiters<CMD>>) -> MutexGuard<'_, Waiters<CMD>> {
+    waiters.lock().unwrap_or_else(PoisonError::into_inner)
+}
+
+/// Gives up the waiter when the call is dropped, e.g. on timeout. A waiter
+/// whose command never made it onto the wire has no reply coming and is
+/// removed outright.
+struct WaiterGuard<'r, CMD: CommandAny> {
+    waiters: &'r Mutex<Waiters<CMD>>,
+    id: u64,
+    sent: bool,
+}
+
+impl<CMD: CommandAny> Drop for WaiterGuard<'_, CMD> {
+    fn drop(&mut self) {
+        let mut waiters = lock(self.waiters);
+        match self.sent {
+            true => waiters.abandon(self.id),
+            false => waiters.remove(self.id),
+        }
+    }
+}
+
 #[derive(Debug)]
 struct Receiver<CMD, RX>
 where
@@ -341,9 +366,10 @@ where
 {
     recv_task: tokio::task::JoinHandle<()>,
 
-    queue: Arc<
-        DashMap<CMD, VecDeque<oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>>>,
-    >,
+    waiters: Arc<Mutex<Waiters<CMD>>>,
+    /// Held while a waiter is queued and its command written, so the queue
+    /// order matches the order on the wire.
+    send_lock: tokio::sync::Mutex<()>,
 
     _phantom: std::marker::PhantomData<RX>,
 }
@@ -353,12 +379,57 @@ where
     CMD: CommandAny,
     RX: Debug + Send + Sync + 'static,
 {
-    fn add_queue(
+    async fn call<T, TX>(
         &self,
-        command: CMD,
-        tx: oneshot::Sender<Result<Box<dyn Any + Send>, Box<dyn Any + Send>>>,
-    ) {
-        self.queue.entry(command).or_default().push_back(tx)
+        tx: &mpsc::Sender<TX>,
+        sig: T,
+        timeout: Duration,
+    ) -> super::Result<Result<T::Ok, T::Error>>
+    where
+        T: Command<Kind = CMD>,
+        TX: From<ArcStr> + Debug + Send + Sync + 'static,
+    {
+        let sig_kind = sig.kind();
+        let call = async {
+            let (guard, rx) = {
+                let _order = self.send_lock.lock().await;
+                let (id, rx) = lock(&self.waiters).push(sig_kind.clone(), timeout);
+                let mut guard = WaiterGuard {
+                    waiters: &self.waiters,
+                    id,
+                    sent: false,
+                };
+                if tx.send(sig.encode().into()).await.is_err() {
+                    return Err(super::Error::CommandSendFailed);
+                }
+                guard.sent = true;
+                (guard, rx)
+            };
+
+            let ret = rx.await.map_err(|_| super::Error::CommandReceiveFailed);
+            drop(guard);
+            match ret? {
+                Ok(ok) => {
+                    let ok = *ok
+                        .downcast::<T::Ok>()
+                        .map_err(|_| super::Error::CommandResponseTypeMismatch)?;
+                    Ok(Ok(ok))
+                }
+                Err(err) => {
+                    let err = *err
+                        .downcast::<T::Error>()
+                        .map_err(|_| super::Error::CommandResponseTypeMismatch)?;
+                    Ok(Err(err))
+                }
+            }
+        };
+
+        tokio::time::timeout(timeout, call)
+            .await
+            .map_err(|_| super::Error::CallElapsed {
+                kind: sig_kind.encode(),
+            })
+            .flatten()
     }
 
     pub fn close(&self) {
@@ -375,6 +446,7 @@ where
 {
     tx: mpsc::Sender<TX>,
     resolver: Arc<Receiver<CMD, RX>>,
+    timeout: Duration,
 }
 
 impl<CMD, TX, RX> Sender<CMD, TX, RX>
@@ -383,46 +455,38 @@ where
     TX: From<ArcStr> + Debug + Send + Sync + 'static,
     RX: Debug + Send + Sync + 'static,
 {
-    fn new(tx: mpsc::Sender<TX>, resolver: Arc<Receiver<CMD, RX>>) -> Self {
-        Self { tx, resolver }
+    fn new(tx: mpsc::Sender<TX>, resolver: Arc<Receiver<CMD, RX>>, timeout: Duration) -> Self {
+        Self { tx, resolver, timeout }
     }
-    
-    async fn send<T: Command<Kind = CMD>>(&self, sig: T) -> super::Result<Result<T::Ok, T::Error>> {
-        let sig_kind = sig.kind();
-        let sender = &self.tx;
-        sender.send(sig.encode().into()).await
-            .map_err(|_| super::Error::CommandSendFailed)?;
 
-        let (tx, rx) = oneshot::channel();
-        self.resolver.add_queue(sig_kind, tx);
-        match rx.await.map_err(|_| super::Error::CommandReceiveFailed)? {
-            Ok(ok) => {
-                let ok = *ok.downcast::<T::Ok>()
-                    .map_err(|_| super::Error::CommandResponseTypeMismatch)?;
-                Ok(Ok(ok))
-            }
-            Err(err) => {
-                let err = *err.downcast::<T::Error>()
-                    .map_err(|_| super::Error::CommandResponseTypeMismatch)?;
-                Ok(Err(err))
-            }
-        }
+    /// Default timeout of [`Sender::call`], [`TIMEOUT`] unless changed.
+    pub fn with_timeout(&mut self, timeout: Duration) -> &mut Self {
+        self.timeout = timeout;
+        self
+    }
+
+    pub fn timeout(&self) -> Duration {
+        self.timeout
     }
 
     pub async fn call<T: Command<Kind = CMD>>(
         &self,
         sig: T,
     ) -> super::Result<Result<T::Ok, T::Error>> {
-        let sig_kind = sig.kind();
-        let res = tokio::time::timeout(TIMEOUT, self.send(sig))
-            .await.map_err(|_| super::Error::CallElapsed {kind: sig_kind.encode()}).flatten()?;
-        
-        Ok(res)
+        self.call_timeout(sig, self.timeout).await
+    }
+
+    pub async fn call_timeout<T: Command<Kind = CMD>>(
+        &self,
+        sig: T,
+        timeout: Duration,
+    ) -> super::Result<Result<T::Ok, T::Error>> {
+        self.resolver.call(&self.tx, sig, timeout).await
     }
 
     pub fn downgrade(&self) -> WeakSender<CMD, TX, RX> {
         let tx = self.tx.downgrade();
-        WeakSender::new(tx, Arc::clone(&self.resolver))
+        WeakSender::new(tx, Arc::clone(&self.resolver), self.timeout)
     }
 }
 
@@ -435,6 +499,7 @@ where
 {
     tx: mpsc::WeakSender<TX>,
     resolver: Arc<Receiver<CMD, RX>>,
+    timeout: Duration,
 }
 
 impl<CMD, TX, RX> WeakSender<CMD, TX, RX>
@@ -443,34 +508,128 @@ where
     TX: From<ArcStr> + Debug + Send + Sync + 'static,
     RX: Debug + Send + Sync + 'static,
 {
-    fn new(tx: mpsc::WeakSender<TX>, resolver: Arc<Receiver<CMD, RX>>) -> Self {
-        Self { tx, resolver }
+    fn new(tx: mpsc::WeakSender<TX>, resolver: Arc<Receiver<CMD, RX>>, timeout: Duration) -> Self {
+        Self { tx, resolver, timeout }
     }
 
     pub async fn send<T: Command<Kind = CMD>>(&self, sig: T) -> Result<Result<T::Ok, T::Error>, super::Error> {
-        let sig_kind = sig.kind();
         let sender = self.tx.upgrade().ok_or(super::Error::SenderClosed)?;
-        sender.send(sig.encode().into()).await
-            .map_err(|_| super::Error::CommandSendFailed)?;
-
-        let (tx, rx) = oneshot::channel();
-        self.resolver.add_queue(sig_kind, tx);
-        match rx.await.map_err(|_| super::Error::CommandReceiveFailed)? {
-            Ok(ok) => {
-                let ok = *ok.downcast::<T::Ok>()
-                    .map_err(|_| super::Error::CommandResponseTypeMismatch)?;
-                Ok(Ok(ok))
-            }
-            Err(err) => {
-                let err = *err.downcast::<T::Error>()
-                    .map_err(|_| super::Error::CommandResponseTypeMismatch)?;
-                Ok(Err(err))
-            }
-        }
+        self.resolver.call(&sender, sig, self.timeout).await
     }
 
     pub fn upgrade(self) -> Option<Sender<CMD, TX, RX>> {
         let tx = self.tx.upgrade()?;
-        Some(Sender::new(tx, self.resolver))
+        Some(Sender::new(tx, self.resolver, self.timeout))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::command::trainer::{ChangeMode, CheckBall, Move};
+    use common::command::trainer::change_mode::CommandChangeModeError;
+    use common::types::{PlayMode, Vec2};
+
+    use super::*;
+
+    type TestCaller = Sender<TrainerCommand, ArcStr, RxData>;
+
+    fn trainer() -> (TestCaller, mpsc::Receiver<ArcStr>, mpsc::Sender<RxData>) {
+        let resolver = CallResolver::<TrainerCommand, RxData>::new(8);
+        let (tx, wire) = mpsc::channel(8);
+        let ingest = resolver.ingest_tx().unwrap();
+        (resolver.sender(tx), wire, ingest)
+    }
+
+    #[tokio::test]
+    async fn test_error_goes_to_oldest_call() {
+        let (caller, mut wire, ingest) = trainer();
+
+        let server = tokio::spawn(async move {
+            assert!(wire.recv().await.unwrap().starts_with("(change_mode"));
+            assert!(wire.recv().await.unwrap().starts_with("(move"));
+            ingest.send("(error illegal_command_form)".into()).await.unwrap();
+            ingest.send("(ok move)".into()).await.unwrap();
+        });
+
+        let (mode, mv) = tokio::join!(
+            caller.call(ChangeMode { play_mode: PlayMode::PM_PlayOn }),
+            caller.call(Move::ball(Vec2::new(0.0, 0.0))),
+        );
+        server.await.unwrap();
+
+        assert!(matches!(mode.unwrap(), Err(CommandChangeModeError::IllegalCommandForm)));
+        assert!(mv.unwrap().is_ok());
+    }
+
+    #[tokio::test]
+    async fn test_late_reply_is_not_delivered_to_next_call() {
+        let (caller, mut wire, ingest) = trainer();
+
+        // the late reply is swallowed for as long as the call's timeout
+        let ret = caller.call_timeout(CheckBall, Duration::from_millis(200)).await;
+        assert!(matches!(ret, Err(crate::client::Error::CallElapsed { .. })));
+        wire.recv().await.unwrap();
+
+        let server = tokio::spawn(async move {
+            wire.recv().await.unwrap();
+            ingest.send("(ok check_ball 10 in_field)".into()).await.unwrap();
+            ingest.send("(ok check_ball 20 in_field)".into()).await.unwrap();
+        });
+
+        let ret = caller.call(CheckBall).await.unwrap().unwrap();
+        server.await.unwrap();
+        assert_eq!(ret.time, 20);
+        assert!(lock(&caller.resolver.waiters).queue.is_empty());
+    }
+
+    fn split<CMD: ReplyKind>(raw: &str) -> Option<(String, usize)> {
+        let msg = sexpr::parse(raw).unwrap();
+        CMD::split_ok(msg.head().unwrap(), msg.args()).map(|(kind, rest)| (kind.to_string(), rest.len()))
+    }
+
+    #[test]
+    fn test_reply_framing() {
+        assert_eq!(split::<PlayerCommand>("(change_view wide high)"), Some(("change_view".to_string(), 2)));
+        assert_eq!(split::<PlayerCommand>("(ok)"), None);
+        assert_eq!(split::<CoachCommand>("(init l ok)"), Some(("init".to_string(), 2)));
+        assert_eq!(split::<CoachCommand>("(ok look 0)"), Some(("look".to_string(), 1)));
+        assert_eq!(split::<TrainerCommand>("(init l ok)"), None);
+        assert_eq!(TrainerCommand::normalize(" (init ok)\0"), "(ok init)");
+
+        assert!(CoachCommand::is_error("warning") && !TrainerCommand::is_error("warning"));
+    }
+
+    #[tokio::test]
+    async fn test_call_dropped_while_sending() {
+        let resolver = CallResolver::<TrainerCommand, RxData>::new(8);
+        let (tx, mut wire) = mpsc::channel(1);
+        let ingest = resolver.ingest_tx().unwrap();
+        let caller: TestCaller = resolver.sender(tx);
+
+        // the wire is full, the call times out before its command is written
+        caller.tx.send("(look)".into()).await.unwrap();
+        let ret = caller.call_timeout(CheckBall, Duration::from_millis(20)).await;
+        assert!(matches!(ret, Err(crate::client::Error::CallElapsed { .. })));
+        assert!(lock(&caller.resolver.waiters).queue.is_empty());
+
+        wire.recv().await.unwrap();
+        let server = tokio::spawn(async move {
+            wire.recv().await.unwrap();
+            ingest.send("(ok check_ball 10 in_field)".into()).await.unwrap();
+        });
+        let ret = caller.call(CheckBall).await.unwrap().unwrap();
+        server.await.unwrap();
+        assert_eq!(ret.time, 10);
+    }
+
+    #[test]
+    fn test_abandoned_waiter_kept_for_call_timeout() {
+        let mut waiters = Waiters::<TrainerCommand>::default();
+        let (short, _) = waiters.push(TrainerCommand::CheckBall, Duration::ZERO);
+        let (long, _) = waiters.push(TrainerCommand::CheckBall, Duration::from_secs(60));
+        waiters.abandon(short);
+        waiters.abandon(long);
+        waiters.prune();
+        assert_eq!(waiters.queue.iter().map(|w| w.id).collect::<Vec<_>>(), vec![long]);
     }
 }
@@ -1,4 +1,5 @@
 use std::net::SocketAddr;
+use std::time::Duration;
 
 use dashmap::DashMap;
 use log::trace;
@@ -16,6 +17,7 @@ pub type RichClientCaller<CMD> = CallSender<CMD, TxData, RxData>;
 
 pub const DEFAULT_LOCAL_PLAYER_PORT: u16 = 6000;
 pub const DEFAULT_LOCAL_TRAINER_PORT: u16 = 6001;
+pub const DEFAULT_LOCAL_OLCOACH_PORT: u16 = 6002;
 
 #[derive(Clone, Debug)]
 pub struct RichClientBuilder {
@@ -49,6 +51,16 @@ impl RichClientBuilder {
         Self { conn_builder }
     }
 
+    pub fn coach() -> Self {
+        let mut conn_builder = client::Builder::new();
+        conn_builder
+            .with_kind(client::Kind::OlCoach)
+            .with_name("Default Coach".to_string())
+            .with_local_peer(DEFAULT_LOCAL_OLCOACH_PORT);
+
+        Self { conn_builder }
+    }
+
     pub fn with_kind(&mut self, kind: client::Kind) -> &mut Self {
         self.conn_builder.with_kind(kind);
         self
@@ -173,6 +185,19 @@ where
             .await
     }
 
+    /// [`RichClient::call`] with its own timeout instead of the caller's.
+    pub async fn call_timeout<T: Command<Kind = CMD>>(
+        &self,
+        cmd: T,
+        timeout: Duration,
+    ) -> Result<CommandResult<T>> {
+        self.resolver_tx
+            .get()
+            .expect("CallResolver not initialized - call init_resolver() first")
+            .call_timeout(cmd, timeout)
+            .await
+    }
+
     pub fn subscribe(&self, ingest_tx: mpsc::Sender<RxData>) -> Uuid {
         self.conn.subscribe(ingest_tx)
     }
@@ -181,7 +206,9 @@ where
         self.conn.unsubscribe(id)
     }
 
-    async fn send_cmd(&self, ctrl: impl Command) -> Result<()> {
+    /// Send without waiting for a reply, for commands the server never
+    /// answers such as `dash` or `kick`.
+    pub async fn send<T: Command<Kind = CMD>>(&self, ctrl: T) -> Result<()> {
         self.conn
             .send_data(ctrl.encode())
             .await
@@ -204,7 +231,7 @@ where
         Ok(())
     }
 
-    pub(super) fn init_resolver(&self, resolver: CallResolver<CMD, RxData>) -> Result<Uuid> {
+    pub(crate) fn init_resolver(&self, resolver: CallResolver<CMD, RxData>) -> Result<Uuid> {
         self.resolver_tx
             .set(resolver.sender(self.conn.data_sender()))
             .map_err(|_| Error::ResolverNotSingleton)?;
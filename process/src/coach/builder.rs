@@ -0,0 +1,48 @@
+use crate::client::RichClientBuilder;
+use crate::coach::OnlineCoach;
+use std::ops::{Deref, DerefMut};
+
+#[derive(Clone, Debug)]
+pub struct OnlineCoachBuilder {
+    pub builder: RichClientBuilder,
+    pub team_name: String,
+}
+
+impl Default for OnlineCoachBuilder {
+    fn default() -> Self {
+        let builder = RichClientBuilder::coach();
+        Self {
+            builder,
+            team_name: String::new(),
+        }
+    }
+}
+
+impl OnlineCoachBuilder {
+    pub fn with_team_name(&mut self, team_name: String) -> &mut Self {
+        self.team_name = team_name;
+        self
+    }
+
+    pub fn build(&self) -> OnlineCoach {
+        OnlineCoach::from_client_config(self.builder.conn_builder.build(), self.team_name.clone())
+    }
+
+    pub fn build_into(self) -> OnlineCoach {
+        OnlineCoach::from_client_config(self.builder.conn_builder.build_into(), self.team_name)
+    }
+}
+
+impl Deref for OnlineCoachBuilder {
+    type Target = RichClientBuilder;
+
+    fn deref(&self) -> &Self::Target {
+        &self.builder
+    }
+}
+
+impl DerefMut for OnlineCoachBuilder {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.builder
+    }
+}
@@ -0,0 +1,5 @@
+mod builder;
+mod online;
+
+pub use builder::OnlineCoachBuilder as Builder;
+pub use online::OnlineCoach;
@@ -0,0 +1,155 @@
+use std::ops::{Deref, DerefMut};
+use std::sync::OnceLock;
+
+use arcstr::ArcStr;
+use log::{debug, error, trace};
+use tokio::sync::{mpsc, watch};
+
+use common::client::{RxData, TxData};
+use common::command::coach::CoachCommand;
+use common::command::CommandAny;
+use common::types::{Side, WorldSnapshot};
+use common::{client, command};
+
+use crate::client::{CallResolver, CallSender, Result, RichClient};
+use crate::trainer::SeeGlobalAddon;
+
+use super::Builder;
+
+pub const COACH_PROTOCOL_VERSION: u8 = 19;
+
+/// A team's online coach, connected to `olcoach_port`.
+#[derive(Debug)]
+pub struct OnlineCoach {
+    client: RichClient<CoachCommand>,
+    team_name: String,
+    side: OnceLock<Side>,
+    see_global: OnceLock<watch::Receiver<Option<WorldSnapshot>>>,
+}
+
+impl OnlineCoach {
+    pub fn builder() -> Builder {
+        Builder::default()
+    }
+
+    pub fn client(&self) -> &RichClient<CoachCommand> {
+        &self.client
+    }
+
+    pub fn from_client_config(config: client::Config, team_name: String) -> Self {
+        assert_eq!(
+            config.kind,
+            client::Kind::OlCoach,
+            "ClientKind::OlCoach expected"
+        );
+        let client = RichClient::from_client_config(config);
+
+        Self {
+            client,
+            team_name,
+            side: OnceLock::new(),
+            see_global: OnceLock::new(),
+        }
+    }
+
+    pub fn team_name(&self) -> &str {
+        &self.team_name
+    }
+
+    /// The side assigned by the server, known once `init` succeeded.
+    pub fn side(&self) -> Option<Side> {
+        self.side.get().copied()
+    }
+
+    pub(super) fn init_resolver(&self) -> Result<()> {
+        trace!("[OnlineCoach] Initializing CallResolver addon.");
+        let id = self.client.init_resolver(CallResolver::new(32))?;
+        trace!("[OnlineCoach] CallResolver addon initialized, id = {id}");
+
+        Ok(())
+    }
+
+    pub async fn connect(&self) -> Result<()> {
+        trace!(
+            "[OnlineCoach] Connecting to host {:?} via peer {:?}",
+            self.config().host,
+            self.config().peer
+        );
+        self.conn_connect().await?;
+        debug!("[OnlineCoach] Connected.");
+        self.init_resolver()?;
+        debug!("[OnlineCoach] CallResolver initialized.");
+        Ok(())
+    }
+
+    pub async fn connect_and_init(&self) -> Result<Side> {
+        self.connect().await?;
+
+        let init = command::coach::Init {
+            team_name: self.team_name.clone(),
+            version: Some(COACH_PROTOCOL_VERSION),
+        };
+        match self.call(init).await? {
+            Ok(ok) => {
+                trace!("[OnlineCoach] Init command succeeded returned with {ok:?}.");
+                let _ = self.side.set(ok.side);
+                Ok(ok.side)
+            },
+            Err(e) => {
+                error!("[OnlineCoach] Init command returned with error: {}", e);
+                Err(crate::client::Error::RcssErrorCall {
+                    kind: CoachCommand::Init.encode(),
+                    msg: ArcStr::from(e.to_string())
+                })
+            },
+        }
+    }
+
+    /// `look` with every player's side filled in from `team_names`.
+    pub async fn look(&self) -> Result<WorldSnapshot> {
+        let Ok(teams) = self.call(command::coach::TeamNames).await?;
+        let Ok(mut world) = self.call(command::coach::Look).await?;
+        world.assign_sides(teams.left.as_deref(), teams.right.as_deref());
+
+        Ok(world)
+    }
+
+    /// The latest `see_global` frame, only updated after `(eye on)`.
+    pub fn see_global(&self) -> watch::Receiver<Option<WorldSnapshot>> {
+        self.see_global
+            .get_or_init(|| {
+                trace!("[OnlineCoach] Adding see_global addon.");
+                let (tx, rx) = mpsc::channel(32);
+                let id = self.subscribe(tx);
+                let addon = SeeGlobalAddon::from_rx(rx);
+                let world_rx = addon.watch();
+                self.addons.insert("see_global", Box::new(addon));
+                trace!("[OnlineCoach] see_global addon added, id = {id}");
+
+                world_rx
+            })
+            .clone()
+    }
+
+    pub fn command_sender(&self) -> CallSender<CoachCommand, TxData, RxData> {
+        self.caller()
+    }
+
+    pub async fn shutdown(&mut self) -> Result<()> {
+        self.client.shutdown().await
+    }
+}
+
+impl Deref for OnlineCoach {
+    type Target = RichClient<CoachCommand>;
+
+    fn deref(&self) -> &Self::Target {
+        &self.client
+    }
+}
+
+impl DerefMut for OnlineCoach {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.client
+    }
+}
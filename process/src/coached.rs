@@ -1,5 +1,6 @@
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
 use std::time::Duration;
-use log::error;
+use log::{error, warn};
 
 use tokio::sync::watch;
 
@@ -7,7 +8,7 @@ use common::command::trainer::TrainerCommand;
 
 use crate::{Error, Result};
 use crate::client::CommandCaller;
-use crate::process::{self, ServerProcess, ServerProcessSpawner};
+use crate::process::{self, PortAllocator, Ports, ServerProcess, ServerProcessSpawner};
 use crate::trainer::{self, OfflineCoach};
 
 use crate::RCSS_PROCESS_NAME;
@@ -19,6 +20,8 @@ pub struct CoachedProcessSpawner<const OUT: usize = 32, const ERR: usize = 32> {
 }
 
 impl CoachedProcessSpawner {
+    pub const PORT_ATTEMPTS: usize = 3;
+
     pub async fn new() -> Self {
         CoachedProcessSpawner {
             coach: OfflineCoach::builder(),
@@ -27,7 +30,6 @@ impl CoachedProcessSpawner {
     }
 
     pub fn with_ports(&mut self, port: u16, coach_port: u16, olcoach_port: u16) -> &mut Self {
-        use std::net::{IpAddr, Ipv4Addr, SocketAddr};
         self.process_config_mut()
             .with_ports(port, coach_port, olcoach_port);
         self.coach
@@ -41,7 +43,7 @@ impl CoachedProcessSpawner {
         self
     }
     
-    pub fn with_log_dir(&mut self, log_dir: &'static str) -> &mut Self {
+    pub fn with_log_dir(&mut self, log_dir: &str) -> &mut Self {
         self.process_config_mut().with_log_dir(log_dir);
         self
     }
@@ -50,7 +52,40 @@ impl CoachedProcessSpawner {
         self.process.config_mut()
     }
 
+    pub fn with_port_allocator(&mut self, allocator: PortAllocator) -> &mut Self {
+        self.process.with_port_allocator(allocator);
+        self
+    }
+
+    /// Spawns the process and connects the trainer, with a port allocator set
+    /// a triple found taken by the time rcssserver binds it is swapped for
+    /// another up to [`Self::PORT_ATTEMPTS`] times.
     pub async fn spawn(&self) -> Result<CoachedProcess> {
+        let mut attempt = 1;
+        let process = loop {
+            match self.spawn_process().await {
+                Err(Error::PortsInUse(e)) if self.process.port_allocator().is_some() && attempt < Self::PORT_ATTEMPTS => {
+                    warn!("CoachedProcessSpawner: ports taken on attempt {attempt}, retrying: {e}");
+                    attempt += 1;
+                }
+                ret => break ret?,
+            }
+        };
+
+        let coach = {
+            let mut builder = self.coach.clone();
+            if let Some(ports) = process.ports() {
+                builder.with_peer(SocketAddr::new(IpAddr::V4(Ipv4Addr::LOCALHOST), ports.trainer));
+            }
+            let coach = builder.build_into();
+            coach.connect_and_init().await.map_err(|e| Error::ConnectCoach(e))?;
+            coach
+        };
+
+        Ok(CoachedProcess::from_started(coach, process))
+    }
+
+    async fn spawn_process(&self) -> Result<ServerProcess> {
         let process = {
             let mut process = self.process.spawn().await
                 .map_err(|e| Error::SpawnProcess(e))?;
@@ -81,18 +116,15 @@ impl CoachedProcessSpawner {
                 error!("CoachedProcessSpawner: process stdout:\n{:?}", stdout_trace);
                 error!("CoachedProcessSpawner: process stderr:\n{:?}", stderr_trace);
 
+                if process.port_conflict().await {
+                    return Err(crate::Error::PortsInUse(err))
+                }
                 return Err(crate::Error::SpawnProcess(err))
             }
             process
         };
 
-        let coach = {
-            let coach = self.coach.build();
-            coach.connect_and_init().await.map_err(|e| Error::ConnectCoach(e))?;
-            coach
-        };
-
-        Ok(CoachedProcess::from_started(coach, process))
+        Ok(process)
     }
 }
 
@@ -128,4 +160,9 @@ impl CoachedProcess {
     pub fn process(&self) -> &ServerProcess {
         &self.process
     }
+
+    /// The ports reserved for the process when spawned with a port allocator.
+    pub fn ports(&self) -> Option<Ports> {
+        self.process.ports()
+    }
 }
@@ -4,10 +4,14 @@ pub enum Error {
     ConnectCoach(crate::client::Error),
     #[error("[Process] Failed to spawn, {0}")]
     SpawnProcess(crate::process::Error),
+    #[error("[Process] Failed to spawn, a port is already in use, {0}")]
+    PortsInUse(crate::process::Error),
     #[error("[Coach] Failed to shutdown, {0}")]
     ShutdownCoach(crate::client::Error),
     #[error("[Process] Failed to shutdown, {0}")]
     ShutdownProcess(crate::process::Error),
+    #[error("[Pool] Closed, no more leases")]
+    PoolClosed,
 }
 
 pub type Result<T> = std::result::Result<T, Error>;
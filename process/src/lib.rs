@@ -1,5 +1,7 @@
 mod client;
+mod coach;
 mod coached;
+mod pool;
 mod process;
 mod test;
 mod trainer;
@@ -8,6 +10,7 @@ mod error;
 
 pub mod addon {
     pub use super::client::{Addon, CallerAddon, RawAddon};
+    pub use super::trainer::{Card, Foul, Score, TrainerEvent, TrainerEventAddon};
 }
 
 pub mod resolver {
@@ -15,11 +18,15 @@ pub mod resolver {
 }
 
 pub use client::CommandCaller;
+pub use coach::OnlineCoach;
 pub use coached::{CoachedProcess, CoachedProcessSpawner};
+pub use pool::{PoolConfig, Recycle, ServerLease, ServerPool};
 pub use process::Config as ProcessConfig;
+pub use process::{PortAllocator, PortLease, Ports};
+pub use process::{ConfigChange, ConfigError, ConfigViolation, CsvSaverConfig, PlayerConfig, Presets, Rule, ServerConfig};
 pub use common::process::ProcessStatus;
 pub use error::{Result, Error};
 
-pub use player::{Player};
+pub use player::{Perception, PerceptionAddon, Player};
 
 pub const RCSS_PROCESS_NAME: &str = "rcssserver";
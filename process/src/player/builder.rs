@@ -50,6 +50,7 @@ impl PlayerBuilder {
         let client = self.rich_client.build_into();
         let player = Player {
             client,
+            perception: Default::default(),
         };
         if !self.enable_resolver {
             if self.init_on_build.is_some() {
@@ -1,5 +1,7 @@
 mod builder;
+mod perception;
 mod player;
 
 pub use builder::PlayerBuilder;
+pub use perception::{Perception, PerceptionAddon};
 pub use player::Player;
@@ -0,0 +1,166 @@
+use log::debug;
+use tokio::sync::{broadcast, mpsc, watch};
+use tokio::task::JoinHandle;
+
+use common::client::{RxData, TxData, TxSignal};
+use common::perception::{Hear, PlayerMessage, PlayerParam, PlayerType, See, SenseBody, ServerParam};
+
+use crate::client::{Addon, RawAddon};
+
+const STREAM_CAPACITY: usize = 16;
+
+/// Parses the sensor messages a player receives and publishes them per type.
+///
+/// Per-cycle sensors go out on broadcast channels, the parameters sent once
+/// after `init` are kept on watch channels so late subscribers still see them.
+#[derive(Debug)]
+pub struct PerceptionAddon {
+    task: JoinHandle<()>,
+    handle: Perception,
+}
+
+/// Cloneable access to the channels of a [`PerceptionAddon`].
+#[derive(Clone, Debug)]
+pub struct Perception {
+    see: broadcast::Sender<See>,
+    hear: broadcast::Sender<Hear>,
+    sense_body: broadcast::Sender<SenseBody>,
+    server_param: watch::Receiver<Option<ServerParam>>,
+    player_param: watch::Receiver<Option<PlayerParam>>,
+    player_types: watch::Receiver<Vec<PlayerType>>,
+}
+
+impl Perception {
+    pub fn see(&self) -> broadcast::Receiver<See> {
+        self.see.subscribe()
+    }
+
+    pub fn hear(&self) -> broadcast::Receiver<Hear> {
+        self.hear.subscribe()
+    }
+
+    pub fn sense_body(&self) -> broadcast::Receiver<SenseBody> {
+        self.sense_body.subscribe()
+    }
+
+    pub fn server_param(&self) -> watch::Receiver<Option<ServerParam>> {
+        self.server_param.clone()
+    }
+
+    pub fn player_param(&self) -> watch::Receiver<Option<PlayerParam>> {
+        self.player_param.clone()
+    }
+
+    /// Heterogeneous player types received so far, indexed by id.
+    pub fn player_types(&self) -> watch::Receiver<Vec<PlayerType>> {
+        self.player_types.clone()
+    }
+}
+
+impl PerceptionAddon {
+    pub fn from_rx(mut receiver: mpsc::Receiver<RxData>) -> Self {
+        let (see, _) = broadcast::channel(STREAM_CAPACITY);
+        let (hear, _) = broadcast::channel(STREAM_CAPACITY);
+        let (sense_body, _) = broadcast::channel(STREAM_CAPACITY);
+        let (server_param_tx, server_param) = watch::channel(None);
+        let (player_param_tx, player_param) = watch::channel(None);
+        let (player_types_tx, player_types) = watch::channel(Vec::new());
+
+        let handle = Perception {
+            see,
+            hear,
+            sense_body,
+            server_param,
+            player_param,
+            player_types,
+        };
+
+        let channels = handle.clone();
+        let task = tokio::spawn(async move {
+            while let Some(raw_msg) = receiver.recv().await {
+                let Some(msg) = PlayerMessage::parse_str(&raw_msg) else {
+                    continue;
+                };
+
+                // a send error only means nobody listens at the moment
+                match msg {
+                    PlayerMessage::See(see) => {
+                        let _ = channels.see.send(see);
+                    }
+                    PlayerMessage::Hear(hear) => {
+                        let _ = channels.hear.send(hear);
+                    }
+                    PlayerMessage::SenseBody(body) => {
+                        let _ = channels.sense_body.send(body);
+                    }
+                    PlayerMessage::ServerParam(param) => {
+                        server_param_tx.send_replace(Some(param));
+                    }
+                    PlayerMessage::PlayerParam(param) => {
+                        player_param_tx.send_replace(Some(param));
+                    }
+                    PlayerMessage::PlayerType(ty) => {
+                        debug!("[PerceptionAddon] Received player_type {}", ty.id);
+                        player_types_tx.send_modify(|types| {
+                            let id = ty.id as usize;
+                            if types.len() <= id {
+                                types.resize(id + 1, PlayerType::default());
+                            }
+                            types[id] = ty;
+                        });
+                    }
+                }
+            }
+        });
+
+        Self { task, handle }
+    }
+
+    pub fn handle(&self) -> Perception {
+        self.handle.clone()
+    }
+}
+
+impl Addon for PerceptionAddon {
+    fn close(&self) {
+        self.task.abort();
+    }
+}
+
+impl RawAddon for PerceptionAddon {
+    fn from_raw(
+        _: mpsc::Sender<TxSignal>,
+        _: mpsc::Sender<TxData>,
+        data_rx: mpsc::Receiver<RxData>,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_rx(data_rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_publish_by_type() {
+        let (tx, rx) = mpsc::channel(8);
+        let addon = PerceptionAddon::from_rx(rx);
+        let perception = addon.handle();
+        let mut see = perception.see();
+        let mut types = perception.player_types();
+
+        tx.send("(see 1 ((b) 3 0))".into()).await.unwrap();
+        tx.send("(ok score 0 0 0)".into()).await.unwrap();
+        tx.send("(player_type (id 1) (player_decay 0.45))".into()).await.unwrap();
+
+        assert_eq!(see.recv().await.unwrap().time, 1);
+        types.changed().await.unwrap();
+        assert_eq!(types.borrow()[1].player_decay, 0.45);
+        assert!(perception.server_param().borrow().is_none());
+
+        addon.close();
+    }
+}
@@ -1,12 +1,17 @@
 use std::ops::{Deref, DerefMut};
-use log::{debug, trace};
-use common::client::RxData;
-use common::command::player::PlayerCommand;
+use std::sync::OnceLock;
+use arcstr::ArcStr;
+use log::{debug, error, trace};
+use common::command::CommandAny;
+use common::command::player::{CommandInit, PlayerCommand};
+use common::command::player::init::CommandInitOk;
+use tokio::sync::mpsc;
 use crate::client::{CallResolver, Result, RichClient};
-use super::PlayerBuilder;
+use super::{Perception, PerceptionAddon, PlayerBuilder};
 
 pub struct Player {
     pub client: RichClient<PlayerCommand>,
+    pub(super) perception: OnceLock<Perception>,
 }
 
 impl Deref for Player {
@@ -30,20 +35,29 @@ impl Player {
 
     pub(super) fn init_resolver(&self) -> Result<()> {
         trace!("[Player] Initializing CallResolver addon.");
-        let resolver = CallResolver::<PlayerCommand, RxData>::new(32);
-        self.resolver_tx
-            .set(resolver.sender(self.conn.data_sender()))
-            .map_err(|_| crate::client::Error::ResolverNotSingleton)?;
-        let id = self.subscribe(
-            resolver.ingest_tx()
-                .ok_or(crate::client::Error::ResolverNotSingleton)?
-        );
+        let id = self.client.init_resolver(CallResolver::new(32))?;
         trace!("[Player] CallResolver addon initialized, id = {id}");
-        self.addons.insert("call_resolver", Box::new(resolver));
 
         Ok(())
     }
 
+    /// Typed sensor channels, subscribe before `init` to catch the parameters.
+    pub fn perception(&self) -> Perception {
+        self.perception
+            .get_or_init(|| {
+                trace!("[Player] Adding perception addon.");
+                let (tx, rx) = mpsc::channel(32);
+                let id = self.subscribe(tx);
+                let addon = PerceptionAddon::from_rx(rx);
+                let perception = addon.handle();
+                self.addons.insert("perception", Box::new(addon));
+                trace!("[Player] Perception addon added, id = {id}");
+
+                perception
+            })
+            .clone()
+    }
+
     pub async fn connect(&self) -> Result<()> {
         trace!(
             "[Player] Connecting to host {:?} via peer {:?}",
@@ -54,4 +68,22 @@ impl Player {
         debug!("[Player] Connected.");
         Ok(())
     }
-}
\ No newline at end of file
+
+    pub async fn connect_and_init(&self, init: CommandInit) -> Result<CommandInitOk> {
+        self.connect().await?;
+
+        match self.call(init).await? {
+            Ok(ok) => {
+                trace!("[Player] Init command succeeded returned with {ok:?}.");
+                Ok(ok)
+            },
+            Err(e) => {
+                error!("[Player] Init command returned with error: {}", e);
+                Err(crate::client::Error::RcssErrorCall {
+                    kind: PlayerCommand::Init.encode(),
+                    msg: ArcStr::from(e.to_string())
+                })
+            },
+        }
+    }
+}
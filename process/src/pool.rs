@@ -0,0 +1,543 @@
+//! Warm [`CoachedProcess`]es leased out one match at a time, so a batch of
+//! matches pays the rcssserver startup once per server rather than per match.
+
+use std::collections::VecDeque;
+use std::ops::Deref;
+use std::sync::atomic::{AtomicBool, AtomicUsize, Ordering};
+use std::sync::{Arc, Weak};
+use std::time::{Duration, Instant};
+
+use futures::future::join_all;
+use log::{debug, info, warn};
+use tokio::sync::{Mutex, OwnedSemaphorePermit, Semaphore};
+
+use common::command::trainer;
+use common::types::PlayMode;
+
+use crate::{CoachedProcess, CoachedProcessSpawner, Error, PortAllocator, Result};
+
+/// What happens to a process handed back to the pool.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Recycle {
+    /// Back to `before_kick_off` with every player recovered, cheap but far
+    /// from a fresh server: the clock, the score, the half, cards and the
+    /// players still connected carry over, and the game and text logs go on
+    /// in the same files. Only for processes whose next lease does not care,
+    /// such as ones that never kicked off.
+    Reset,
+    /// Shut down, a freshly spawned process takes its place.
+    #[default]
+    Respawn,
+}
+
+#[derive(Clone, Debug)]
+pub struct PoolConfig {
+    /// Idle processes kept ready, spawned up front and topped up after each lease.
+    pub warm: usize,
+    /// Processes leased out at once, further leases wait for one to return.
+    pub max_leased: usize,
+    /// Idle processes beyond `warm` are shut down once unused for this long.
+    pub idle_timeout: Duration,
+    pub recycle: Recycle,
+}
+
+impl Default for PoolConfig {
+    fn default() -> Self {
+        Self {
+            warm: 2,
+            max_leased: 8,
+            idle_timeout: Duration::from_secs(60),
+            recycle: Recycle::default(),
+        }
+    }
+}
+
+impl PoolConfig {
+    pub fn with_warm(&mut self, warm: usize) -> &mut Self {
+        self.warm = warm;
+        self
+    }
+
+    pub fn with_max_leased(&mut self, max_leased: usize) -> &mut Self {
+        self.max_leased = max_leased;
+        self
+    }
+
+    pub fn with_idle_timeout(&mut self, idle_timeout: Duration) -> &mut Self {
+        self.idle_timeout = idle_timeout;
+        self
+    }
+
+    pub fn with_recycle(&mut self, recycle: Recycle) -> &mut Self {
+        self.recycle = recycle;
+        self
+    }
+}
+
+/// Idle items, the most recently returned is leased first so the others age
+/// out.
+#[derive(Debug)]
+struct Idle<T> {
+    items: VecDeque<(T, Instant)>,
+}
+
+impl<T> Idle<T> {
+    fn new() -> Self {
+        Self { items: VecDeque::new() }
+    }
+
+    fn len(&self) -> usize {
+        self.items.len()
+    }
+
+    fn push(&mut self, item: T, now: Instant) {
+        self.items.push_back((item, now));
+    }
+
+    fn pop(&mut self) -> Option<T> {
+        self.items.pop_back().map(|(item, _)| item)
+    }
+
+    fn drain(&mut self) -> Vec<T> {
+        self.items.drain(..).map(|(item, _)| item).collect()
+    }
+
+    /// Takes out the items idle for longer than `timeout`, leaving at least `keep`.
+    fn expired(&mut self, keep: usize, timeout: Duration, now: Instant) -> Vec<T> {
+        let mut ret = vec![];
+        while self.items.len() > keep {
+            match self.items.front() {
+                Some((_, since)) if now.duration_since(*since) > timeout => {
+                    ret.extend(self.items.pop_front().map(|(item, _)| item));
+                }
+                _ => break,
+            }
+        }
+        ret
+    }
+}
+
+/// Spawns, resets and shuts down what the pool keeps, rcssserver processes
+/// outside of tests.
+trait Backend: Send + Sync + 'static {
+    type Process: std::fmt::Debug + Send + 'static;
+
+    fn spawn(&self) -> impl Future<Output = Result<Self::Process>> + Send;
+    fn is_finished(&self, process: &Self::Process) -> bool;
+    fn reset(&self, process: &Self::Process) -> impl Future<Output = std::result::Result<(), String>> + Send;
+    fn shutdown(&self, process: &mut Self::Process) -> impl Future<Output = ()> + Send;
+}
+
+impl Backend for CoachedProcessSpawner {
+    type Process = CoachedProcess;
+
+    async fn spawn(&self) -> Result<CoachedProcess> {
+        CoachedProcessSpawner::spawn(self).await
+    }
+
+    fn is_finished(&self, process: &CoachedProcess) -> bool {
+        process.process().status_now().is_finished()
+    }
+
+    async fn reset(&self, process: &CoachedProcess) -> std::result::Result<(), String> {
+        let caller = process.command_sender();
+        caller.call(trainer::ChangeMode { play_mode: PlayMode::PM_BeforeKickOff }).await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())?;
+        caller.call(trainer::Recover).await
+            .map_err(|e| e.to_string())?
+            .map_err(|e| e.to_string())
+    }
+
+    async fn shutdown(&self, process: &mut CoachedProcess) {
+        if let Err(e) = process.shutdown().await {
+            warn!("[ServerPool] Failed to shutdown a process: {e}");
+        }
+    }
+}
+
+#[derive(Debug)]
+struct Inner<B: Backend> {
+    backend: B,
+    config: PoolConfig,
+    idle: Mutex<Idle<B::Process>>,
+    leases: Arc<Semaphore>,
+    /// Processes being spawned to top up the idle ones.
+    warming: AtomicUsize,
+    closed: AtomicBool,
+}
+
+impl<B: Backend> Inner<B> {
+    /// Spawns the warm processes, all or none.
+    async fn start(backend: B, config: PoolConfig) -> Result<Arc<Self>> {
+        let spawned = join_all((0..config.warm).map(|_| backend.spawn())).await;
+        let mut idle = Idle::new();
+        let mut failed = None;
+        for ret in spawned {
+            match ret {
+                Ok(process) => idle.push(process, Instant::now()),
+                Err(e) => failed = Some(e),
+            }
+        }
+        if let Some(e) = failed {
+            for mut process in idle.drain() {
+                backend.shutdown(&mut process).await;
+            }
+            return Err(e);
+        }
+        info!("[ServerPool] {} warm processes spawned", config.warm);
+
+        Ok(Arc::new(Self {
+            backend,
+            leases: Arc::new(Semaphore::new(config.max_leased)),
+            config,
+            idle: Mutex::new(idle),
+            warming: AtomicUsize::new(0),
+            closed: AtomicBool::new(false),
+        }))
+    }
+
+    /// An idle process, or a freshly spawned one if none is, waiting while
+    /// [`PoolConfig::max_leased`] are out.
+    async fn lease(self: &Arc<Self>) -> Result<(B::Process, OwnedSemaphorePermit)> {
+        let permit = self.leases.clone().acquire_owned().await
+            .map_err(|_| Error::PoolClosed)?;
+
+        let process = loop {
+            let idle = self.idle.lock().await.pop();
+            match idle {
+                Some(mut process) if self.backend.is_finished(&process) => {
+                    debug!("[ServerPool] Dropping an idle process that exited");
+                    self.backend.shutdown(&mut process).await;
+                }
+                Some(process) => break process,
+                None => break self.backend.spawn().await?,
+            }
+        };
+        tokio::spawn(self.clone().top_up());
+
+        Ok((process, permit))
+    }
+
+    fn leased(&self) -> usize {
+        self.config.max_leased - self.leases.available_permits()
+    }
+
+    /// Spawns processes until `warm` are idle or being spawned.
+    async fn top_up(self: Arc<Self>) {
+        loop {
+            {
+                let idle = self.idle.lock().await;
+                if self.closed.load(Ordering::Acquire) || idle.len() + self.warming.load(Ordering::Acquire) >= self.config.warm {
+                    return;
+                }
+                self.warming.fetch_add(1, Ordering::AcqRel);
+            }
+            let ret = self.backend.spawn().await;
+            self.warming.fetch_sub(1, Ordering::AcqRel);
+            match ret {
+                Ok(process) => self.give_back(process).await,
+                Err(e) => {
+                    warn!("[ServerPool] Failed to spawn a warm process: {e}");
+                    return;
+                }
+            }
+        }
+    }
+
+    /// Queues `process` as idle, or shuts it down once the pool is closed.
+    async fn give_back(&self, mut process: B::Process) {
+        if self.closed.load(Ordering::Acquire) {
+            self.backend.shutdown(&mut process).await;
+            return;
+        }
+        self.idle.lock().await.push(process, Instant::now());
+    }
+
+    /// Takes a returned process back according to [`PoolConfig::recycle`].
+    async fn recycle(self: Arc<Self>, mut process: B::Process) {
+        let reusable = !self.backend.is_finished(&process) && match self.config.recycle {
+            Recycle::Reset => match self.backend.reset(&process).await {
+                Ok(()) => true,
+                Err(e) => {
+                    warn!("[ServerPool] Failed to reset a returned process, respawning: {e}");
+                    false
+                }
+            },
+            Recycle::Respawn => false,
+        };
+
+        if reusable {
+            self.give_back(process).await;
+        } else {
+            self.backend.shutdown(&mut process).await;
+            self.top_up().await;
+        }
+    }
+
+    async fn evict(&self) {
+        let now = Instant::now();
+        let expired = self.idle.lock().await.expired(self.config.warm, self.config.idle_timeout, now);
+        if !expired.is_empty() {
+            debug!("[ServerPool] Evicting {} idle processes", expired.len());
+        }
+        for mut process in expired {
+            self.backend.shutdown(&mut process).await;
+        }
+    }
+
+    async fn eviction_task(inner: Weak<Self>) {
+        loop {
+            let Some(timeout) = inner.upgrade().map(|inner| inner.config.idle_timeout) else { return };
+            tokio::time::sleep(timeout).await;
+            match inner.upgrade() {
+                Some(inner) if !inner.closed.load(Ordering::Acquire) => inner.evict().await,
+                _ => return,
+            }
+        }
+    }
+
+    async fn close(&self) {
+        self.closed.store(true, Ordering::Release);
+        self.leases.close();
+        let idle = self.idle.lock().await.drain();
+        for mut process in idle {
+            self.backend.shutdown(&mut process).await;
+        }
+    }
+}
+
+/// Keeps [`PoolConfig::warm`] processes ready and leases them out, cheap to
+/// clone and shared by clones.
+#[derive(Clone, Debug)]
+pub struct ServerPool {
+    inner: Arc<Inner<CoachedProcessSpawner>>,
+}
+
+impl ServerPool {
+    /// Spawns the warm processes, every spawn picks its ports from the
+    /// spawner's port allocator or a default one if it has none.
+    pub async fn new(mut spawner: CoachedProcessSpawner, config: PoolConfig) -> Result<Self> {
+        if spawner.process.port_allocator().is_none() {
+            spawner.with_port_allocator(PortAllocator::default());
+        }
+
+        let inner = Inner::start(spawner, config).await?;
+        tokio::spawn(Inner::eviction_task(Arc::downgrade(&inner)));
+        Ok(Self { inner })
+    }
+
+    /// An idle process, or a freshly spawned one if none is, waiting while
+    /// [`PoolConfig::max_leased`] are out.
+    pub async fn lease(&self) -> Result<ServerLease> {
+        let (process, permit) = self.inner.lease().await?;
+        Ok(ServerLease { process: Some(process), pool: self.inner.clone(), permit: Some(permit) })
+    }
+
+    pub async fn idle(&self) -> usize {
+        self.inner.idle.lock().await.len()
+    }
+
+    pub fn leased(&self) -> usize {
+        self.inner.leased()
+    }
+
+    /// Shuts down the idle processes and every leased one once returned,
+    /// further leases fail with [`Error::PoolClosed`].
+    pub async fn shutdown(&self) {
+        self.inner.close().await;
+    }
+}
+
+/// A process leased from a [`ServerPool`], returned when released or dropped.
+#[derive(Debug)]
+pub struct ServerLease {
+    process: Option<CoachedProcess>,
+    pool: Arc<Inner<CoachedProcessSpawner>>,
+    permit: Option<OwnedSemaphorePermit>,
+}
+
+impl ServerLease {
+    /// Returns the process and waits until it is reset or replaced.
+    pub async fn release(mut self) {
+        let (Some(process), permit) = (self.process.take(), self.permit.take()) else { return };
+        self.pool.clone().recycle(process).await;
+        drop(permit);
+    }
+}
+
+impl Deref for ServerLease {
+    type Target = CoachedProcess;
+
+    fn deref(&self) -> &Self::Target {
+        self.process.as_ref().expect("process taken before the lease dropped")
+    }
+}
+
+impl Drop for ServerLease {
+    fn drop(&mut self) {
+        let (Some(process), permit) = (self.process.take(), self.permit.take()) else { return };
+        let Ok(handle) = tokio::runtime::Handle::try_current() else {
+            warn!("[ServerPool] Lease dropped outside a runtime, the process is not returned");
+            return;
+        };
+        let pool = self.pool.clone();
+        handle.spawn(async move {
+            pool.recycle(process).await;
+            drop(permit);
+        });
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug)]
+    struct FakeProcess {
+        id: usize,
+        finished: bool,
+        resettable: bool,
+    }
+
+    /// Counts what the pool asks of it, spawned processes are numbered from 0.
+    #[derive(Debug, Default)]
+    struct Fake {
+        spawned: AtomicUsize,
+        shut_down: AtomicUsize,
+    }
+
+    impl Backend for Arc<Fake> {
+        type Process = FakeProcess;
+
+        async fn spawn(&self) -> Result<FakeProcess> {
+            let id = self.spawned.fetch_add(1, Ordering::AcqRel);
+            Ok(FakeProcess { id, finished: false, resettable: true })
+        }
+
+        fn is_finished(&self, process: &FakeProcess) -> bool {
+            process.finished
+        }
+
+        async fn reset(&self, process: &FakeProcess) -> std::result::Result<(), String> {
+            process.resettable.then_some(()).ok_or_else(|| "no reply".to_string())
+        }
+
+        async fn shutdown(&self, _: &mut FakeProcess) {
+            self.shut_down.fetch_add(1, Ordering::AcqRel);
+        }
+    }
+
+    async fn start(config: &PoolConfig) -> (Arc<Fake>, Arc<Inner<Arc<Fake>>>) {
+        let fake = Arc::new(Fake::default());
+        let inner = Inner::start(fake.clone(), config.clone()).await.unwrap();
+        (fake, inner)
+    }
+
+    /// Lets the top-up spawned after a lease finish.
+    async fn settle() {
+        tokio::time::sleep(Duration::from_millis(10)).await;
+    }
+
+    #[test]
+    fn test_idle_eviction_order() {
+        let start = Instant::now();
+        let mut idle = Idle::new();
+        for (item, secs) in [(1, 0), (2, 10), (3, 20)] {
+            idle.push(item, start + Duration::from_secs(secs));
+        }
+
+        let now = start + Duration::from_secs(25);
+        // idle for 25 and 15 seconds, the second is kept to leave two
+        assert_eq!(idle.expired(2, Duration::from_secs(12), now), vec![1]);
+        assert_eq!(idle.expired(0, Duration::from_secs(12), now), vec![2]);
+
+        idle.push(4, now);
+        assert_eq!(idle.pop(), Some(4));
+        assert_eq!(idle.drain(), vec![3]);
+        assert_eq!(idle.pop(), None);
+    }
+
+    #[tokio::test]
+    async fn test_lease_and_return() {
+        let mut config = PoolConfig::default();
+        config.with_warm(1).with_max_leased(2).with_recycle(Recycle::Reset);
+        let (fake, inner) = start(&config).await;
+        assert_eq!(fake.spawned.load(Ordering::Acquire), 1);
+
+        // the warm process goes out first and another is spawned to replace it
+        let (first, permit) = inner.lease().await.unwrap();
+        assert_eq!((first.id, inner.leased()), (0, 1));
+        settle().await;
+        assert_eq!(inner.idle.lock().await.len(), 1);
+
+        let second = inner.lease().await.unwrap();
+        assert_eq!((second.0.id, inner.leased()), (1, 2));
+        // no more than max_leased out at once
+        assert!(tokio::time::timeout(Duration::from_millis(20), inner.lease()).await.is_err());
+
+        // a reset process is idle again and leased before the older one
+        inner.clone().recycle(first).await;
+        drop(permit);
+        settle().await;
+        assert_eq!(inner.leased(), 1);
+        assert_eq!(inner.lease().await.unwrap().0.id, 0);
+        assert_eq!(fake.shut_down.load(Ordering::Acquire), 0);
+
+        // one that fails to reset is replaced
+        let (third, _) = inner.lease().await.unwrap();
+        let spawned = fake.spawned.load(Ordering::Acquire);
+        inner.clone().recycle(FakeProcess { resettable: false, ..third }).await;
+        assert_eq!(fake.shut_down.load(Ordering::Acquire), 1);
+        assert!(fake.spawned.load(Ordering::Acquire) > spawned);
+    }
+
+    #[tokio::test]
+    async fn test_respawn_by_default() {
+        let config = PoolConfig { warm: 1, ..Default::default() };
+        assert_eq!(config.recycle, Recycle::Respawn);
+        let (fake, inner) = start(&config).await;
+
+        let (process, _) = inner.lease().await.unwrap();
+        settle().await;
+        inner.clone().recycle(process).await;
+        assert_eq!(fake.shut_down.load(Ordering::Acquire), 1);
+        assert_eq!(inner.idle.lock().await.len(), 1);
+
+        // an idle process that exited is dropped rather than leased
+        inner.idle.lock().await.push(FakeProcess { id: 99, finished: true, resettable: true }, Instant::now());
+        let (process, _) = inner.lease().await.unwrap();
+        assert_ne!(process.id, 99);
+        assert_eq!(fake.shut_down.load(Ordering::Acquire), 2);
+    }
+
+    #[tokio::test]
+    async fn test_evict_and_close() {
+        let mut config = PoolConfig::default();
+        config.with_warm(1).with_idle_timeout(Duration::from_millis(20)).with_recycle(Recycle::Reset);
+        let (fake, inner) = start(&config).await;
+
+        let leases = vec![inner.lease().await.unwrap(), inner.lease().await.unwrap()];
+        settle().await;
+        for (process, _) in leases {
+            inner.clone().recycle(process).await;
+        }
+        assert_eq!(inner.idle.lock().await.len(), 3);
+
+        // only the idle processes beyond warm are shut down once timed out
+        inner.evict().await;
+        assert_eq!(fake.shut_down.load(Ordering::Acquire), 0);
+        tokio::time::sleep(Duration::from_millis(30)).await;
+        inner.evict().await;
+        assert_eq!(inner.idle.lock().await.len(), 1);
+        assert_eq!(fake.shut_down.load(Ordering::Acquire), 2);
+
+        let (process, _) = inner.lease().await.unwrap();
+        inner.close().await;
+        assert!(matches!(inner.lease().await, Err(Error::PoolClosed)));
+        // returned after closing, shut down rather than kept
+        inner.clone().recycle(process).await;
+        assert_eq!(inner.idle.lock().await.len(), 0);
+        assert_eq!(fake.shut_down.load(Ordering::Acquire), fake.spawned.load(Ordering::Acquire));
+    }
+}
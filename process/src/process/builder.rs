@@ -9,6 +9,7 @@ use super::*;
 pub struct ServerProcessSpawner {
     pgm_name: &'static str,
     pub config: Config,
+    ports: Option<PortAllocator>,
 }
 
 impl ServerProcessSpawner {
@@ -17,6 +18,7 @@ impl ServerProcessSpawner {
         Self {
             pgm_name,
             config: Config::default_trainer_on(),
+            ports: None,
         }
     }
 
@@ -54,15 +56,32 @@ impl ServerProcessSpawner {
         }
     }
 
-    fn build_start_cmd(&self) -> Command {
+    fn build_start_cmd(&self, config: &Config) -> Command {
         let mut cmd = Command::new("stdbuf");
         cmd.arg("-oL").arg("-eL").arg(self.pgm_name);
-        cmd.args(self.config.to_args());
+        cmd.args(config.to_args());
         cmd
     }
 
+    /// Spawns on a triple reserved from the port allocator if one is set,
+    /// otherwise on the configured ports.
     pub async fn spawn(&self) -> Result<ServerProcess> {
-        let mut cmd = self.build_start_cmd();
+        let Some(allocator) = &self.ports else {
+            return self.spawn_with(&self.config).await;
+        };
+
+        let lease = allocator.reserve()?;
+        let ports = lease.ports();
+        let mut config = self.config.clone();
+        config.with_ports(ports.player, ports.trainer, ports.coach);
+
+        let mut process = self.spawn_with(&config).await?;
+        process.hold_ports(lease);
+        Ok(process)
+    }
+
+    async fn spawn_with(&self, config: &Config) -> Result<ServerProcess> {
+        let mut cmd = self.build_start_cmd(config);
         cmd.stdin(Stdio::piped());
         cmd.stdout(Stdio::piped());
         cmd.stderr(Stdio::piped());
@@ -78,4 +97,14 @@ impl ServerProcessSpawner {
     pub fn config_mut(&mut self) -> &mut Config {
         &mut self.config
     }
+
+    /// Picks the ports of every spawn from `allocator` instead of the config.
+    pub fn with_port_allocator(&mut self, allocator: PortAllocator) -> &mut Self {
+        self.ports = Some(allocator);
+        self
+    }
+
+    pub fn port_allocator(&self) -> Option<&PortAllocator> {
+        self.ports.as_ref()
+    }
 }
@@ -5,6 +5,9 @@ pub enum Error {
     #[error("Failed to start server: system process limit is reached. source: {0}")]
     MaxProcessReached(#[source] std::io::Error),
 
+    #[error("No free port triple left in {0:?}")]
+    PortsExhausted(std::ops::Range<u16>),
+
     #[error(transparent)]
     Io(#[from] std::io::Error),
 
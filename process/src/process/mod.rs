@@ -1,12 +1,14 @@
 mod builder;
-pub mod config;
+pub use common::process::config;
 pub mod error;
+pub mod ports;
 pub mod process;
 
 pub use config::*;
 pub use error::*;
 
 pub use builder::ServerProcessSpawner;
+pub use ports::{PortAllocator, PortLease, Ports};
 pub use process::ServerProcess;
 pub use common::process::ProcessError;
 pub use common::process::ProcessStatusKind as StatusKind;
@@ -0,0 +1,147 @@
+//! Free `port`, `coach_port` and `olcoach_port` triples for servers sharing a
+//! host, reserved until the process holding them exits.
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::net::{Ipv4Addr, UdpSocket};
+use std::ops::Range;
+use std::sync::{Arc, Mutex};
+
+use super::error::{Error, Result};
+
+/// What rcssserver logs when one of its sockets fails to bind.
+pub const ADDR_IN_USE: &str = "Address already in use";
+
+/// The three udp ports of one rcssserver.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Ports {
+    /// `server::port`
+    pub player: u16,
+    /// `server::coach_port`, the offline coach
+    pub trainer: u16,
+    /// `server::olcoach_port`, the online coach
+    pub coach: u16,
+}
+
+impl fmt::Display for Ports {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}/{}/{}", self.player, self.trainer, self.coach)
+    }
+}
+
+#[derive(Debug)]
+struct Reservations {
+    ports: BTreeSet<u16>,
+    /// Where the next search starts, so a triple just released or found in
+    /// use is the last one tried again.
+    cursor: u16,
+}
+
+/// Hands out port triples from `range`, cheap to clone and shared by clones.
+#[derive(Clone, Debug)]
+pub struct PortAllocator {
+    range: Range<u16>,
+    reserved: Arc<Mutex<Reservations>>,
+}
+
+impl Default for PortAllocator {
+    fn default() -> Self {
+        Self::new(6000..9000)
+    }
+}
+
+impl PortAllocator {
+    pub fn new(range: Range<u16>) -> Self {
+        let reserved = Reservations { ports: BTreeSet::new(), cursor: range.start };
+        Self { range, reserved: Arc::new(Mutex::new(reserved)) }
+    }
+
+    /// Reserves the next triple neither reserved here nor bound on this host.
+    pub fn reserve(&self) -> Result<PortLease> {
+        let mut reserved = self.reserved.lock().expect("port reservations poisoned");
+        let starts: Vec<u16> = self.range.clone().step_by(3)
+            .filter(|start| start + 2 < self.range.end)
+            .collect();
+        let first = starts.iter().position(|start| *start >= reserved.cursor).unwrap_or(0);
+
+        for start in starts[first..].iter().chain(&starts[..first]) {
+            let triple = [*start, start + 1, start + 2];
+            if triple.iter().any(|port| reserved.ports.contains(port) || !Self::is_free(*port)) {
+                continue;
+            }
+            reserved.ports.extend(triple);
+            reserved.cursor = start + 3;
+            let ports = Ports { player: triple[0], trainer: triple[1], coach: triple[2] };
+            return Ok(PortLease { ports, allocator: self.clone() });
+        }
+        Err(Error::PortsExhausted(self.range.clone()))
+    }
+
+    /// Ports currently reserved, three per lease.
+    pub fn reserved(&self) -> usize {
+        self.reserved.lock().expect("port reservations poisoned").ports.len()
+    }
+
+    fn is_free(port: u16) -> bool {
+        UdpSocket::bind((Ipv4Addr::UNSPECIFIED, port)).is_ok()
+    }
+
+    fn release(&self, ports: Ports) {
+        let mut reserved = self.reserved.lock().expect("port reservations poisoned");
+        for port in [ports.player, ports.trainer, ports.coach] {
+            reserved.ports.remove(&port);
+        }
+    }
+}
+
+/// A reserved triple, released when dropped.
+#[derive(Debug)]
+pub struct PortLease {
+    ports: Ports,
+    allocator: PortAllocator,
+}
+
+impl PortLease {
+    pub fn ports(&self) -> Ports {
+        self.ports
+    }
+}
+
+impl Drop for PortLease {
+    fn drop(&mut self) {
+        self.allocator.release(self.ports);
+    }
+}
+
+/// Whether rcssserver's output shows it failed on a port taken by someone else.
+pub(crate) fn is_conflict(lines: &[String]) -> bool {
+    lines.iter().any(|line| line.contains(ADDR_IN_USE))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_reserve_and_release() {
+        let allocator = PortAllocator::new(47000..47009);
+        let taken = UdpSocket::bind((Ipv4Addr::UNSPECIFIED, 47004)).unwrap();
+
+        let first = allocator.reserve().unwrap();
+        assert_eq!(first.ports(), Ports { player: 47000, trainer: 47001, coach: 47002 });
+        // 47003..47006 has a port bound outside the allocator
+        let second = allocator.reserve().unwrap();
+        assert_eq!(second.ports().player, 47006);
+        assert!(matches!(allocator.reserve(), Err(Error::PortsExhausted(_))));
+        assert_eq!(allocator.reserved(), 6);
+
+        drop(first);
+        drop(taken);
+        assert_eq!(allocator.reserved(), 3);
+        // the search wraps around past the end of the range
+        assert_eq!(allocator.reserve().unwrap().ports().player, 47000);
+        assert_eq!(allocator.reserved(), 3);
+
+        assert!(is_conflict(&["Error initializing sockets: Address already in use".to_string()]));
+    }
+}
@@ -9,6 +9,7 @@ use log::{warn, error};
 
 use super::builder::ServerProcessSpawner;
 use super::error::{Error, Result};
+use super::ports::{self, PortLease, Ports};
 
 pub const READY_LINE: &str = "Hit CTRL-C to exit";
 
@@ -16,6 +17,7 @@ pub const READY_LINE: &str = "Hit CTRL-C to exit";
 pub struct ServerProcess {
     inner: Process,
     status_rx: watch::Receiver<Status>,
+    ports: Option<Ports>,
 }
 
 impl ServerProcess {
@@ -87,9 +89,31 @@ impl ServerProcess {
         Ok(Self {
             inner,
             status_rx,
+            ports: None,
         })
     }
 
+    /// Keeps `lease` reserved until the process exits.
+    pub(crate) fn hold_ports(&mut self, lease: PortLease) {
+        self.ports = Some(lease.ports());
+        let mut status_rx = self.status_watch();
+        tokio::spawn(async move {
+            let _ = status_rx.wait_for(|s| s.is_finished()).await;
+            drop(lease);
+        });
+    }
+
+    /// The ports reserved for the process, `None` when spawned on the configured ones.
+    pub fn ports(&self) -> Option<Ports> {
+        self.ports
+    }
+
+    /// Whether the process failed because one of its ports was taken.
+    pub async fn port_conflict(&self) -> bool {
+        let status = self.status_now();
+        ports::is_conflict(&status.stderr_logs().await) || ports::is_conflict(&status.stdout_logs().await)
+    }
+
     pub fn status_now(&self) -> Status {
         self.status_rx.borrow().clone()
     }
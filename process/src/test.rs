@@ -1,21 +1,15 @@
 #[tokio::test]
 async fn processes_spawn_and_shutdown_1k() {
-    use crate::process::ServerProcess;
+    use crate::process::{PortAllocator, ServerProcess};
     use futures::future::join_all;
-    use itertools::Itertools;
     use rand::random_range;
 
     let mut tasks = vec![];
 
     let mut builder = ServerProcess::spawner("rcssserver").await;
+    builder.with_port_allocator(PortAllocator::new(6000..9000));
 
-    for mut ports in (6000..=9000).chunks(3).into_iter() {
-        if let Some((server, coach, trainer)) = ports.next_tuple() {
-            builder.config.with_ports(server, coach, trainer);
-        } else {
-            break;
-        }
-
+    for _ in 0..1000 {
         let mut process = builder.spawn().await.unwrap();
         println!("Process running, pid = {:?}", process.pid());
         let task = tokio::spawn(async move {
@@ -6,11 +6,17 @@ use common::{client, command};
 use log::{debug, error, trace};
 use arcstr::ArcStr;
 use std::ops::{Deref, DerefMut};
+use std::sync::OnceLock;
 use common::command::{CommandAny};
+use common::types::WorldSnapshot;
+use tokio::sync::{broadcast, mpsc, watch};
+use super::{SeeGlobalAddon, TrainerEvent, TrainerEventAddon};
 
 #[derive(Debug)]
 pub struct OfflineCoach {
     client: RichClient<TrainerCommand>,
+    see_global: OnceLock<watch::Receiver<Option<WorldSnapshot>>>,
+    events: OnceLock<broadcast::Sender<TrainerEvent>>,
 }
 
 impl OfflineCoach {
@@ -30,21 +36,17 @@ impl OfflineCoach {
         );
         let client = RichClient::from_client_config(config);
 
-        Self { client }
+        Self {
+            client,
+            see_global: OnceLock::new(),
+            events: OnceLock::new(),
+        }
     }
 
     pub(super) fn init_resolver(&self) -> Result<()> {
         trace!("[OfflineCoach] Initializing CallResolver addon.");
-        let resolver = CallResolver::<TrainerCommand, RxData>::new(32);
-        self.resolver_tx
-            .set(resolver.sender(self.conn.data_sender()))
-            .map_err(|_| crate::client::Error::ResolverNotSingleton)?;
-        let id = self.subscribe(
-            resolver.ingest_tx()
-                .ok_or(crate::client::Error::ResolverNotSingleton)?
-        );
+        let id = self.client.init_resolver(CallResolver::new(32))?;
         trace!("[OfflineCoach] CallResolver addon initialized, id = {id}");
-        self.addons.insert("call_resolver", Box::new(resolver));
 
         Ok(())
     }
@@ -80,6 +82,51 @@ impl OfflineCoach {
         }
     }
     
+    /// `look` with every player's side filled in from `team_names`.
+    pub async fn look(&self) -> Result<WorldSnapshot> {
+        let Ok(teams) = self.call(command::trainer::TeamNames).await?;
+        let Ok(mut world) = self.call(command::trainer::Look).await?;
+        world.assign_sides(teams.left.as_deref(), teams.right.as_deref());
+
+        Ok(world)
+    }
+
+    /// The latest `see_global` frame, only updated after `(eye on)`.
+    ///
+    /// Players come without a side, see [`WorldSnapshot::assign_sides`].
+    pub fn see_global(&self) -> watch::Receiver<Option<WorldSnapshot>> {
+        self.see_global
+            .get_or_init(|| {
+                trace!("[OfflineCoach] Adding see_global addon.");
+                let (tx, rx) = mpsc::channel(32);
+                let id = self.subscribe(tx);
+                let addon = SeeGlobalAddon::from_rx(rx);
+                let world_rx = addon.watch();
+                self.addons.insert("see_global", Box::new(addon));
+                trace!("[OfflineCoach] see_global addon added, id = {id}");
+
+                world_rx
+            })
+            .clone()
+    }
+
+    /// Referee messages and players' `say`, only heard after `(ear on)`.
+    pub fn events(&self) -> broadcast::Receiver<TrainerEvent> {
+        self.events
+            .get_or_init(|| {
+                trace!("[OfflineCoach] Adding trainer event addon.");
+                let (tx, rx) = mpsc::channel(32);
+                let id = self.subscribe(tx);
+                let addon = TrainerEventAddon::from_rx(rx);
+                let events = addon.sender();
+                self.addons.insert("trainer_event", Box::new(addon));
+                trace!("[OfflineCoach] Trainer event addon added, id = {id}");
+
+                events
+            })
+            .subscribe()
+    }
+
     pub fn command_sender(&self) -> CallSender<TrainerCommand, TxData, RxData> {
         self.caller()
     }
@@ -0,0 +1,317 @@
+use log::debug;
+use serde::{Deserialize, Serialize};
+use tokio::sync::{broadcast, mpsc};
+use tokio::task::JoinHandle;
+
+use common::client::{RxData, TxData, TxSignal};
+use common::sexpr::{self, Sexpr};
+use common::types::{PlayMode, Side};
+
+use crate::client::{Addon, RawAddon};
+
+const STREAM_CAPACITY: usize = 64;
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Score {
+    pub left: u32,
+    pub right: u32,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Foul {
+    Charge,
+    Push,
+    MultipleAttack,
+    BallOut,
+}
+
+#[derive(Serialize, Deserialize, Clone, Copy, Debug, PartialEq, Eq)]
+#[serde(rename_all = "snake_case")]
+pub enum Card {
+    Yellow,
+    Red,
+}
+
+/// Unsolicited traffic a trainer hears once `(ear on)` is set.
+///
+/// A referee message that changes the play mode always comes with a
+/// [`TrainerEvent::PlayMode`] first, followed by the more specific event.
+#[derive(Serialize, Deserialize, Clone, Debug, PartialEq)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum TrainerEvent {
+    PlayMode { time: u16, play_mode: PlayMode },
+    /// `goal_SIDE_N`, `score` includes this goal.
+    Goal { time: u16, side: Side, score: Score },
+    HalfTime { time: u16 },
+    TimeOver { time: u16 },
+    /// `foul_KIND_SIDE`, `side` is the side that committed the foul.
+    Foul { time: u16, side: Side, foul: Foul },
+    /// `yellow_card_SIDE_UNUM` and `red_card_SIDE_UNUM`.
+    Card { time: u16, side: Side, unum: u8, card: Card },
+    /// A player's `say`, `(hear TIME (p "TEAM" UNUM) "MESSAGE")`.
+    PlayerHear { time: u16, team: String, unum: u8, message: String },
+    /// Any other referee message, e.g. `time_extended` or `goalie_catch_ball_l`.
+    Referee { time: u16, message: String },
+}
+
+impl TrainerEvent {
+    pub fn time(&self) -> u16 {
+        match self {
+            TrainerEvent::PlayMode { time, .. }
+            | TrainerEvent::Goal { time, .. }
+            | TrainerEvent::HalfTime { time }
+            | TrainerEvent::TimeOver { time }
+            | TrainerEvent::Foul { time, .. }
+            | TrainerEvent::Card { time, .. }
+            | TrainerEvent::PlayerHear { time, .. }
+            | TrainerEvent::Referee { time, .. } => *time,
+        }
+    }
+}
+
+/// Turns `hear` messages into events, keeping the score since referee
+/// messages only carry the scoring side's goals.
+#[derive(Debug, Default)]
+struct Classifier {
+    score: Score,
+}
+
+impl Classifier {
+    fn classify(&mut self, msg: &Sexpr) -> Vec<TrainerEvent> {
+        if msg.head() != Some("hear") {
+            return Vec::new();
+        }
+        let [time, sender, message] = msg.args() else {
+            return Vec::new();
+        };
+        let Some(time) = time.parse_atom::<u16>() else {
+            return Vec::new();
+        };
+
+        if sender.atom() == Some("referee")
+            && let Some(message) = message.atom()
+        {
+            return self.referee(time, message);
+        }
+
+        // (p "TEAM" UNUM) or (player TEAM UNUM), goalies carry a trailing `goalie`
+        if let [head, team, unum, ..] = sender.list().unwrap_or_default()
+            && matches!(head.atom(), Some("p" | "player"))
+            && let Some(team) = team.text()
+            && let Some(unum) = unum.parse_atom()
+            && let Some(message) = message.text()
+        {
+            return vec![TrainerEvent::PlayerHear {
+                time,
+                team: team.to_string(),
+                unum,
+                message: message.to_string(),
+            }];
+        }
+
+        Vec::new()
+    }
+
+    fn referee(&mut self, time: u16, message: &str) -> Vec<TrainerEvent> {
+        if let Some(play_mode) = PlayMode::decode(message) {
+            if play_mode == PlayMode::PM_BeforeKickOff && time == 0 {
+                self.score = Score::default();
+            }
+
+            let mut events = vec![TrainerEvent::PlayMode { time, play_mode }];
+            if play_mode == PlayMode::PM_TimeOver {
+                events.push(TrainerEvent::TimeOver { time });
+            }
+            if let Some((foul, side)) = Self::foul(message) {
+                events.push(TrainerEvent::Foul { time, side, foul });
+            }
+            return events;
+        }
+
+        if message == "half_time" {
+            return vec![TrainerEvent::HalfTime { time }];
+        }
+
+        if let Some(rest) = message.strip_prefix("goal_")
+            && let Some((side, goals)) = rest.split_once('_')
+            && let Some(side) = Side::decode(side)
+            && let Ok(goals) = goals.parse()
+        {
+            let play_mode = match side {
+                Side::LEFT => PlayMode::PM_AfterGoal_Left,
+                _ => PlayMode::PM_AfterGoal_Right,
+            };
+            match side {
+                Side::LEFT => self.score.left = goals,
+                _ => self.score.right = goals,
+            }
+            return vec![
+                TrainerEvent::PlayMode { time, play_mode },
+                TrainerEvent::Goal {
+                    time,
+                    side,
+                    score: self.score,
+                },
+            ];
+        }
+
+        for (prefix, card) in [("yellow_card_", Card::Yellow), ("red_card_", Card::Red)] {
+            if let Some(rest) = message.strip_prefix(prefix)
+                && let Some((side, unum)) = rest.split_once('_')
+                && let Some(side) = Side::decode(side)
+                && let Ok(unum) = unum.parse()
+            {
+                return vec![TrainerEvent::Card {
+                    time,
+                    side,
+                    unum,
+                    card,
+                }];
+            }
+        }
+
+        vec![TrainerEvent::Referee {
+            time,
+            message: message.to_string(),
+        }]
+    }
+
+    fn foul(message: &str) -> Option<(Foul, Side)> {
+        let (kind, side) = message.strip_prefix("foul_")?.rsplit_once('_')?;
+        let foul = match kind {
+            "charge" => Foul::Charge,
+            "push" => Foul::Push,
+            "multiple_attack" => Foul::MultipleAttack,
+            "ballout" => Foul::BallOut,
+            _ => return None,
+        };
+        Some((foul, Side::decode(side)?))
+    }
+}
+
+/// Classifies the unsolicited messages of a trainer connection into
+/// [`TrainerEvent`]s and fans them out on a broadcast channel.
+#[derive(Debug)]
+pub struct TrainerEventAddon {
+    task: JoinHandle<()>,
+    events: broadcast::Sender<TrainerEvent>,
+}
+
+impl TrainerEventAddon {
+    pub fn from_rx(mut receiver: mpsc::Receiver<RxData>) -> Self {
+        let (events, _) = broadcast::channel(STREAM_CAPACITY);
+
+        let events_ = events.clone();
+        let task = tokio::spawn(async move {
+            let mut classifier = Classifier::default();
+            while let Some(raw_msg) = receiver.recv().await {
+                if !raw_msg.starts_with("(hear") {
+                    continue;
+                }
+                let Ok(msg) = sexpr::parse(&raw_msg) else {
+                    debug!("[TrainerEventAddon] Ignore malformed message: {raw_msg:?}");
+                    continue;
+                };
+
+                // a send error only means nobody listens at the moment
+                for event in classifier.classify(&msg) {
+                    let _ = events_.send(event);
+                }
+            }
+        });
+
+        Self { task, events }
+    }
+
+    pub fn sender(&self) -> broadcast::Sender<TrainerEvent> {
+        self.events.clone()
+    }
+
+    pub fn subscribe(&self) -> broadcast::Receiver<TrainerEvent> {
+        self.events.subscribe()
+    }
+}
+
+impl Addon for TrainerEventAddon {
+    fn close(&self) {
+        self.task.abort();
+    }
+}
+
+impl RawAddon for TrainerEventAddon {
+    fn from_raw(
+        _: mpsc::Sender<TxSignal>,
+        _: mpsc::Sender<TxData>,
+        data_rx: mpsc::Receiver<RxData>,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_rx(data_rx)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn classify(classifier: &mut Classifier, raw: &str) -> Vec<TrainerEvent> {
+        classifier.classify(&sexpr::parse(raw).unwrap())
+    }
+
+    #[test]
+    fn test_classify_referee() {
+        let mut c = Classifier::default();
+        assert_eq!(
+            classify(&mut c, "(hear 0 referee kick_off_l)"),
+            vec![TrainerEvent::PlayMode { time: 0, play_mode: PlayMode::PM_KickOff_Left }]
+        );
+        assert_eq!(
+            classify(&mut c, "(hear 120 referee goal_r_1)")[1],
+            TrainerEvent::Goal { time: 120, side: Side::RIGHT, score: Score { left: 0, right: 1 } }
+        );
+        assert_eq!(
+            classify(&mut c, "(hear 300 referee goal_l_1)")[1],
+            TrainerEvent::Goal { time: 300, side: Side::LEFT, score: Score { left: 1, right: 1 } }
+        );
+        assert_eq!(
+            classify(&mut c, "(hear 310 referee foul_charge_r)"),
+            vec![
+                TrainerEvent::PlayMode { time: 310, play_mode: PlayMode::PM_Foul_Charge_Right },
+                TrainerEvent::Foul { time: 310, side: Side::RIGHT, foul: Foul::Charge },
+            ]
+        );
+        assert_eq!(
+            classify(&mut c, "(hear 311 referee yellow_card_r_4)"),
+            vec![TrainerEvent::Card { time: 311, side: Side::RIGHT, unum: 4, card: Card::Yellow }]
+        );
+        assert_eq!(
+            classify(&mut c, "(hear 3000 referee half_time)"),
+            vec![TrainerEvent::HalfTime { time: 3000 }]
+        );
+        assert_eq!(
+            classify(&mut c, "(hear 6000 referee time_over)")[1],
+            TrainerEvent::TimeOver { time: 6000 }
+        );
+        assert_eq!(
+            classify(&mut c, "(hear 6000 referee time_extended)"),
+            vec![TrainerEvent::Referee { time: 6000, message: "time_extended".to_string() }]
+        );
+    }
+
+    #[test]
+    fn test_classify_player_hear() {
+        let mut c = Classifier::default();
+        assert_eq!(
+            classify(&mut c, "(hear 42 (p \"HELIOS\" 7) \"pass\")"),
+            vec![TrainerEvent::PlayerHear {
+                time: 42,
+                team: "HELIOS".to_string(),
+                unum: 7,
+                message: "pass".to_string(),
+            }]
+        );
+        assert!(classify(&mut c, "(see_global 42 ((b) 0 0 0 0))").is_empty());
+    }
+}
@@ -1,6 +1,10 @@
 mod builder;
 mod coach;
+mod event;
+mod see_global;
 
 pub use builder::OfflineCoachBuilder as Builder;
 pub use coach::OfflineCoach;
 pub use coach::OfflineCoach as Trainer;
+pub use event::{Card, Foul, Score, TrainerEvent, TrainerEventAddon};
+pub use see_global::SeeGlobalAddon;
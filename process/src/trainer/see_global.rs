@@ -0,0 +1,68 @@
+use log::debug;
+use tokio::sync::{mpsc, watch};
+use tokio::task::JoinHandle;
+
+use common::client::{RxData, TxData, TxSignal};
+use common::sexpr;
+use common::types::WorldSnapshot;
+
+use crate::client::{Addon, RawAddon};
+
+/// Keeps the latest unsolicited `see_global` frame, sent every cycle once the
+/// trainer has `(eye on)`.
+#[derive(Debug)]
+pub struct SeeGlobalAddon {
+    task: JoinHandle<()>,
+    world_rx: watch::Receiver<Option<WorldSnapshot>>,
+}
+
+impl SeeGlobalAddon {
+    pub fn from_rx(mut receiver: mpsc::Receiver<RxData>) -> Self {
+        let (world_tx, world_rx) = watch::channel(None);
+
+        let task = tokio::spawn(async move {
+            while let Some(raw_msg) = receiver.recv().await {
+                if !raw_msg.starts_with("(see_global") {
+                    continue;
+                }
+
+                let world = sexpr::parse(&raw_msg)
+                    .ok()
+                    .and_then(|msg| WorldSnapshot::parse_see_global(&msg));
+                match world {
+                    Some(world) => {
+                        if world_tx.send(Some(world)).is_err() {
+                            break;
+                        }
+                    }
+                    None => debug!("[SeeGlobalAddon] Ignore malformed frame: {raw_msg:?}"),
+                }
+            }
+        });
+
+        Self { task, world_rx }
+    }
+
+    pub fn watch(&self) -> watch::Receiver<Option<WorldSnapshot>> {
+        self.world_rx.clone()
+    }
+}
+
+impl Addon for SeeGlobalAddon {
+    fn close(&self) {
+        self.task.abort();
+    }
+}
+
+impl RawAddon for SeeGlobalAddon {
+    fn from_raw(
+        _: mpsc::Sender<TxSignal>,
+        _: mpsc::Sender<TxData>,
+        data_rx: mpsc::Receiver<RxData>,
+    ) -> Self
+    where
+        Self: Sized,
+    {
+        Self::from_rx(data_rx)
+    }
+}
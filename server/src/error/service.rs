@@ -11,8 +11,20 @@ impl<'a> ServiceError<'a> {
             Error::ServerStillRunningToSpawn => StatusCode::OK,
             Error::Timeout { op: _ } => StatusCode::REQUEST_TIMEOUT,
             Error::ProcessFailedToShutdown => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::InvalidConfig(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::InvalidOverrides(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::UnknownPreset(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::InvalidConfDir(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::InvalidScenarioDir(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Error::ProcessSpawnFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Error::TrainerCommandFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
+            Error::UnknownScenario(_) => StatusCode::NOT_FOUND,
+            Error::TeamNotConnected { side: _ } => StatusCode::OK,
+            Error::CurriculumRunning => StatusCode::OK,
+            Error::InvalidCurriculum(_) => StatusCode::OK,
+            Error::PenaltyTrainingRunning => StatusCode::OK,
+            Error::InvalidPenaltyTraining(_) => StatusCode::OK,
+            Error::KeepawayLogFailed(_) => StatusCode::INTERNAL_SERVER_ERROR,
             Error::StatusChannelClosed => StatusCode::INTERNAL_SERVER_ERROR,
             #[cfg(feature = "agones")]
             Error::AgonesSdkFailToConnect(_) => unreachable!(),
@@ -46,6 +58,21 @@ impl<'a> From<ServiceError<'a>> for Response {
                     "Failed to shutdown process due to internal error."
                 )
             },
+            Error::InvalidConfig(_) => {
+                Response::error("InvalidConfig", &value.0.to_string())
+            },
+            Error::InvalidOverrides(_) => {
+                Response::error("InvalidOverrides", &value.0.to_string())
+            },
+            Error::UnknownPreset(_) => {
+                Response::error("UnknownPreset", &value.0.to_string())
+            },
+            Error::InvalidConfDir(_) => {
+                Response::error("InvalidConfDir", &value.0.to_string())
+            },
+            Error::InvalidScenarioDir(_) => {
+                Response::error("InvalidScenarioDir", &value.0.to_string())
+            },
             Error::ProcessSpawnFailed(_) => {
                 Response::error(
                     "ProcessSpawnFailed",
@@ -58,6 +85,27 @@ impl<'a> From<ServiceError<'a>> for Response {
                     "Failed to send command to trainer process due to internal error."
                 )
             },
+            Error::UnknownScenario(_) => {
+                Response::error("UnknownScenario", &value.0.to_string())
+            },
+            Error::TeamNotConnected { side: _ } => {
+                Response::error("TeamNotConnected", &value.0.to_string())
+            },
+            Error::CurriculumRunning => {
+                Response::error("CurriculumRunning", &value.0.to_string())
+            },
+            Error::InvalidCurriculum(_) => {
+                Response::error("InvalidCurriculum", &value.0.to_string())
+            },
+            Error::PenaltyTrainingRunning => {
+                Response::error("PenaltyTrainingRunning", &value.0.to_string())
+            },
+            Error::InvalidPenaltyTraining(_) => {
+                Response::error("InvalidPenaltyTraining", &value.0.to_string())
+            },
+            Error::KeepawayLogFailed(_) => {
+                Response::error("KeepawayLogFailed", &value.0.to_string())
+            },
             Error::StatusChannelClosed => {
                 Response::error(
                     "StatusChannelClosed",
@@ -0,0 +1,37 @@
+use super::{AppState, Response};
+use axum::Router;
+use axum::extract::State;
+use serde::Serialize;
+
+use common::types::Side;
+use crate::proxy::filter::BlockedCount;
+
+#[derive(Serialize, Debug)]
+struct PlayerBlocklist {
+    side: Side,
+    unum: u8,
+    dash: bool,
+    catch: bool,
+    /// Commands dropped so far.
+    dropped: BlockedCount,
+}
+
+/// Every blocked player and how many of their commands the proxy dropped.
+async fn get(State(state): State<AppState>) -> Response {
+    let mut players: Vec<_> = state.service.blocklist().iter()
+        .map(|(side, unum, actions)| PlayerBlocklist {
+            side,
+            unum,
+            dash: actions.dash,
+            catch: actions.catch,
+            dropped: state.blocked.get(side, unum),
+        })
+        .collect();
+    players.sort_by_key(|p| (p.side as i8, p.unum));
+
+    Response::success(Some(players))
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new().route(path, axum::routing::get(get))
+}
@@ -1,6 +1,6 @@
 use axum::extract::State;
 use axum::{Json, Router, routing};
-use common::command::Command;
+use common::command::{Command, CommandResult};
 use common::command::trainer::*;
 use serde::{Deserialize, Serialize};
 
@@ -23,7 +23,15 @@ pub async fn post<C: Command<Kind = TrainerCommand>>(
     State(s): State<AppState>,
     Json(req): Json<PostRequest<C>>,
 ) -> Json<Response> {
-    let result = s.service.send_trainer_command(req.0).await;
+    respond::<C>(s.service.send_trainer_command(req.0).await)
+}
+
+/// `move` naming players by team or by side.
+pub async fn post_move(State(s): State<AppState>, Json(req): Json<PostRequest<Move>>) -> Json<Response> {
+    respond::<Move>(s.service.send_move(req.0).await)
+}
+
+fn respond<C: Command<Kind = TrainerCommand>>(result: service::Result<CommandResult<C>>) -> Json<Response> {
     if let Err(e) = result {
         return Json(Error::from(e).into());
     }
@@ -42,7 +50,7 @@ pub fn route(path: &str) -> Router<AppState> {
         .route("/eye", routing::post(post::<Eye>))
         .route("/init", routing::post(post::<Init>))
         .route("/look", routing::post(post::<Look>))
-        .route("/move", routing::post(post::<Move>))
+        .route("/move", routing::post(post_move))
         .route("/recover", routing::post(post::<Recover>))
         .route("/start", routing::post(post::<Start>))
         .route("/team_names", routing::post(post::<TeamNames>));
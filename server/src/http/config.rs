@@ -0,0 +1,54 @@
+use super::{AppState, Response};
+use axum::extract::{Path, State};
+use axum::http::header;
+use axum::response::{IntoResponse, Response as AxumResponse};
+use axum::{Json, Router, routing};
+
+use std::collections::BTreeMap;
+
+use process::{CsvSaverConfig, PlayerConfig, Presets, ProcessConfig, ServerConfig};
+
+/// The rcssserver configuration the process is spawned with.
+async fn get(State(state): State<AppState>) -> Response {
+    Response::success(Some(state.service.config()))
+}
+
+/// One part of the configuration as rcssserver's own `server.conf`,
+/// `player.conf` or `CSVSaver.conf`.
+async fn get_conf(State(state): State<AppState>, Path(file): Path<String>) -> AxumResponse {
+    let config = state.service.config();
+    let conf = match file.trim_end_matches(".conf") {
+        ServerConfig::NAMESPACE => config.server.to_conf(),
+        PlayerConfig::NAMESPACE => config.player.to_conf(),
+        CsvSaverConfig::NAMESPACE => config.csv_saver.to_conf(),
+        _ => return Response::error("Unknown Config File", &file).into_response(),
+    };
+    ([(header::CONTENT_TYPE, "text/plain")], conf).into_response()
+}
+
+/// Every rule the config in the body breaks, empty when it is valid.
+async fn validate(Json(config): Json<ProcessConfig>) -> Response {
+    Response::success(Some(config.validate().err().unwrap_or_default()))
+}
+
+/// Fields the config in the body sets differently from the process's.
+async fn diff(State(state): State<AppState>, Json(config): Json<ProcessConfig>) -> Response {
+    Response::success(Some(state.service.config().diff(&config)))
+}
+
+/// The built-in presets by name, as merged over the spawned configuration.
+async fn presets() -> Response {
+    let presets = Presets::default();
+    let presets: BTreeMap<_, _> = presets.iter().collect();
+    Response::success(Some(presets))
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    let inner = Router::new()
+        .route("/", routing::get(get))
+        .route("/validate", routing::post(validate))
+        .route("/diff", routing::post(diff))
+        .route("/presets", routing::get(presets))
+        .route("/{file}", routing::get(get_conf));
+    Router::new().nest(path, inner)
+}
@@ -1,11 +1,13 @@
 #[cfg(feature = "standalone")]
 mod restart;
+mod reset;
 
 use super::{AppState, Response};
 use axum::Router;
 
 pub fn route(path: &str) -> Router<AppState> {
-    let inner = Router::new();
+    let inner = Router::new()
+        .merge(reset::route("/reset"));
 
     #[cfg(feature = "standalone")]
     let inner = inner.merge(restart::route("/restart"));
@@ -0,0 +1,61 @@
+use super::{AppState, Response};
+use axum::extract::State;
+use axum::{Json, Router, routing};
+use serde::Deserialize;
+
+use common::types::{PlayMode, Side, Vec2, play_mode_name};
+use service::{InitialState, PlayerInit};
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PlayerRequest {
+    pub side: Side,
+    pub unum: u8,
+    #[serde(default)]
+    pub pos: Option<Vec2>,
+    #[serde(default)]
+    pub stamina: Option<u16>,
+}
+
+/// Replaces the configured initial state for this reset only.
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct InitStateRequest {
+    #[serde(default)]
+    pub ball: Option<Vec2>,
+    #[serde(default)]
+    pub players: Vec<PlayerRequest>,
+}
+
+impl From<InitStateRequest> for InitialState {
+    fn from(req: InitStateRequest) -> Self {
+        let players = req.players.into_iter()
+            .map(|p| PlayerInit { side: p.side, unum: p.unum, pos: p.pos, stamina: p.stamina })
+            .collect();
+        Self { ball: req.ball, players }
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct PostRequest {
+    #[serde(default = "default_play_mode", with = "play_mode_name")]
+    pub play_mode: PlayMode,
+    #[serde(default)]
+    pub init_state: Option<InitStateRequest>,
+}
+
+fn default_play_mode() -> PlayMode {
+    PlayMode::PM_PlayOn
+}
+
+async fn post(State(state): State<AppState>, Json(req): Json<PostRequest>) -> Response {
+    match state.service.reset_episode(req.play_mode, req.init_state.map(Into::into)).await {
+        Ok(episode) => Response::success(Some(episode.id)),
+        Err(e) => Response::error("Reset Failed", &e.to_string()),
+    }
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new().route(path, routing::post(post))
+}
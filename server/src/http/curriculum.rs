@@ -0,0 +1,134 @@
+use super::{AppState, Response};
+use axum::extract::State;
+use axum::{Json, Router, routing};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use common::types::{Side, Vec2};
+use service::{Curriculum, CurriculumProgress, Stage, Success};
+
+#[derive(Deserialize, Debug)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+enum SuccessRequest {
+    Goal { side: Side },
+    BallInRegion { min: Vec2, max: Vec2 },
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StageRequest {
+    scenario: String,
+    success: Vec<SuccessRequest>,
+    within: u16,
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PostRequest {
+    stages: Vec<StageRequest>,
+    #[serde(default = "default_window")]
+    window: usize,
+    #[serde(default = "default_promote")]
+    promote: f64,
+    /// `null` never demotes.
+    #[serde(default = "default_demote")]
+    demote: Option<f64>,
+    #[serde(default)]
+    repeat: bool,
+}
+
+fn default_window() -> usize {
+    Curriculum::default().window
+}
+
+fn default_promote() -> f64 {
+    Curriculum::default().promote
+}
+
+fn default_demote() -> Option<f64> {
+    Curriculum::default().demote
+}
+
+impl From<PostRequest> for Curriculum {
+    fn from(req: PostRequest) -> Self {
+        let stages = req.stages.into_iter()
+            .map(|stage| Stage {
+                scenario: stage.scenario,
+                success: stage.success.into_iter()
+                    .map(|success| match success {
+                        SuccessRequest::Goal { side } => Success::Goal { side },
+                        SuccessRequest::BallInRegion { min, max } => Success::BallInRegion { min, max },
+                    })
+                    .collect(),
+                within: stage.within,
+            })
+            .collect();
+
+        Curriculum {
+            stages,
+            window: req.window,
+            promote: req.promote,
+            demote: req.demote,
+            repeat: req.repeat,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub(super) struct CurriculumStatus {
+    stage: usize,
+    scenario: String,
+    attempts: u64,
+    /// Outcomes of the latest attempts at this stage, oldest first.
+    recent: Vec<bool>,
+    success_rate: Option<f64>,
+    promotions: u32,
+    demotions: u32,
+    completed: bool,
+    running: bool,
+}
+
+impl From<CurriculumProgress> for CurriculumStatus {
+    fn from(progress: CurriculumProgress) -> Self {
+        Self {
+            success_rate: progress.success_rate(),
+            stage: progress.stage,
+            scenario: progress.scenario,
+            attempts: progress.attempts,
+            recent: progress.recent.into(),
+            promotions: progress.promotions,
+            demotions: progress.demotions,
+            completed: progress.completed,
+            running: progress.running,
+        }
+    }
+}
+
+async fn get(State(state): State<AppState>) -> Response {
+    Response::success(state.service.curriculum().map(CurriculumStatus::from))
+}
+
+/// Starts the curriculum in the background, progress shows on GET and `/status`.
+async fn post(State(state): State<AppState>, Json(req): Json<PostRequest>) -> Response {
+    let curriculum = Curriculum::from(req);
+    if let Err(e) = state.service.check_curriculum(&curriculum) {
+        return Response::error("Curriculum Rejected", &e.to_string());
+    }
+
+    let service = state.service.clone();
+    tokio::spawn(async move {
+        if let Err(e) = service.run_curriculum(curriculum).await {
+            warn!("[Curriculum] Stopped: {e}");
+        }
+    });
+    Response::success::<()>(None)
+}
+
+async fn delete(State(state): State<AppState>) -> Response {
+    Response::success(Some(state.service.stop_curriculum()))
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new().route(path, routing::get(get).post(post).delete(delete))
+}
@@ -0,0 +1,88 @@
+use super::{AppState, Response};
+use axum::extract::State;
+use axum::{Router, routing};
+use serde::Serialize;
+
+use service::{KeepawayEpisode, KeepawayLog, KeepawayStats};
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct EpisodeResponse {
+    id: u32,
+    start: u16,
+    end: u16,
+    duration: u16,
+    /// `o` out of bounds, `t` taken away, as in the `.kwy` log.
+    end_reason: &'static str,
+}
+
+impl From<&KeepawayEpisode> for EpisodeResponse {
+    fn from(episode: &KeepawayEpisode) -> Self {
+        Self {
+            id: episode.id,
+            start: episode.start,
+            end: episode.end,
+            duration: episode.duration(),
+            end_reason: episode.end_reason.encode(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StatsResponse {
+    enabled: bool,
+    episodes: Vec<EpisodeResponse>,
+    current_start: Option<u16>,
+    mean_duration: Option<f64>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct LogResponse {
+    keepers: Option<u8>,
+    takers: Option<u8>,
+    region_length: Option<f64>,
+    region_width: Option<f64>,
+    episodes: Vec<EpisodeResponse>,
+    mean_duration: Option<f64>,
+}
+
+impl From<KeepawayLog> for LogResponse {
+    fn from(log: KeepawayLog) -> Self {
+        Self {
+            keepers: log.keepers,
+            takers: log.takers,
+            region_length: log.region.map(|(length, _)| length),
+            region_width: log.region.map(|(_, width)| width),
+            mean_duration: log.mean_duration(),
+            episodes: log.episodes.iter().map(EpisodeResponse::from).collect(),
+        }
+    }
+}
+
+/// Episodes tracked from the referee since the process spawned.
+async fn get(State(state): State<AppState>) -> Response {
+    let stats = state.service.keepaway_stats().await.unwrap_or_default();
+    let KeepawayStats { episodes, current_start } = &stats;
+    Response::success(Some(StatsResponse {
+        enabled: state.service.keepaway_enabled(),
+        episodes: episodes.iter().map(EpisodeResponse::from).collect(),
+        current_start: *current_start,
+        mean_duration: stats.mean_duration(),
+    }))
+}
+
+/// The latest `.kwy` log rcssserver wrote.
+async fn log(State(state): State<AppState>) -> Response {
+    match state.service.keepaway_log() {
+        Ok(log) => Response::success(log.map(LogResponse::from)),
+        Err(e) => Response::error("Keepaway Log Failed", &e.to_string()),
+    }
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new()
+        .route(path, routing::get(get))
+        .route(&format!("{path}/log"), routing::get(log))
+}
@@ -1,7 +1,14 @@
+mod blocklist;
 mod command;
+mod config;
 mod control;
+mod curriculum;
 mod gateway;
 mod health;
+mod keepaway;
+mod penalty;
+mod scenario;
+mod status;
 
 use crate::AppState;
 use crate::error::Error;
@@ -18,8 +25,15 @@ async fn fallback_404(State(_state): State<AppState>) -> AxumResponse {
 pub fn route(path: &str, app_state: AppState) -> Router {
     let inner = Router::new()
         .merge(command::route("/"))
+        .merge(config::route("/config"))
         .merge(control::route("/control"))
+        .merge(curriculum::route("/curriculum"))
         .merge(gateway::route("/gateway"))
+        .merge(blocklist::route("/blocklist"))
+        .merge(keepaway::route("/keepaway"))
+        .merge(penalty::route("/penalty"))
+        .merge(scenario::route("/scenarios"))
+        .merge(status::route("/status"))
         .fallback(fallback_404)
         .with_state(app_state);
 
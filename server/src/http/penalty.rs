@@ -0,0 +1,172 @@
+use super::{AppState, Response};
+use axum::extract::State;
+use axum::{Json, Router, routing};
+use log::warn;
+use serde::{Deserialize, Serialize};
+
+use common::types::Side;
+use service::{PenaltyAttempt, PenaltyOutcome, PenaltyProgress, PenaltyTraining};
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PostRequest {
+    #[serde(default = "default_kickers")]
+    kickers_l: Vec<u8>,
+    #[serde(default = "default_kickers")]
+    kickers_r: Vec<u8>,
+    #[serde(default = "default_goalie")]
+    goalie_l: u8,
+    #[serde(default = "default_goalie")]
+    goalie_r: u8,
+    #[serde(default = "default_alternate")]
+    alternate: bool,
+    /// `null` runs until stopped.
+    #[serde(default)]
+    attempts: Option<u32>,
+    #[serde(default = "default_within")]
+    within: u16,
+}
+
+fn default_kickers() -> Vec<u8> {
+    PenaltyTraining::default().kickers_l
+}
+
+fn default_goalie() -> u8 {
+    PenaltyTraining::default().goalie_l
+}
+
+fn default_alternate() -> bool {
+    PenaltyTraining::default().alternate
+}
+
+fn default_within() -> u16 {
+    PenaltyTraining::default().within
+}
+
+impl From<PostRequest> for PenaltyTraining {
+    fn from(req: PostRequest) -> Self {
+        PenaltyTraining {
+            kickers_l: req.kickers_l,
+            kickers_r: req.kickers_r,
+            goalie_l: req.goalie_l,
+            goalie_r: req.goalie_r,
+            alternate: req.alternate,
+            attempts: req.attempts,
+            within: req.within,
+        }
+    }
+}
+
+#[derive(Serialize, Clone, Copy, Debug)]
+#[serde(rename_all = "camelCase")]
+enum Outcome {
+    Goal,
+    Save,
+    Miss,
+    Timeout,
+}
+
+impl From<PenaltyOutcome> for Outcome {
+    fn from(outcome: PenaltyOutcome) -> Self {
+        match outcome {
+            PenaltyOutcome::Goal => Outcome::Goal,
+            PenaltyOutcome::Save => Outcome::Save,
+            PenaltyOutcome::Miss => Outcome::Miss,
+            PenaltyOutcome::Timeout => Outcome::Timeout,
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct AttemptResponse {
+    id: u32,
+    side: Side,
+    kicker: u8,
+    start: u16,
+    end: u16,
+    outcome: Outcome,
+}
+
+impl From<&PenaltyAttempt> for AttemptResponse {
+    fn from(attempt: &PenaltyAttempt) -> Self {
+        Self {
+            id: attempt.id,
+            side: attempt.side,
+            kicker: attempt.kicker,
+            start: attempt.start,
+            end: attempt.end,
+            outcome: attempt.outcome.into(),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct Tally {
+    goals: usize,
+    saves: usize,
+    misses: usize,
+    timeouts: usize,
+}
+
+impl Tally {
+    fn of(progress: &PenaltyProgress, side: Side) -> Self {
+        Self {
+            goals: progress.count(side, PenaltyOutcome::Goal),
+            saves: progress.count(side, PenaltyOutcome::Save),
+            misses: progress.count(side, PenaltyOutcome::Miss),
+            timeouts: progress.count(side, PenaltyOutcome::Timeout),
+        }
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct PenaltyStatus {
+    attempts: Vec<AttemptResponse>,
+    left: Tally,
+    right: Tally,
+    completed: bool,
+    running: bool,
+}
+
+impl From<PenaltyProgress> for PenaltyStatus {
+    fn from(progress: PenaltyProgress) -> Self {
+        Self {
+            attempts: progress.attempts.iter().map(AttemptResponse::from).collect(),
+            left: Tally::of(&progress, Side::LEFT),
+            right: Tally::of(&progress, Side::RIGHT),
+            completed: progress.completed,
+            running: progress.running,
+        }
+    }
+}
+
+async fn get(State(state): State<AppState>) -> Response {
+    Response::success(state.service.penalty_training().map(PenaltyStatus::from))
+}
+
+/// Starts the training in the background, attempts show on GET as they are judged.
+async fn post(State(state): State<AppState>, Json(req): Json<PostRequest>) -> Response {
+    let training = PenaltyTraining::from(req);
+    if let Err(e) = state.service.check_penalty_training(&training) {
+        return Response::error("Penalty Training Rejected", &e.to_string());
+    }
+
+    let service = state.service.clone();
+    tokio::spawn(async move {
+        if let Err(e) = service.run_penalty_training(training).await {
+            warn!("[PenaltyTraining] Stopped: {e}");
+        }
+    });
+    Response::success::<()>(None)
+}
+
+async fn delete(State(state): State<AppState>) -> Response {
+    Response::success(Some(state.service.stop_penalty_training()))
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new().route(path, routing::get(get).post(post).delete(delete))
+}
@@ -0,0 +1,96 @@
+use super::{AppState, Response};
+use axum::extract::{Path, State};
+use axum::http::{HeaderMap, header};
+use axum::{Router, routing};
+use serde::Serialize;
+
+use common::scenario::{Scenario, ScenarioResult};
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct ScenarioSummary {
+    name: String,
+    description: Option<String>,
+    play_mode: &'static str,
+}
+
+/// A scenario from a JSON body, or TOML when the content type says so.
+fn parse_body(headers: &HeaderMap, body: &str) -> ScenarioResult<Scenario> {
+    let is_toml = headers.get(header::CONTENT_TYPE)
+        .and_then(|v| v.to_str().ok())
+        .is_some_and(|v| v.contains("toml"));
+    if is_toml {
+        Scenario::from_toml(body)
+    } else {
+        Scenario::from_json(body)
+    }
+}
+
+async fn list(State(state): State<AppState>) -> Response {
+    let scenarios: Vec<_> = state.service.scenarios().iter()
+        .map(|(name, scenario)| ScenarioSummary {
+            name: name.to_string(),
+            description: scenario.description.clone(),
+            play_mode: scenario.play_mode.encode(),
+        })
+        .collect();
+    Response::success(Some(scenarios))
+}
+
+async fn get(State(state): State<AppState>, Path(name): Path<String>) -> Response {
+    match state.service.scenario(&name) {
+        Some(scenario) => Response::success(Some(scenario)),
+        None => Response::error("Unknown Scenario", &name),
+    }
+}
+
+async fn put(
+    State(state): State<AppState>,
+    Path(name): Path<String>,
+    headers: HeaderMap,
+    body: String,
+) -> Response {
+    match parse_body(&headers, &body) {
+        Ok(scenario) => {
+            let replaced = state.service.set_scenario(name, scenario).is_some();
+            Response::success(Some(replaced))
+        }
+        Err(e) => Response::error("Invalid Scenario", &e.to_string()),
+    }
+}
+
+async fn delete(State(state): State<AppState>, Path(name): Path<String>) -> Response {
+    match state.service.remove_scenario(&name) {
+        Some(_) => Response::success::<()>(None),
+        None => Response::error("Unknown Scenario", &name),
+    }
+}
+
+/// Sets up a registered scenario, returns the id of the episode it starts.
+async fn apply_named(State(state): State<AppState>, Path(name): Path<String>) -> Response {
+    match state.service.apply_named_scenario(&name).await {
+        Ok(episode) => Response::success(Some(episode.id)),
+        Err(e) => Response::error("Scenario Failed", &e.to_string()),
+    }
+}
+
+/// Sets up the scenario in the body without registering it.
+async fn apply(State(state): State<AppState>, headers: HeaderMap, body: String) -> Response {
+    let scenario = match parse_body(&headers, &body) {
+        Ok(scenario) => scenario,
+        Err(e) => return Response::error("Invalid Scenario", &e.to_string()),
+    };
+    match state.service.apply_scenario(&scenario).await {
+        Ok(episode) => Response::success(Some(episode.id)),
+        Err(e) => Response::error("Scenario Failed", &e.to_string()),
+    }
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    let inner = Router::new()
+        .route("/", routing::get(list))
+        .route("/apply", routing::post(apply))
+        .route("/{name}", routing::get(get).put(put).delete(delete))
+        .route("/{name}/apply", routing::post(apply_named));
+    Router::new().nest(path, inner)
+}
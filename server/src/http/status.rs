@@ -0,0 +1,60 @@
+use super::{AppState, Response};
+use axum::Router;
+use axum::extract::State;
+use serde::Serialize;
+
+use common::types::{PlayMode, play_mode_name};
+use service::{Phase, ServerStatus, StopReason};
+
+use super::curriculum::CurriculumStatus;
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct EpisodeStatus {
+    id: u64,
+    start_time: u16,
+    /// Cycles since the episode started.
+    time: Option<u16>,
+    score: service::Score,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+struct StatusResponse {
+    status: ServerStatus,
+    time: Option<u16>,
+    #[serde(with = "play_mode_name::option")]
+    play_mode: Option<PlayMode>,
+    score: Option<service::Score>,
+    phase: Phase,
+    stop_reason: Option<StopReason>,
+    episode: EpisodeStatus,
+    curriculum: Option<CurriculumStatus>,
+}
+
+async fn get(State(state): State<AppState>) -> Response {
+    let service = &state.service;
+    let time = service.time_now().await;
+    let score = service.score_now().await;
+    let episode = service.episode();
+
+    Response::success(Some(StatusResponse {
+        status: service.status_now(),
+        time,
+        play_mode: service.play_mode_now().await,
+        score,
+        phase: service.phase(),
+        stop_reason: service.stop_reason(),
+        episode: EpisodeStatus {
+            id: episode.id,
+            start_time: episode.start_time,
+            time: time.map(|t| episode.time(t)),
+            score: episode.score(score.unwrap_or_default()),
+        },
+        curriculum: service.curriculum().map(CurriculumStatus::from),
+    }))
+}
+
+pub fn route(path: &str) -> Router<AppState> {
+    Router::new().route(path, axum::routing::get(get))
+}
@@ -0,0 +1,169 @@
+use std::borrow::Cow;
+use std::sync::{Arc, OnceLock};
+
+use dashmap::DashMap;
+use log::debug;
+use serde::Serialize;
+use tokio::sync::watch;
+
+use common::types::Side;
+use service::Blocklist;
+
+/// Blocked commands dropped so far for one player.
+#[derive(Serialize, Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct BlockedCount {
+    pub dash: u64,
+    pub catch: u64,
+}
+
+#[derive(Debug, Default)]
+pub struct BlockedStats {
+    players: DashMap<(Side, u8), BlockedCount>,
+}
+
+impl BlockedStats {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    fn record(&self, side: Side, unum: u8, command: &str) {
+        let mut count = self.players.entry((side, unum)).or_default();
+        match command {
+            "dash" => count.dash += 1,
+            "catch" => count.catch += 1,
+            _ => {}
+        }
+    }
+
+    pub fn get(&self, side: Side, unum: u8) -> BlockedCount {
+        self.players.get(&(side, unum)).map(|c| *c).unwrap_or_default()
+    }
+}
+
+/// Drops the commands of one player connection the blocklist forbids.
+///
+/// rcssserver tells a player its side and unum in the `(init SIDE UNUM PLAYMODE)`
+/// reply, everything sent before it passes unfiltered.
+#[derive(Clone, Debug)]
+pub struct CommandFilter {
+    player: Arc<OnceLock<(Side, u8)>>,
+    blocklist: watch::Receiver<Blocklist>,
+    stats: Arc<BlockedStats>,
+}
+
+impl CommandFilter {
+    pub fn new(blocklist: watch::Receiver<Blocklist>, stats: Arc<BlockedStats>) -> Self {
+        Self {
+            player: Arc::new(OnceLock::new()),
+            blocklist,
+            stats,
+        }
+    }
+
+    /// Picks the player's side and unum from a message of the server.
+    pub fn observe(&self, msg: &str) {
+        if self.player.get().is_some() {
+            return;
+        }
+        let Some(rest) = msg.strip_prefix("(init ") else {
+            return;
+        };
+
+        let mut args = rest.split_whitespace();
+        let side = args.next().and_then(Side::decode);
+        let unum = args.next().and_then(|unum| unum.parse().ok());
+        if let (Some(side), Some(unum)) = (side, unum) {
+            debug!("[CommandFilter] Connection identified as {side:?} {unum}");
+            let _ = self.player.set((side, unum));
+        }
+    }
+
+    /// The message without its blocked commands, `None` if none is left.
+    pub fn filter<'a>(&self, msg: &'a str) -> Option<Cow<'a, str>> {
+        let Some(&(side, unum)) = self.player.get() else {
+            return Some(Cow::Borrowed(msg));
+        };
+        let actions = self.blocklist.borrow().get(side, unum);
+        if actions.is_empty() {
+            return Some(Cow::Borrowed(msg));
+        }
+
+        let commands = split_commands(msg);
+        let (blocked, allowed): (Vec<_>, Vec<_>) = commands
+            .into_iter()
+            .partition(|command| actions.blocks(command_name(command)));
+
+        if blocked.is_empty() {
+            return Some(Cow::Borrowed(msg));
+        }
+        for command in blocked {
+            debug!("[CommandFilter] Dropped {command} of {side:?} {unum}");
+            self.stats.record(side, unum, command_name(command));
+        }
+
+        (!allowed.is_empty()).then(|| Cow::Owned(allowed.concat()))
+    }
+}
+
+/// The top level `(...)` commands of a message, a player may send several at once.
+fn split_commands(msg: &str) -> Vec<&str> {
+    let mut ret = vec![];
+    let mut depth = 0usize;
+    let mut start = 0;
+    let mut quoted = false;
+
+    for (idx, c) in msg.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => {
+                if depth == 0 {
+                    start = idx;
+                }
+                depth += 1;
+            }
+            ')' if !quoted && depth > 0 => {
+                depth -= 1;
+                if depth == 0 {
+                    ret.push(&msg[start..=idx]);
+                }
+            }
+            _ => {}
+        }
+    }
+    ret
+}
+
+fn command_name(command: &str) -> &str {
+    command
+        .trim_start_matches('(')
+        .split(|c: char| c.is_whitespace() || c == '(' || c == ')')
+        .next()
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use service::ActionList;
+
+    #[test]
+    fn test_filter_blocked_commands() {
+        let mut blocklist = Blocklist::default();
+        blocklist.insert(Side::LEFT, 5, ActionList { dash: true, catch: false });
+        let (_tx, rx) = watch::channel(blocklist);
+        let stats = Arc::new(BlockedStats::new());
+        let filter = CommandFilter::new(rx, stats.clone());
+
+        // unidentified connections pass through
+        assert_eq!(filter.filter("(dash 100)").as_deref(), Some("(dash 100)"));
+
+        filter.observe("(init l 5 before_kick_off)");
+        assert_eq!(filter.filter("(dash 100)"), None);
+        assert_eq!(
+            filter.filter("(dash 100 30)(turn_neck 10)(say \"(dash)\")").as_deref(),
+            Some("(turn_neck 10)(say \"(dash)\")")
+        );
+        assert_eq!(filter.filter("(catch 45)").as_deref(), Some("(catch 45)"));
+        assert_eq!(stats.get(Side::LEFT, 5), BlockedCount { dash: 2, catch: 0 });
+    }
+}
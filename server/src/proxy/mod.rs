@@ -1,3 +1,4 @@
 pub mod manager;
 pub mod ws;
 pub mod udp;
+pub mod filter;
@@ -10,6 +10,7 @@ use tokio::task::JoinHandle;
 use uuid::Uuid;
 
 use common::client::{Client, Error as ClientError};
+use crate::proxy::filter::CommandFilter;
 use crate::state::{AppState, AppStateStatus};
 use crate::PEER_IP;
 
@@ -23,6 +24,7 @@ pub const DEFAULT_SERVER_UDP_PORT: u16 = 6000;
 struct SessionInfo {
     uuid: Uuid,
     client: Arc<Client>,
+    filter: CommandFilter,
     last_active: Instant,
     forward_task: JoinHandle<()>,
 }
@@ -118,7 +120,7 @@ impl UdpProxy {
 
                     if !self.sessions.contains_key(&addr) {
                         let uuid = Uuid::now_v7();
-                        let server_port = self.state.service.config().server.port.unwrap_or(DEFAULT_SERVER_UDP_PORT);
+                        let server_port = self.state.service.player_port().await.unwrap_or(DEFAULT_SERVER_UDP_PORT);
                         let server_addr = SocketAddr::new(PEER_IP, server_port);
 
                         let name = Some(format!("udp-{}", addr));
@@ -137,9 +139,12 @@ impl UdpProxy {
                         let (tx, mut rx) = mpsc::channel(32);
                         let _sub_id = client.subscribe(tx);
 
+                        let filter = self.state.command_filter();
+                        let filter_clone = filter.clone();
                         let socket_clone = self.socket.clone();
                         let forward_task = tokio::spawn(async move {
                             while let Some(msg) = rx.recv().await {
+                                filter_clone.observe(&msg);
                                 let bytes = msg.as_bytes();
                                 if let Err(_e) = socket_clone.send_to(bytes, addr).await {
                                      info!("[UDP Proxy] Failed to send data downstream to {}: {}, ignoring", addr, _e);
@@ -150,6 +155,7 @@ impl UdpProxy {
                         self.sessions.insert(addr, SessionInfo {
                             uuid,
                             client: client.clone(),
+                            filter,
                             last_active: Instant::now(),
                             forward_task,
                         });
@@ -158,7 +164,10 @@ impl UdpProxy {
 
                     if let Some(mut session) = self.sessions.get_mut(&addr) {
                         session.last_active = Instant::now();
-                        if let Err(e) = session.client.send_data(data_str.into()).await {
+                        let Some(data) = session.filter.filter(data_str) else {
+                            continue;
+                        };
+                        if let Err(e) = session.client.send_data(data.as_ref().into()).await {
                             error!("[UDP Proxy] Failed to send data upstream for {}: {}", addr, e);
                         }
                     }
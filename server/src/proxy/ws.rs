@@ -54,9 +54,8 @@ async fn handle_upgrade(
     let server_addr = SocketAddr::new(
         PEER_IP,
         state.service
-            .config()
-            .server
-            .port
+            .player_port()
+            .await
             .unwrap_or(DEFAULT_SERVER_UDP_PORT),
     );
 
@@ -86,6 +85,7 @@ async fn handle_upgrade(
     }
 
     let (socket_tx, mut socket_rx, mut socket_task) = ws_into_mpsc_tx::<32>(socket);
+    let filter = state.command_filter();
 
     let mut state_status = state.status_rx.clone();
     loop {
@@ -122,7 +122,8 @@ async fn handle_upgrade(
                     Message::Text(text) => {
                         let text = text.trim();
                         if text.is_empty() { continue; }
-                        if let Err(e) = player_client.send_data(text.into()).await {
+                        let Some(text) = filter.filter(text) else { continue; };
+                        if let Err(e) = player_client.send_data(text.as_ref().into()).await {
                              error!("[WS Proxy] Client[{client_id}] Failed send msg to udp client: {}", e);
                         }
                     },
@@ -142,6 +143,7 @@ async fn handle_upgrade(
                 }
             },
             Some(msg) = client_rx.recv() => {
+                filter.observe(&msg);
                 let message = match ArcStr::as_static(&msg) {
                     Some(text) => Message::Text(text.into()),
                     None => Message::Binary(msg.to_string().into()),
@@ -5,6 +5,7 @@ use tokio::sync::{oneshot, watch};
 use chrono::{Utc, Duration};
 
 use service::Service;
+use crate::proxy::filter::{BlockedStats, CommandFilter};
 use crate::proxy::manager::SessionManager;
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -33,6 +34,7 @@ impl AppStateStatus {
 pub struct AppState {
     pub(crate) service: Arc<Service>,
     pub(crate) session: Arc<SessionManager>,
+    pub(crate) blocked: Arc<BlockedStats>,
 
     pub status_rx: watch::Receiver<AppStateStatus>,
 }
@@ -54,9 +56,15 @@ impl AppState {
         Self {
             service,
             session: Arc::new(SessionManager::new()),
+            blocked: Arc::new(BlockedStats::new()),
             status_rx,
         }
     }
+
+    /// A blocklist filter for a new player connection.
+    pub fn command_filter(&self) -> CommandFilter {
+        CommandFilter::new(self.service.blocklist_watch(), self.blocked.clone())
+    }
     
     async fn run_wait_for_shutdown_cleaner(
         mut service: Arc<Service>,
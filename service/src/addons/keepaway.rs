@@ -0,0 +1,115 @@
+use log::debug;
+use tokio::sync::{broadcast, watch};
+use tokio::task::JoinHandle;
+
+use common::types::PlayMode;
+use process::addon::{Addon, TrainerEvent};
+
+use crate::base::{KeepawayEnd, KeepawayStats};
+
+/// Follows rcssserver's keepaway referee: an episode starts on `play_on` and
+/// ends on `out_of_bounds` or `take_ball`, the field is reset right away and
+/// the next one starts.
+#[derive(Debug)]
+pub struct KeepawayAddon {
+    stats: watch::Receiver<KeepawayStats>,
+    task: JoinHandle<()>,
+}
+
+impl KeepawayAddon {
+    pub fn new(mut events: broadcast::Receiver<TrainerEvent>) -> Self {
+        let (stats_tx, stats_rx) = watch::channel(KeepawayStats::default());
+        let task = tokio::spawn(async move {
+            loop {
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        debug!("[KeepawayAddon] Lagged behind by {n} events.");
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        debug!("[KeepawayAddon] Event channel closed, stopping.");
+                        break;
+                    }
+                };
+
+                match event {
+                    TrainerEvent::PlayMode { time, play_mode: PlayMode::PM_PlayOn } => {
+                        stats_tx.send_if_modified(|stats| {
+                            let idle = stats.current_start.is_none();
+                            stats.start(time);
+                            idle
+                        });
+                    }
+                    TrainerEvent::Referee { time, message } => {
+                        let Some(end_reason) = KeepawayEnd::from_referee(&message) else {
+                            continue;
+                        };
+                        stats_tx.send_modify(|stats| {
+                            if let Some(episode) = stats.end(time, end_reason) {
+                                debug!("[KeepawayAddon] Episode ended: {episode:?}");
+                            }
+                        });
+                    }
+                    _ => {}
+                }
+
+                if stats_tx.is_closed() {
+                    debug!("[KeepawayAddon] Nobody is watching, stopping.");
+                    break;
+                }
+            }
+        });
+
+        Self {
+            stats: stats_rx,
+            task,
+        }
+    }
+
+    pub fn watcher(&self) -> watch::Receiver<KeepawayStats> {
+        self.stats.clone()
+    }
+
+    pub fn stats(&self) -> KeepawayStats {
+        self.stats.borrow().clone()
+    }
+}
+
+impl Addon for KeepawayAddon {
+    fn close(&self) {
+        self.task.abort()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[tokio::test]
+    async fn test_tracking_keepaway_episodes() {
+        let (events, _) = broadcast::channel(16);
+        let addon = KeepawayAddon::new(events.subscribe());
+        let mut stats = addon.watcher();
+
+        events
+            .send(TrainerEvent::PlayMode { time: 3, play_mode: PlayMode::PM_PlayOn })
+            .unwrap();
+        stats.changed().await.unwrap();
+        assert_eq!(addon.stats().current_start, Some(3));
+
+        for (time, message) in [(40, "take_ball"), (45, "time_extended"), (72, "out_of_bounds")] {
+            events
+                .send(TrainerEvent::Referee { time, message: message.to_string() })
+                .unwrap();
+        }
+        stats.wait_for(|s| s.episodes.len() == 2).await.unwrap();
+
+        let stats = addon.stats();
+        assert_eq!(stats.episodes[0].end_reason, KeepawayEnd::Taken);
+        assert_eq!((stats.episodes[1].start, stats.episodes[1].end), (40, 72));
+        assert_eq!(stats.mean_duration(), Some(34.5));
+
+        addon.close();
+    }
+}
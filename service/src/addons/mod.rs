@@ -1,4 +1,7 @@
 mod time;
 mod playmode;
+mod keepaway;
 
+pub use playmode::PlayModeStatusAddon;
 pub use time::TimeStatusAddon;
+pub use keepaway::KeepawayAddon;
@@ -1,120 +1,112 @@
-use std::time::Duration;
-
 use log::debug;
-use tokio::sync::{mpsc, watch};
+use tokio::sync::{broadcast, watch};
 use tokio::task::JoinHandle;
 
-use common::client::{RxData, TxData, TxSignal};
-use common::command;
-use common::command::trainer::TrainerCommand;
-use process::addon::{Addon, CallerAddon};
-use process::resolver::CallSender;
+use common::types::PlayMode;
+use process::addon::{Addon, Score, TrainerEvent};
 
+/// Follows the referee: the current play mode and the score per side.
 #[derive(Debug)]
-pub struct PlayModeStatusAddon<const POLL_INT_MS: u64 = 2000> {
-    timestep: watch::Receiver<Option<u16>>,
+pub struct PlayModeStatusAddon {
+    play_mode: watch::Receiver<Option<PlayMode>>,
+    score: watch::Receiver<Score>,
     task: JoinHandle<()>,
 }
 
-impl<const POLL_INT_MS: u64> PlayModeStatusAddon<POLL_INT_MS> {
-    fn start(caller: CallSender<TrainerCommand, TxData, RxData>) -> Self {
-        let (time_tx, time_rx) = watch::channel(None);
+impl PlayModeStatusAddon {
+    pub fn new(initial: Option<PlayMode>, mut events: broadcast::Receiver<TrainerEvent>) -> Self {
+        let (play_mode_tx, play_mode_rx) = watch::channel(initial);
+        let (score_tx, score_rx) = watch::channel(Score::default());
         let task = tokio::spawn(async move {
             loop {
-                if let Ok(Ok(res)) = caller.call(command::trainer::CheckBall).await {
-                    time_tx.send(Some(res.time)).expect("Channel Closed"); // TODO: Handle error
-                } else {
-                    debug!("[PlayModeStatusAddon] Failed to get time: Caller closed.");
+                let event = match events.recv().await {
+                    Ok(event) => event,
+                    Err(broadcast::error::RecvError::Lagged(n)) => {
+                        debug!("[PlayModeStatusAddon] Lagged behind by {n} events.");
+                        continue;
+                    }
+                    Err(broadcast::error::RecvError::Closed) => {
+                        debug!("[PlayModeStatusAddon] Event channel closed, stopping.");
+                        break;
+                    }
+                };
+
+                match event {
+                    TrainerEvent::PlayMode { play_mode, .. } => {
+                        play_mode_tx.send_if_modified(|pm| {
+                            let changed = *pm != Some(play_mode);
+                            *pm = Some(play_mode);
+                            changed
+                        });
+                    }
+                    TrainerEvent::Goal { score, .. } => {
+                        score_tx.send_replace(score);
+                    }
+                    _ => {}
+                }
+
+                if play_mode_tx.is_closed() && score_tx.is_closed() {
+                    debug!("[PlayModeStatusAddon] Nobody is watching, stopping.");
                     break;
                 }
-                tokio::time::sleep(Duration::from_millis(POLL_INT_MS)).await;
             }
         });
 
         Self {
-            timestep: time_rx,
+            play_mode: play_mode_rx,
+            score: score_rx,
             task,
         }
     }
 
-    fn watcher(&self) -> watch::Receiver<Option<u16>> {
-        self.timestep.clone()
+    pub fn play_mode_watcher(&self) -> watch::Receiver<Option<PlayMode>> {
+        self.play_mode.clone()
     }
 
-    fn time(&self) -> Option<u16> {
-        *self.timestep.borrow()
+    pub fn score_watcher(&self) -> watch::Receiver<Score> {
+        self.score.clone()
     }
-}
 
-impl<const POLL_INT_MS: u64> Addon for PlayModeStatusAddon<POLL_INT_MS> {
-    fn close(&self) {
-        self.task.abort()
+    pub fn play_mode(&self) -> Option<PlayMode> {
+        *self.play_mode.borrow()
     }
-}
 
-impl<const POLL_INT_MS: u64> CallerAddon<TrainerCommand> for PlayModeStatusAddon<POLL_INT_MS> {
-    type Handle = watch::Receiver<Option<u16>>;
-
-    fn handle(&self) -> Self::Handle {
-        self.watcher()
+    pub fn score(&self) -> Score {
+        *self.score.borrow()
     }
+}
 
-    fn from_caller(
-        _: mpsc::Sender<TxSignal>,
-        caller: CallSender<TrainerCommand, TxData, RxData>,
-    ) -> Self {
-        Self::start(caller)
+impl Addon for PlayModeStatusAddon {
+    fn close(&self) {
+        self.task.abort()
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use process::CoachedProcess;
+    use common::types::Side;
 
     #[tokio::test]
-    async fn test_tracking_time_status_auto_start_half_time_break_end() -> Result<(), ()> {
-        let spawner = CoachedProcess::spawner().await;
-        let server = spawner.spawn().await.expect("Spawn failed");
-
-        let rx = server.coach().add_caller_addon::<PlayModeStatusAddon>("time");
-        let caller = server.coach().caller();
-        caller
-            .call(command::trainer::Start)
-            .await
-            .expect("Start failed")
-            .expect("Failed to start");
-
-        let time_task = tokio::spawn(async move {
-            let mut rx = rx;
-            while let Ok(_) = rx.changed().await {
-                let t = *rx.borrow();
-                println!("Timestep:\t{t:?}.");
-                if let Some(t) = t {
-                    if t == 3000 {
-                        caller
-                            .call(command::trainer::Start)
-                            .await
-                            .expect("Start failed")
-                            .expect("Failed to start at half-time");
-                        continue;
-                    }
-                    if t >= 6000 {
-                        println!("Reached end of test at timestep {t}.");
-                        break;
-                    }
-                }
-            }
-        });
-
-        tokio::time::timeout(Duration::from_secs(10), time_task)
-            .await
-            .expect("Timeout waiting for timestep >= 6000")
+    async fn test_tracking_play_mode_and_score() {
+        let (events, _) = broadcast::channel(16);
+        let addon = PlayModeStatusAddon::new(Some(PlayMode::PM_BeforeKickOff), events.subscribe());
+        let mut play_mode = addon.play_mode_watcher();
+        let mut score = addon.score_watcher();
+
+        events
+            .send(TrainerEvent::PlayMode { time: 0, play_mode: PlayMode::PM_KickOff_Left })
             .unwrap();
+        play_mode.changed().await.unwrap();
+        assert_eq!(addon.play_mode(), Some(PlayMode::PM_KickOff_Left));
 
-        let mut server = server;
-        server.shutdown().await.expect("Shutdown failed");
+        let goal = Score { left: 1, right: 0 };
+        events
+            .send(TrainerEvent::Goal { time: 120, side: Side::LEFT, score: goal })
+            .unwrap();
+        score.changed().await.unwrap();
+        assert_eq!(addon.score(), goal);
 
-        Ok(())
+        addon.close();
     }
 }
@@ -1,36 +1,57 @@
-use std::time::Duration;
-
 use log::debug;
-use tokio::sync::{mpsc, watch};
+use tokio::sync::{broadcast, watch};
 use tokio::task::JoinHandle;
 
-use common::client::{RxData, TxData, TxSignal};
-use common::command;
-use common::command::trainer::TrainerCommand;
-use process::addon::{Addon, CallerAddon};
-use process::resolver::CallSender;
+use common::types::WorldSnapshot;
+use process::addon::{Addon, TrainerEvent};
 
+/// Tracks the simulation cycle from the trainer's `see_global` frames, sent
+/// every cycle after `(eye on)`, and the time stamp of referee messages.
 #[derive(Debug)]
-pub struct TimeStatusAddon<const POLL_INT_MS: u64 = 2000> {
+pub struct TimeStatusAddon {
     timestep: watch::Receiver<Option<u16>>,
     task: JoinHandle<()>,
 }
 
-impl<const POLL_INT_MS: u64> TimeStatusAddon<POLL_INT_MS> {
-    fn start(caller: CallSender<TrainerCommand, TxData, RxData>) -> Self {
-        let (time_tx, time_rx) = watch::channel(None);
+impl TimeStatusAddon {
+    pub fn new(
+        initial: Option<u16>,
+        mut see_global: watch::Receiver<Option<WorldSnapshot>>,
+        mut events: broadcast::Receiver<TrainerEvent>,
+    ) -> Self {
+        let (time_tx, time_rx) = watch::channel(initial);
         let task = tokio::spawn(async move {
             loop {
-                if let Ok(Ok(res)) = caller.call(command::trainer::CheckBall).await {
-                    if time_tx.send(Some(res.time)).is_err() {
-                        debug!("[TimeStatusAddon] Time channel closed, stopping polling.");
-                        break;
-                    }
-                } else {
-                    debug!("[TimeStatusAddon] Failed to get time: Caller closed.");
+                let time = tokio::select! {
+                    res = see_global.changed() => {
+                        if res.is_err() {
+                            debug!("[TimeStatusAddon] see_global channel closed, stopping.");
+                            break;
+                        }
+                        match see_global.borrow_and_update().as_ref() {
+                            Some(world) => world.time,
+                            None => continue,
+                        }
+                    },
+                    res = events.recv() => match res {
+                        Ok(event) => event.time(),
+                        Err(broadcast::error::RecvError::Lagged(_)) => continue,
+                        Err(broadcast::error::RecvError::Closed) => {
+                            debug!("[TimeStatusAddon] Event channel closed, stopping.");
+                            break;
+                        }
+                    },
+                };
+
+                time_tx.send_if_modified(|t| {
+                    let changed = *t != Some(time);
+                    *t = Some(time);
+                    changed
+                });
+                if time_tx.is_closed() {
+                    debug!("[TimeStatusAddon] Time channel closed, stopping.");
                     break;
                 }
-                tokio::time::sleep(Duration::from_millis(POLL_INT_MS)).await;
             }
         });
 
@@ -40,48 +61,37 @@ impl<const POLL_INT_MS: u64> TimeStatusAddon<POLL_INT_MS> {
         }
     }
 
-    fn watcher(&self) -> watch::Receiver<Option<u16>> {
+    pub fn watcher(&self) -> watch::Receiver<Option<u16>> {
         self.timestep.clone()
     }
 
-    fn time(&self) -> Option<u16> {
+    pub fn time(&self) -> Option<u16> {
         *self.timestep.borrow()
     }
 }
 
-impl<const POLL_INT_MS: u64> Addon for TimeStatusAddon<POLL_INT_MS> {
+impl Addon for TimeStatusAddon {
     fn close(&self) {
         self.task.abort()
     }
 }
 
-impl<const POLL_INT_MS: u64> CallerAddon<TrainerCommand> for TimeStatusAddon<POLL_INT_MS> {
-    type Handle = watch::Receiver<Option<u16>>;
-
-    fn handle(&self) -> Self::Handle {
-        self.watcher()
-    }
-
-    fn from_caller(
-        _: mpsc::Sender<TxSignal>,
-        caller: CallSender<TrainerCommand, TxData, RxData>,
-    ) -> Self {
-        Self::start(caller)
-    }
-}
-
 #[cfg(test)]
 mod tests {
-    use super::*;
+    use std::time::Duration;
+
+    use common::command;
     use process::CoachedProcess;
 
+    use crate::base::AddonProcess;
+
     #[tokio::test]
     async fn test_tracking_time_status_auto_start_half_time_break_end() -> Result<(), ()> {
         let spawner = CoachedProcess::spawner().await;
-        let server = spawner.spawn().await.expect("Spawn failed");
+        let server = AddonProcess::spawn(&spawner).await.expect("Spawn failed");
 
-        let rx = server.coach().add_caller_addon::<TimeStatusAddon>("time");
-        let caller = server.coach().caller();
+        let rx = server.time_watch();
+        let caller = server.trainer_command_sender();
         caller
             .call(command::trainer::Start)
             .await
@@ -92,7 +102,6 @@ mod tests {
             let mut rx = rx;
             while let Ok(_) = rx.changed().await {
                 let t = *rx.borrow();
-                println!("Timestep:\t{t:?}.");
                 if let Some(t) = t {
                     if t == 3000 {
                         caller
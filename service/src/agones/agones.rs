@@ -6,7 +6,10 @@ use log::{debug, info, warn};
 use tokio::sync::{mpsc, watch, RwLock};
 use tokio_util::sync::CancellationToken;
 use agones::Sdk as AgonesSdk;
-use crate::{Error, Result, ServerStatus};
+use common::declaration::{Blocklist as BlocklistDeclaration, InitState as InitStateDeclaration};
+use common::stopping::StoppingEvent;
+use common::types::Side;
+use crate::{ActionList, Blocklist, Error, InitialState, PlayerInit, Result, ServerStatus, StopConditions};
 use crate::agones::config::{AgonesAutoShutdownConfig};
 use super::{AgonesConfig, AgonesArgs, BaseService};
 use super::match_composer::MatchComposerClient;
@@ -50,7 +53,7 @@ impl AgonesService {
             args.agones_keep_alive.map(Duration::from_secs),
         ).await.map_err(Error::AgonesSdkFailToConnect)?;
 
-        let base = BaseService::from_args(args.base_args).await;
+        let base = BaseService::from_args(args.base_args).await?;
 
         let mc_config = args.mc_args.into_config();
         let mc_client = mc_config.as_ref()
@@ -95,6 +98,16 @@ impl AgonesService {
             )
         );
 
+        let _annotation_task = tokio::spawn(
+            Self::run_annotation_watch(
+                sdk_guard.clone(),
+                self.service.stop_conditions_sender(),
+                self.service.initial_state_sender(),
+                self.service.blocklist_sender(),
+                self.cancel_token.clone(),
+            )
+        );
+
         let _shutdown_sig_task = tokio::spawn(
             Self::run_shutdown_signal(
                 self.cfg.shutdown.clone(),
@@ -167,6 +180,90 @@ impl AgonesService {
         }
     }
 
+    /// The allocator hands over the `stopping`, `init` and `blocklist` declarations as game
+    /// server annotations on allocation, apply them whenever they show up or change.
+    async fn run_annotation_watch(
+        mut sdk: AgonesSdk,
+        stopping_tx: watch::Sender<StopConditions>,
+        init_state_tx: watch::Sender<InitialState>,
+        blocklist_tx: watch::Sender<Blocklist>,
+        cancel_token: CancellationToken,
+    ) {
+        let mut stream = match sdk.watch_gameserver().await {
+            Ok(stream) => stream,
+            Err(e) => {
+                warn!("[AgonesService] 'run_annotation_watch': Failed to watch game server: {e}");
+                return;
+            }
+        };
+
+        loop {
+            let gs = tokio::select! {
+                _ = cancel_token.cancelled() => break,
+                msg = stream.message() => match msg {
+                    Ok(Some(gs)) => gs,
+                    Ok(None) => break,
+                    Err(e) => {
+                        warn!("[AgonesService] 'run_annotation_watch': Watch stream failed: {e}");
+                        break;
+                    }
+                },
+            };
+
+            let Some(annotations) = gs.object_meta.as_ref().map(|m| &m.annotations) else {
+                continue;
+            };
+
+            if let Some(raw) = annotations.get("stopping") {
+                match parse_stopping_annotation(raw, &stopping_tx.borrow()) {
+                    Some(stopping) => {
+                        stopping_tx.send_if_modified(|current| {
+                            if *current == stopping {
+                                return false;
+                            }
+                            info!("[AgonesService] Stop conditions updated: {stopping:?}");
+                            *current = stopping;
+                            true
+                        });
+                    }
+                    None => warn!("[AgonesService] 'run_annotation_watch': Ignore malformed stopping: {raw:?}"),
+                }
+            }
+
+            if let Some(raw) = annotations.get("init") {
+                match parse_init_annotation(raw) {
+                    Some(init) => {
+                        init_state_tx.send_if_modified(|current| {
+                            if *current == init {
+                                return false;
+                            }
+                            info!("[AgonesService] Initial state updated: {init:?}");
+                            *current = init;
+                            true
+                        });
+                    }
+                    None => warn!("[AgonesService] 'run_annotation_watch': Ignore malformed init: {raw:?}"),
+                }
+            }
+
+            if let Some(raw) = annotations.get("blocklist") {
+                match parse_blocklist_annotation(raw) {
+                    Some(blocklist) => {
+                        blocklist_tx.send_if_modified(|current| {
+                            if *current == blocklist {
+                                return false;
+                            }
+                            info!("[AgonesService] Blocklist updated: {blocklist:?}");
+                            *current = blocklist;
+                            true
+                        });
+                    }
+                    None => warn!("[AgonesService] 'run_annotation_watch': Ignore malformed blocklist: {raw:?}"),
+                }
+            }
+        }
+    }
+
     // resolves when service needs to shut down
     pub fn shutdown_signal(&self) -> impl Future<Output = ()> + 'static {
         let mut rx = self.shutdown_rx.clone();
@@ -255,4 +352,82 @@ impl AgonesService {
     fn health_check_interval(&self) -> Duration {
         self.cfg.health_check_interval
     }
-}
\ No newline at end of file
+}
+/// Reads the allocator's [`StoppingEvent`], fields it leaves out keep
+/// their current value except the goal limits, which it always decides.
+fn parse_stopping_annotation(raw: &str, current: &StopConditions) -> Option<StopConditions> {
+    let declaration: StoppingEvent = serde_json::from_str(raw).ok()?;
+    declaration.verify().ok()?;
+    Some(StopConditions {
+        time_up: declaration.timeup.or(current.time_up),
+        goal_l: declaration.goal_l,
+        goal_r: declaration.goal_r,
+        play_modes: current.play_modes.clone(),
+    })
+}
+
+/// Reads the allocator's [`InitStateDeclaration`], positions are normalised
+/// to the pitch, see [`InitialState::pitch_pos`].
+fn parse_init_annotation(raw: &str) -> Option<InitialState> {
+    let declaration: InitStateDeclaration = serde_json::from_str(raw).ok()?;
+    let players_l = declaration.players_l.into_iter().map(|p| (Side::LEFT, p));
+    let players_r = declaration.players_r.into_iter().map(|p| (Side::RIGHT, p));
+    Some(InitialState {
+        ball: declaration.ball.map(|b| InitialState::pitch_pos(b.x, b.y)),
+        players: players_l.chain(players_r)
+            .map(|(side, (unum, player))| PlayerInit {
+                side,
+                unum: *unum,
+                pos: player.pos.map(|p| InitialState::pitch_pos(p.x, p.y)),
+                stamina: player.stamina,
+            })
+            .collect(),
+    })
+}
+
+/// Reads the allocator's [`BlocklistDeclaration`].
+fn parse_blocklist_annotation(raw: &str) -> Option<Blocklist> {
+    let declaration: BlocklistDeclaration = serde_json::from_str(raw).ok()?;
+    let players_l = declaration.players_l.into_iter().map(|p| (Side::LEFT, p));
+    let players_r = declaration.players_r.into_iter().map(|p| (Side::RIGHT, p));
+
+    let mut ret = Blocklist::default();
+    for (side, (unum, actions)) in players_l.chain(players_r) {
+        ret.insert(side, *unum, ActionList { dash: actions.dash, catch: actions.r#catch });
+    }
+    Some(ret)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// The `init` annotation the allocator writes for its `from_v1` test config.
+    /// The `blocklist` annotation the allocator writes for the same config.
+    const BLOCKLIST: &str = r#"{"players_l":{},"players_r":{"1":{"dash":false,"catch":true}}}"#;
+
+    const INIT: &str = r#"{"ball":{"x":0.5,"y":0.25},"players_l":{"2":{"pos":null,"stamina":null}},"players_r":{"1":{"pos":{"x":0.75,"y":0.5},"stamina":4000}}}"#;
+
+    #[test]
+    fn test_parse_init_annotation() {
+        let init = parse_init_annotation(INIT).unwrap();
+        assert_eq!(init.ball, Some(InitialState::pitch_pos(0.5, 0.25)));
+        assert_eq!(init.players, vec![
+            PlayerInit { side: Side::LEFT, unum: 2, pos: None, stamina: None },
+            PlayerInit { side: Side::RIGHT, unum: 1, pos: Some(InitialState::pitch_pos(0.75, 0.5)), stamina: Some(4000) },
+        ]);
+
+        assert_eq!(parse_init_annotation("{}"), Some(InitialState::default()));
+        assert_eq!(parse_init_annotation(r#"{"players_l":{"two":{}}}"#), None);
+    }
+
+    #[test]
+    fn test_parse_blocklist_annotation() {
+        let blocklist = parse_blocklist_annotation(BLOCKLIST).unwrap();
+        assert_eq!(blocklist.get(Side::RIGHT, 1), ActionList { dash: false, catch: true });
+        assert!(blocklist.get(Side::LEFT, 1).is_empty());
+
+        assert!(parse_blocklist_annotation("{}").unwrap().is_empty());
+        assert!(parse_blocklist_annotation(r#"{"players_l":{"1":{"dash":1}}}"#).is_none());
+    }
+}
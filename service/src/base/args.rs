@@ -1,4 +1,6 @@
 use clap::Parser;
+use clap::builder::PossibleValuesParser;
+use process::Presets;
 
 #[derive(Parser, Debug)]
 pub struct BaseArgs {
@@ -8,16 +10,55 @@ pub struct BaseArgs {
     pub trainer_port: u16,
     #[clap(long, default_value_t = 6002, help = "RCSS coach udp port")]
     pub coach_port: u16,
+    #[clap(long, default_value_t = false, conflicts_with_all = ["player_port", "trainer_port", "coach_port"], help = "Pick free RCSS udp ports at every spawn instead of the fixed ones")]
+    pub auto_ports: bool,
     #[clap(long, default_value_t = true, help = "RCSS sync mode")]
     pub rcss_sync: bool,
     #[clap(long, default_value = "./log", help = "RCSS log directory")]
     pub rcss_log_dir: String,
-    #[clap(long, default_value_t = 6000, help = "Total timesteps")]
-    pub timesteps: u16,
+    #[clap(long, env = Presets::ENV, value_parser = PossibleValuesParser::new(Presets::BUILTIN), help = "RCSS parameter preset, applied over --rcss-sync")]
+    pub preset: Option<String>,
+    #[clap(long, help = "Directory of rcssserver's server.conf, player.conf and CSVSaver.conf, applied over the preset")]
+    pub rcss_conf_dir: Option<String>,
+    #[clap(long, env = Presets::OVERRIDES_ENV, help = "RCSS parameters as JSON, e.g. {\"server\":{\"half_time\":60}}, applied over the ports and log directory")]
+    pub rcss_overrides: Option<String>,
+    #[clap(long, help = "Finish at this timestep regardless of the match format")]
+    pub timesteps: Option<u16>,
+    #[clap(long, help = "Seconds per normal half, RCSS server::half_time")]
+    pub half_time: Option<i32>,
+    #[clap(long, help = "Number of normal halves, RCSS server::nr_normal_halfs")]
+    pub nr_normal_halfs: Option<i32>,
+    #[clap(long, help = "Seconds per extra half, RCSS server::extra_half_time")]
+    pub extra_half_time: Option<i32>,
+    #[clap(long, help = "Number of extra halves on a tie, RCSS server::nr_extra_halfs")]
+    pub nr_extra_halfs: Option<i32>,
+    #[clap(long, help = "Penalty shoot-out on a tie, RCSS server::penalty_shoot_outs")]
+    pub penalty_shoot_outs: Option<bool>,
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..), help = "Finish once the left team scored this many goals")]
+    pub stop_goal_l: Option<u32>,
+    #[clap(long, value_parser = clap::value_parser!(u32).range(1..), help = "Finish once the right team scored this many goals")]
+    pub stop_goal_r: Option<u32>,
     
-    #[clap(long, default_value_t = false, help = "Auto start when half-time(3000) is reached")]
+    #[clap(long, default_value_t = false, help = "Auto start every half, extra half and the penalty shoot-out")]
     pub half_time_auto_start: bool,
     
+    #[clap(long, default_value_t = false, help = "Place the initial state and kick off once every player connected")]
+    pub kick_off_auto_start: bool,
+
+    #[clap(long, default_value_t = false, help = "Run the keepaway referee and track its episodes, RCSS server::keepaway")]
+    pub keepaway: bool,
+    #[clap(long, help = "Keepaway region length, RCSS server::keepaway_length")]
+    pub keepaway_length: Option<f64>,
+    #[clap(long, help = "Keepaway region width, RCSS server::keepaway_width")]
+    pub keepaway_width: Option<f64>,
+    #[clap(long, help = "Write .kwy episode logs to the RCSS log directory, RCSS server::keepaway_logging")]
+    pub keepaway_logging: Option<bool>,
+    #[clap(long, help = "Seconds to wait for players before starting keepaway, RCSS server::keepaway_start")]
+    pub keepaway_start: Option<i32>,
+
+    #[clap(long, help = "Directory of .toml and .json scenarios to register by file name")]
+    pub scenario_dir: Option<String>,
+
     #[clap(long, default_value_t = true, help = "Always log stdout and stderr")]
     pub always_log_stdout: bool,
 }
\ No newline at end of file
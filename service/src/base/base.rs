@@ -3,10 +3,15 @@ use log::{debug, info, warn};
 use tokio::task::JoinHandle;
 use common::command::{trainer, Command, CommandResult};
 use common::command::trainer::TrainerCommand;
-use process::{CoachedProcessSpawner, CommandCaller, ProcessConfig, ProcessStatus};
+use common::scenario::Scenario;
+use common::types::{PlayMode, WorldSnapshot};
+use process::addon::Score;
+use process::{CoachedProcessSpawner, CommandCaller, PortAllocator, Presets, ProcessConfig, ProcessStatus};
 
 use crate::{Error, Result};
-use super::{AddonProcess, BaseArgs, BaseConfig, ServerStatus};
+use super::{AddonProcess, BaseArgs, BaseConfig, Blocklist, Curriculum, CurriculumProgress, Episode, InitialState, KeepawayLog, KeepawayStats, MatchFormat, PenaltyProgress, PenaltyTraining, Phase, Scenarios, ServerStatus, StopConditions, StopReason};
+use super::scenario;
+use super::training::{TrainingProgress, TrainingRunner};
 
 #[derive(Debug)]
 pub enum OptionedProcess {
@@ -51,6 +56,15 @@ pub struct BaseService {
     process: RwLock<OptionedProcess>,
     status_tx: watch::Sender<ServerStatus>,
     status_rx: watch::Receiver<ServerStatus>,
+    stopping_tx: watch::Sender<StopConditions>,
+    stop_reason_tx: watch::Sender<Option<StopReason>>,
+    format: MatchFormat,
+    phase_tx: watch::Sender<Phase>,
+    init_state_tx: watch::Sender<InitialState>,
+    blocklist_tx: watch::Sender<Blocklist>,
+    episode_tx: watch::Sender<Episode>,
+    scenarios_tx: watch::Sender<Scenarios>,
+    training: TrainingRunner,
 
     cancel_tx: watch::Sender<bool>,
 }
@@ -65,26 +79,76 @@ pub(crate) fn get_status(watch: &watch::Receiver<ServerStatus>) -> ServerStatus
 }
 
 impl BaseService {
-    pub async fn from_args(args: BaseArgs) -> Self {
-        let config = (&args).into();
+    pub async fn from_args(args: BaseArgs) -> Result<Self> {
+        let mut config: BaseConfig = (&args).into();
+        if let Some(dir) = &args.scenario_dir {
+            let n = config.scenarios.load_dir(dir).map_err(Error::InvalidScenarioDir)?;
+            info!("[BaseService] Loaded {n} scenarios from {dir}");
+        }
         let mut spawner = CoachedProcessSpawner::new().await;
-        let rcss_log_dir = args.rcss_log_dir.leak(); // STRING LEAK
-        spawner
-            .with_ports(args.player_port, args.trainer_port, args.coach_port)
-            .with_sync_mode(args.rcss_sync)
-            .with_log_dir(rcss_log_dir);
+        spawner.with_sync_mode(args.rcss_sync);
+        if let Some(name) = &args.preset {
+            let presets = Presets::default();
+            let preset = presets.get(name).ok_or_else(|| Error::UnknownPreset(name.clone()))?;
+            spawner.process_config_mut().merge(preset);
+            info!("[BaseService] Applied rcssserver preset {name}");
+        }
+        if let Some(dir) = &args.rcss_conf_dir {
+            let conf = ProcessConfig::load_dir(dir).map_err(Error::InvalidConfDir)?;
+            spawner.process_config_mut().merge(&conf);
+            info!("[BaseService] Loaded rcssserver configuration from {dir}");
+        }
+        if args.auto_ports {
+            spawner.with_port_allocator(PortAllocator::default());
+        } else {
+            spawner.with_ports(args.player_port, args.trainer_port, args.coach_port);
+        }
+        spawner.with_log_dir(&args.rcss_log_dir);
+        if let Some(json) = &args.rcss_overrides {
+            let overrides: ProcessConfig = serde_json::from_str(json).map_err(Error::InvalidOverrides)?;
+            spawner.process_config_mut().merge(&overrides);
+        }
+        spawner.process_config_mut().server_then(|c| {
+            if let Some(half_time) = args.half_time { c.half_time(half_time); }
+            if let Some(halfs) = args.nr_normal_halfs { c.nr_normal_halfs(halfs); }
+            if let Some(extra_half_time) = args.extra_half_time { c.extra_half_time(extra_half_time); }
+            if let Some(halfs) = args.nr_extra_halfs { c.nr_extra_halfs(halfs); }
+            if let Some(penalty_shoot_outs) = args.penalty_shoot_outs { c.penalty_shoot_outs(penalty_shoot_outs); }
+            if args.keepaway {
+                c.keepaway(true).keepaway_log_dir(args.rcss_log_dir.clone());
+                if let Some(length) = args.keepaway_length { c.keepaway_length(length); }
+                if let Some(width) = args.keepaway_width { c.keepaway_width(width); }
+                if let Some(logging) = args.keepaway_logging { c.keepaway_logging(logging); }
+                if let Some(start) = args.keepaway_start { c.keepaway_start(start); }
+            }
+        });
 
-        BaseService::new(config, spawner).await
+        Ok(BaseService::new(config, spawner).await)
     }
 
     pub(super) async fn new(config: BaseConfig, spawner: CoachedProcessSpawner) -> Self {
         let process = RwLock::new(OptionedProcess::Uninitialized);
         let (status_tx, status_rx) = watch::channel(ServerStatus::Uninitialized);
         let (cancel_tx, _) = watch::channel(false);
-        Self { config, spawner, process, status_tx, status_rx, cancel_tx }
+        let (stopping_tx, _) = watch::channel(config.stopping.clone());
+        let (stop_reason_tx, _) = watch::channel(None);
+        let format = MatchFormat::from_config(&spawner.process.config);
+        let (phase_tx, _) = watch::channel(Phase::Regular { half: 1 });
+        let (init_state_tx, _) = watch::channel(config.init_state.clone());
+        let (blocklist_tx, _) = watch::channel(config.blocklist.clone());
+        let (episode_tx, _) = watch::channel(Episode::default());
+        let (scenarios_tx, _) = watch::channel(config.scenarios.clone());
+        let training = TrainingRunner::new();
+        Self {
+            config, spawner, process, status_tx, status_rx, stopping_tx, stop_reason_tx,
+            format, phase_tx, init_state_tx, blocklist_tx, episode_tx, scenarios_tx,
+            training, cancel_tx,
+        }
     }
 
     pub(crate) async fn spawn(&self, force: bool) -> Result<JoinHandle<()>> {
+        self.config().validate().map_err(Error::InvalidConfig)?;
+
         // >- process WRITE lock -<
         let mut process_guard = self.process.write().await;
 
@@ -95,6 +159,8 @@ impl BaseService {
             }
 
             warn!("[BaseService] Force restarting the process...");
+            // stop the tasks of the old process before its channels close
+            let _ = self.cancel_tx.send(true);
             if let Err(e) = process.shutdown().await {
                 warn!("[BaseService] Failed to shutdown existing process: {:?}. dropping", e);
             }
@@ -104,29 +170,55 @@ impl BaseService {
 
         let process = self.spawner.spawn().await
             .map_err(|e| Error::ProcessSpawnFailed(e))?;
-        let process = AddonProcess::from_coached_process(process);
+        let mut process = AddonProcess::from_coached_process(process).await?;
         info!("[BaseService] AddonProcess spawned");
+        if self.keepaway_enabled() {
+            process.track_keepaway();
+        }
 
         let cancel_tx = self.cancel_tx.clone();
         let mut tasks: Vec<JoinHandle<()>> = vec![];
 
-        let time_rx = process.time_watch();
-        let status_tracing = tokio::spawn(
-            Self::status_tracing_task(self.status_tx.clone(), time_rx, cancel_tx.clone())
-        );
+        self.stop_reason_tx.send_replace(None);
+        self.phase_tx.send_replace(Phase::Regular { half: 1 });
+        self.episode_tx.send_replace(Episode::default());
+        let status_tracing = tokio::spawn(Self::status_tracing_task(
+            self.status_tx.clone(),
+            process.time_watch(),
+            process.play_mode_watch(),
+            process.score_watch(),
+            self.episode_tx.subscribe(),
+            self.stopping_tx.subscribe(),
+            self.stop_reason_tx.clone(),
+            self.format,
+            self.phase_tx.clone(),
+            cancel_tx.clone(),
+        ));
         tasks.push(status_tracing);
         info!("[BaseService] Status tracing task spawned");
 
-        if let Some(half_time) = self.config.half_time_auto_start {
+        if self.config.half_time_auto_start {
             let caller = process.trainer_command_sender();
             let kick_off_half_time = tokio::spawn(Self::kick_off_half_time_task(
                 process.time_watch(),
+                process.score_watch(),
                 caller,
-                half_time,
+                self.format,
                 cancel_tx.clone()
             ));
             tasks.push(kick_off_half_time);
-            info!("[BaseService] KickOff Half-Time task spawned (half_time = {}ts)", half_time);
+            info!("[BaseService] KickOff Half-Time task spawned (half ends = {:?})", self.format.half_ends());
+        }
+
+        if self.config.kick_off_auto_start {
+            let kick_off = tokio::spawn(Self::kick_off_task(
+                process.world_watch(),
+                self.init_state_tx.subscribe(),
+                process.trainer_command_sender(),
+                cancel_tx.clone(),
+            ));
+            tasks.push(kick_off);
+            info!("[BaseService] KickOff task spawned");
         }
 
         if self.config.always_log_stdout {
@@ -158,6 +250,17 @@ impl BaseService {
             .map_err(|_| Error::Timeout { op: "send_trainer_command" })
     }
 
+    /// Sends a trainer `move`, a player addressed by side is named by the
+    /// `team_names` reply first.
+    pub async fn send_move(&self, mut command: trainer::Move) -> Result<CommandResult<trainer::Move>> {
+        if let trainer::MoveObject::PlayerOnSide { .. } = command.object {
+            let names = self.send_trainer_command(trainer::TeamNames).await?
+                .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?;
+            command.object.resolve(&names).map_err(|side| Error::TeamNotConnected { side })?;
+        }
+        self.send_trainer_command(command).await
+    }
+
     pub async fn trainer_command_sender(&self) -> Result<CommandCaller<TrainerCommand>> {
         let ret = self.process.read().await.process()
             .ok_or(Error::ServerNotRunning { status: ServerStatus::Uninitialized })?
@@ -165,6 +268,125 @@ impl BaseService {
         Ok(ret)
     }
 
+    /// Places the ball and players of the initial state, recovers them and kicks off.
+    pub async fn kick_off(&self) -> Result<()> {
+        let caller = self.trainer_command_sender().await?;
+        self.initial_state().kick_off(&caller).await
+    }
+
+    /// Starts a new episode on the running process: places `init_state`, or the
+    /// configured initial state when `None`, sets `play_mode` and counts time
+    /// and score from here. The ball goes to the centre spot unless the state
+    /// places it.
+    pub async fn reset_episode(&self, play_mode: PlayMode, init_state: Option<InitialState>) -> Result<Episode> {
+        let mut init = init_state.unwrap_or_else(|| self.initial_state());
+        init.ball.get_or_insert(InitialState::pitch_pos(0.5, 0.5));
+        let scenario = Scenario { play_mode, ..Scenario::from(&init) };
+
+        // >- process READ lock -<
+        let process_guard = self.process.read().await;
+        let process = process_guard.process()
+            .ok_or(Error::ServerNotRunning { status: self.status_now() })?;
+        scenario::execute(&scenario, &process.trainer_command_sender()).await?;
+
+        let episode = self.start_episode(process, play_mode)?;
+        info!("[BaseService] Episode {} started at {}ts in {:?}", episode.id, episode.start_time, play_mode);
+        Ok(episode)
+        // >- process READ free -<
+    }
+
+    /// Waits for the scenario's cycle, sets it up and starts a new episode from there.
+    pub async fn apply_scenario(&self, scenario: &Scenario) -> Result<Episode> {
+        if let Some(cycle) = scenario.cycle {
+            let mut time_rx = self.time().await
+                .ok_or(Error::ServerNotRunning { status: self.status_now() })?;
+            time_rx.wait_for(|time| time.is_some_and(|time| time >= cycle)).await
+                .map_err(|_| Error::ServerNotRunning { status: self.status_now() })?;
+        }
+
+        // >- process READ lock -<
+        let process_guard = self.process.read().await;
+        let process = process_guard.process()
+            .ok_or(Error::ServerNotRunning { status: self.status_now() })?;
+        scenario::execute(scenario, &process.trainer_command_sender()).await?;
+
+        let episode = self.start_episode(process, scenario.play_mode)?;
+        info!("[BaseService] Episode {} started at {}ts from a scenario", episode.id, episode.start_time);
+        Ok(episode)
+        // >- process READ free -<
+    }
+
+    pub async fn apply_named_scenario(&self, name: &str) -> Result<Episode> {
+        let scenario = self.scenario(name)
+            .ok_or_else(|| Error::UnknownScenario(name.to_string()))?;
+        self.apply_scenario(&scenario).await
+    }
+
+    /// Runs attempts at the curriculum's stages until it completes or
+    /// [`BaseService::stop_curriculum`] is called, progress goes out on
+    /// [`BaseService::training_watch`] after every attempt.
+    pub async fn run_curriculum(&self, curriculum: Curriculum) -> Result<CurriculumProgress> {
+        self.check_curriculum(&curriculum)?;
+        info!("[BaseService] Curriculum started with {} stages", curriculum.stages.len());
+        self.training.run(self, &curriculum, TrainingProgress::Curriculum(curriculum.start())).await?;
+        Ok(self.curriculum().unwrap_or_default())
+    }
+
+    /// Whether [`BaseService::run_curriculum`] would accept `curriculum`.
+    pub fn check_curriculum(&self, curriculum: &Curriculum) -> Result<()> {
+        curriculum.verify().map_err(Error::InvalidCurriculum)?;
+        if let Some(stage) = curriculum.stages.iter().find(|s| self.scenario(&s.scenario).is_none()) {
+            return Err(Error::UnknownScenario(stage.scenario.clone()));
+        }
+        self.training.check()
+    }
+
+    /// Stops a running curriculum, dropping the attempt in progress. Returns
+    /// whether one was running.
+    pub fn stop_curriculum(&self) -> bool {
+        self.curriculum().is_some_and(|p| p.running) && self.training.stop()
+    }
+
+    /// Takes penalty after penalty until the training completes or
+    /// [`BaseService::stop_penalty_training`] is called, every attempt goes out
+    /// on [`BaseService::training_watch`].
+    pub async fn run_penalty_training(&self, training: PenaltyTraining) -> Result<PenaltyProgress> {
+        self.check_penalty_training(&training)?;
+        info!("[BaseService] Penalty training started, kickers {:?} and {:?}", training.kickers_l, training.kickers_r);
+        self.training.run(self, &training, TrainingProgress::Penalty(training.start())).await?;
+        Ok(self.penalty_training().unwrap_or_default())
+    }
+
+    /// Whether [`BaseService::run_penalty_training`] would accept `training`.
+    pub fn check_penalty_training(&self, training: &PenaltyTraining) -> Result<()> {
+        if training.turn(0).is_none() {
+            return Err(Error::InvalidPenaltyTraining("at least one kicker is needed"));
+        }
+        if training.within == 0 || training.attempts == Some(0) {
+            return Err(Error::InvalidPenaltyTraining("attempts need at least one cycle and one attempt"));
+        }
+        self.training.check()
+    }
+
+    /// Stops a running penalty training, dropping the attempt in progress.
+    /// Returns whether one was running.
+    pub fn stop_penalty_training(&self) -> bool {
+        self.penalty_training().is_some_and(|p| p.running) && self.training.stop()
+    }
+
+    /// Counts time and score from here and resumes the stop checks.
+    fn start_episode(&self, process: &AddonProcess, play_mode: PlayMode) -> Result<Episode> {
+        let episode = self.episode().next(process.time().unwrap_or_default(), process.score());
+        self.episode_tx.send_replace(episode);
+        self.stop_reason_tx.send_replace(None);
+        let status = match play_mode {
+            PlayMode::PM_BeforeKickOff => ServerStatus::Idle,
+            _ => ServerStatus::Simulating,
+        };
+        self.set_status(status).ok_or(Error::StatusChannelClosed)?;
+        Ok(episode)
+    }
+
     pub async fn shutdown(&self) -> Result<()> {
         let _ = self.cancel_tx.send(true);
 
@@ -193,54 +415,86 @@ impl BaseService {
         // >- process WRITE free -<
     }
 
+    /// follows the cycle, play mode and score until one of the stop conditions fires
+    /// or the match format has no more play left, a finished episode waits for a reset
+    #[allow(clippy::too_many_arguments)]
     async fn status_tracing_task(
         status_tx: watch::Sender<ServerStatus>,
         mut time_rx: watch::Receiver<Option<u16>>,
+        mut play_mode_rx: watch::Receiver<Option<PlayMode>>,
+        mut score_rx: watch::Receiver<Score>,
+        mut episode_rx: watch::Receiver<Episode>,
+        mut stopping_rx: watch::Receiver<StopConditions>,
+        stop_reason_tx: watch::Sender<Option<StopReason>>,
+        format: MatchFormat,
+        phase_tx: watch::Sender<Phase>,
         cancel_tx: watch::Sender<bool>,
     ) {
         let status_rx = status_tx.subscribe();
         let mut cancel_rx = cancel_tx.subscribe();
         loop {
-            tokio::select! {
-                res = time_rx.changed() => {
-                    let timestep = match res {
-                        Ok(_) => *time_rx.borrow(),
-                        Err(_) => {
-                            let _ = set_status(&status_tx, ServerStatus::Finished);
-                            info!("[BaseService] Status Tracking ended: time_rx channel closed.");
-                            break;
-                        }
-                    };
+            let closed = tokio::select! {
+                res = time_rx.changed() => res.is_err(),
+                res = play_mode_rx.changed() => res.is_err(),
+                res = score_rx.changed() => res.is_err(),
+                res = episode_rx.changed() => res.is_err(),
+                res = stopping_rx.changed() => res.is_err(),
+                _ = cancel_rx.changed() => {
+                    info!("[BaseService] Status Tracking ended: cancel recved.");
+                    break;
+                },
+            };
 
-                    let next_status = match (get_status(&status_rx), timestep) {
-                        (ServerStatus::Uninitialized, Some(0)) => ServerStatus::Idle,
-                        (ServerStatus::Uninitialized, Some(_)) => ServerStatus::Simulating,
-                        (ServerStatus::Idle, Some(t)) if t > 0 && t < 6000 => {
-                            ServerStatus::Simulating
-                        }
-                        (ServerStatus::Idle, Some(t)) if t >= 6000 => ServerStatus::Finished,
-                        (ServerStatus::Simulating, Some(t)) if t >= 6000 => ServerStatus::Finished,
-                        _ => continue,
-                    };
+            if closed {
+                stop_reason_tx.send_replace(Some(StopReason::Disconnected));
+                let _ = set_status(&status_tx, ServerStatus::Finished);
+                info!("[BaseService] Status Tracking ended: tracking channel closed.");
+                break;
+            }
 
-                    debug!("[BaseService] Status Tracking: {:?} -> {:?}",
-                        get_status(&status_rx),next_status);
+            let timestep = *time_rx.borrow();
+            let play_mode = *play_mode_rx.borrow();
+            let score = *score_rx.borrow();
+            let episode = *episode_rx.borrow();
+
+            let mut next_status = match (get_status(&status_rx), timestep) {
+                (ServerStatus::Uninitialized, Some(0)) => ServerStatus::Idle,
+                (ServerStatus::Uninitialized, Some(_)) => ServerStatus::Simulating,
+                (ServerStatus::Idle, Some(t)) if t > 0 => ServerStatus::Simulating,
+                (status, _) => status,
+            };
+
+            if let Some(time) = timestep {
+                let phase = format.phase(time, play_mode);
+                phase_tx.send_if_modified(|p| {
+                    let changed = *p != phase;
+                    *p = phase;
+                    changed
+                });
+            }
 
-                    if set_status(&status_tx, next_status).is_none() {
-                        info!("[BaseService] Status Tracking ended: status_tx channel closed.");
-                        break;
-                    }
+            // a finished episode stays finished until the next reset_episode
+            if get_status(&status_rx).is_finished() { continue };
+
+            let reason = stopping_rx.borrow()
+                .check(timestep.map(|t| episode.time(t)), play_mode, episode.score(score))
+                .or_else(|| timestep
+                    .filter(|time| format.is_over(*time, score))
+                    .map(|_| StopReason::TimeOver));
+            if let Some(reason) = reason {
+                info!("[BaseService] Status Tracking: stop condition met, {reason:?}");
+                stop_reason_tx.send_replace(Some(reason));
+                next_status = ServerStatus::Finished;
+            }
 
-                    if get_status(&status_rx).is_finished() {
-                        info!("[BaseService] Status Tracking ended: server status finished.");
-                        break;
-                    };
-                },
+            if next_status as u8 != get_status(&status_rx) as u8 {
+                debug!("[BaseService] Status Tracking: {:?} -> {:?}",
+                    get_status(&status_rx), next_status);
 
-                _ = cancel_rx.changed() => {
-                    info!("[BaseService] Status Tracking ended: cancel recved.");
+                if set_status(&status_tx, next_status).is_none() {
+                    info!("[BaseService] Status Tracking ended: status_tx channel closed.");
                     break;
-                },
+                }
             }
         }
 
@@ -248,25 +502,25 @@ impl BaseService {
         info!("[BaseService] Status Tracking finished.");
     }
 
-    /// trying to send start when half-time reached
+    /// trying to send start whenever a half ends and the match goes on
     async fn kick_off_half_time_task(
         mut time_rx: watch::Receiver<Option<u16>>,
+        score_rx: watch::Receiver<Score>,
         caller: CommandCaller<TrainerCommand>,
-        half_time: u16,
+        format: MatchFormat,
         cancel_tx: watch::Sender<bool>,
     ) {
         let mut cancel_rx = cancel_tx.subscribe();
-
-        assert!(half_time > 0 && half_time < 6000,
-            "[BaseService] kick_off_half_time_task: half_time must be between 1 and 5999");
+        let mut kicked_off = None;
 
         loop {
             tokio::select! {
                 _ = cancel_rx.changed() => {
                     info!("[BaseService] KickOff Halftime ended: cancel recved.");
+                    break;
                 },
                 res = time_rx.changed() => {
-                    if let Err(e) = res {
+                    if res.is_err() {
                         info!("[BaseService] KickOff Halftime ended: time_rx channel closed.");
                         let _ = cancel_tx.send(true);
                         break;
@@ -277,14 +531,15 @@ impl BaseService {
                         None => continue,
                     };
 
-                    // rcss server always stop at the half-time point
-                    // thus the equality check would be safe
-                    if time != half_time { continue };
+                    // rcss server always stops at the end of a half,
+                    // thus the equality check in kick_off_due would be safe
+                    if kicked_off == Some(time) || !format.kick_off_due(time, *score_rx.borrow()) { continue };
+                    kicked_off = Some(time);
                     match caller.call(trainer::Start).await {
                         Ok(_) =>
-                            debug!("[BaseService] KickOff Halftime: Sent Start command at half-time {}", half_time),
+                            debug!("[BaseService] KickOff Halftime: Sent Start command at {}ts, {:?}", time, format.phase(time, None)),
                         Err(e) =>
-                            warn!("[BaseService] KickOff Halftime: Failed to send Start command at {}ts: {:?}", half_time, e),
+                            warn!("[BaseService] KickOff Halftime: Failed to send Start command at {}ts: {:?}", time, e),
                     }
 
                 },
@@ -294,6 +549,64 @@ impl BaseService {
         info!("[BaseService] KickOff Halftime finished.");
     }
 
+    /// waits until every player of the initial state is on the pitch, then kicks off with it
+    async fn kick_off_task(
+        mut world_rx: watch::Receiver<Option<WorldSnapshot>>,
+        mut init_rx: watch::Receiver<InitialState>,
+        caller: CommandCaller<TrainerCommand>,
+        cancel_tx: watch::Sender<bool>,
+    ) {
+        let mut cancel_rx = cancel_tx.subscribe();
+        // team names only change when a player joins, fetched again once the count does
+        let mut names: Option<(usize, <trainer::TeamNames as Command>::Ok)> = None;
+
+        loop {
+            let closed = tokio::select! {
+                _ = cancel_rx.changed() => {
+                    info!("[BaseService] KickOff ended: cancel recved.");
+                    break;
+                },
+                res = init_rx.changed() => res.is_err(),
+                res = world_rx.changed() => res.is_err(),
+            };
+            if closed {
+                info!("[BaseService] KickOff ended: watch channel closed.");
+                break;
+            }
+
+            let init = init_rx.borrow().clone();
+            let Some(mut world) = world_rx.borrow_and_update().clone() else { continue };
+            if world.time > 0 {
+                info!("[BaseService] KickOff ended: match already started at {}ts.", world.time);
+                break;
+            }
+            // a ball-only state still needs both teams, hence two players at least
+            if world.players.len() < init.players.len().max(2) { continue };
+
+            let count = world.players.len();
+            if names.as_ref().is_none_or(|(fetched_at, _)| *fetched_at != count) {
+                match caller.call(trainer::TeamNames).await {
+                    Ok(Ok(fetched)) => names = Some((count, fetched)),
+                    res => {
+                        warn!("[BaseService] KickOff: Failed to get team names: {:?}", res);
+                        continue;
+                    }
+                }
+            }
+            let Some((_, team_names)) = &names else { continue };
+            world.assign_sides(team_names.left.as_deref(), team_names.right.as_deref());
+            if !init.all_connected(&world) { continue };
+
+            match init.kick_off(&caller).await {
+                Ok(()) => info!("[BaseService] KickOff: {count} players connected, kicked off."),
+                Err(e) => warn!("[BaseService] KickOff: Failed to kick off: {e}"),
+            }
+            break;
+        }
+
+        info!("[BaseService] KickOff finished.");
+    }
+
     async fn stdout_err_logging_task(
         mut status: watch::Receiver<ProcessStatus>,
         cancel_tx: watch::Sender<bool>,
@@ -346,7 +659,184 @@ impl BaseService {
         self.process.read().await.process().map(|p| p.time_watch())
     }
 
+    /// Cycles since the current episode started.
+    pub async fn episode_time_now(&self) -> Option<u16> {
+        let time = self.time_now().await?;
+        Some(self.episode().time(time))
+    }
+
+    pub fn episode(&self) -> Episode {
+        *self.episode_tx.borrow()
+    }
+
+    pub fn episode_watch(&self) -> watch::Receiver<Episode> {
+        self.episode_tx.subscribe()
+    }
+
+    /// The latest `see_global` frame, players come without a side.
+    pub async fn world(&self) -> Option<watch::Receiver<Option<WorldSnapshot>>> {
+        self.process.read().await.process().map(|p| p.world_watch())
+    }
+
+    pub async fn play_mode_now(&self) -> Option<PlayMode> {
+        self.process.read().await.process().and_then(|p| p.play_mode())
+    }
+
+    pub async fn play_mode(&self) -> Option<watch::Receiver<Option<PlayMode>>> {
+        self.process.read().await.process().map(|p| p.play_mode_watch())
+    }
+
+    pub async fn score_now(&self) -> Option<Score> {
+        self.process.read().await.process().map(|p| p.score())
+    }
+
+    pub async fn score(&self) -> Option<watch::Receiver<Score>> {
+        self.process.read().await.process().map(|p| p.score_watch())
+    }
+
+    /// Replaces the stop conditions, they are checked again right away.
+    pub fn set_stop_conditions(&self, stopping: StopConditions) {
+        self.stopping_tx.send_replace(stopping);
+    }
+
+    #[cfg(feature = "agones")]
+    pub(crate) fn stop_conditions_sender(&self) -> watch::Sender<StopConditions> {
+        self.stopping_tx.clone()
+    }
+
+    pub fn stop_conditions(&self) -> StopConditions {
+        self.stopping_tx.borrow().clone()
+    }
+
+    /// Why the current match finished, `None` while it is still going.
+    pub fn stop_reason(&self) -> Option<StopReason> {
+        *self.stop_reason_tx.borrow()
+    }
+
+    pub fn stop_reason_watch(&self) -> watch::Receiver<Option<StopReason>> {
+        self.stop_reason_tx.subscribe()
+    }
+
+    /// The match length and phases, read from the server config.
+    pub fn format(&self) -> MatchFormat {
+        self.format
+    }
+
+    pub fn phase(&self) -> Phase {
+        *self.phase_tx.borrow()
+    }
+
+    pub fn phase_watch(&self) -> watch::Receiver<Phase> {
+        self.phase_tx.subscribe()
+    }
+
+    /// Replaces the initial state applied on the next kick-off.
+    pub fn set_initial_state(&self, init_state: InitialState) {
+        self.init_state_tx.send_replace(init_state);
+    }
+
+    pub fn initial_state(&self) -> InitialState {
+        self.init_state_tx.borrow().clone()
+    }
+
+    #[cfg(feature = "agones")]
+    pub(crate) fn initial_state_sender(&self) -> watch::Sender<InitialState> {
+        self.init_state_tx.clone()
+    }
+
+    /// Replaces the player commands the proxy drops.
+    pub fn set_blocklist(&self, blocklist: Blocklist) {
+        self.blocklist_tx.send_replace(blocklist);
+    }
+
+    pub fn blocklist(&self) -> Blocklist {
+        self.blocklist_tx.borrow().clone()
+    }
+
+    pub fn blocklist_watch(&self) -> watch::Receiver<Blocklist> {
+        self.blocklist_tx.subscribe()
+    }
+
+    #[cfg(feature = "agones")]
+    pub(crate) fn blocklist_sender(&self) -> watch::Sender<Blocklist> {
+        self.blocklist_tx.clone()
+    }
+
+    pub fn scenarios(&self) -> Scenarios {
+        self.scenarios_tx.borrow().clone()
+    }
+
+    pub fn scenario(&self, name: &str) -> Option<Scenario> {
+        self.scenarios_tx.borrow().get(name).cloned()
+    }
+
+    /// Registers `scenario` under `name`, returning the one it replaced.
+    pub fn set_scenario(&self, name: String, scenario: Scenario) -> Option<Scenario> {
+        let mut replaced = None;
+        self.scenarios_tx.send_modify(|scenarios| replaced = scenarios.insert(name, scenario));
+        replaced
+    }
+
+    pub fn remove_scenario(&self, name: &str) -> Option<Scenario> {
+        let mut removed = None;
+        self.scenarios_tx.send_modify(|scenarios| removed = scenarios.remove(name));
+        removed
+    }
+
+    /// Progress of the latest curriculum, unless a penalty training ran since.
+    pub fn curriculum(&self) -> Option<CurriculumProgress> {
+        match self.training.progress()? {
+            TrainingProgress::Curriculum(progress) => Some(progress),
+            _ => None,
+        }
+    }
+
+    /// Whether rcssserver runs its keepaway referee, see [`BaseArgs::keepaway`].
+    pub fn keepaway_enabled(&self) -> bool {
+        self.config().server.keepaway == Some(true)
+    }
+
+    /// Episodes of the running process, `None` unless keepaway is enabled.
+    pub async fn keepaway_stats(&self) -> Option<KeepawayStats> {
+        self.process.read().await.process().and_then(|p| p.keepaway_stats())
+    }
+
+    pub async fn keepaway_watch(&self) -> Option<watch::Receiver<KeepawayStats>> {
+        self.process.read().await.process().and_then(|p| p.keepaway_watch())
+    }
+
+    /// The latest `.kwy` log in the keepaway log directory, `None` before
+    /// rcssserver wrote one.
+    pub fn keepaway_log(&self) -> Result<Option<KeepawayLog>> {
+        let dir = self.config().server.keepaway_log_dir.as_deref().unwrap_or("./");
+        let Some(path) = KeepawayLog::latest_in(dir).map_err(Error::KeepawayLogFailed)? else {
+            return Ok(None);
+        };
+        debug!("[BaseService] Reading keepaway log {}", path.display());
+        KeepawayLog::load(path).map(Some).map_err(Error::KeepawayLogFailed)
+    }
+
+    /// Progress of the latest penalty training, unless a curriculum ran since.
+    pub fn penalty_training(&self) -> Option<PenaltyProgress> {
+        match self.training.progress()? {
+            TrainingProgress::Penalty(progress) => Some(progress),
+            _ => None,
+        }
+    }
+
+    /// Progress of the latest curriculum or penalty training, only one runs at a time.
+    pub fn training_watch(&self) -> watch::Receiver<Option<TrainingProgress>> {
+        self.training.progress_watch()
+    }
+
     pub fn config(&self) -> &ProcessConfig {
         &self.spawner.process.config
     }
+
+    /// The udp port players connect to, picked at spawn with `--auto-ports`
+    /// and configured otherwise.
+    pub async fn player_port(&self) -> Option<u16> {
+        let reserved = self.process.read().await.process().and_then(|p| p.ports());
+        reserved.map(|ports| ports.player).or(self.config().server.port)
+    }
 }
\ No newline at end of file
@@ -0,0 +1,54 @@
+use std::collections::HashMap;
+
+use common::types::Side;
+
+/// Player commands that never reach rcssserver, default all allowed.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ActionList {
+    pub dash: bool,
+    pub catch: bool,
+}
+
+impl ActionList {
+    pub fn is_empty(&self) -> bool {
+        !self.dash && !self.catch
+    }
+
+    /// Whether the command named `command`, e.g. `dash` in `(dash 100)`, is blocked.
+    pub fn blocks(&self, command: &str) -> bool {
+        match command {
+            "dash" => self.dash,
+            "catch" => self.catch,
+            _ => false,
+        }
+    }
+}
+
+/// Blocked commands per player, mirrors the allocator's `blocklist` declaration.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Blocklist {
+    players: HashMap<(Side, u8), ActionList>,
+}
+
+impl Blocklist {
+    pub fn insert(&mut self, side: Side, unum: u8, actions: ActionList) -> &mut Self {
+        if actions.is_empty() {
+            self.players.remove(&(side, unum));
+        } else {
+            self.players.insert((side, unum), actions);
+        }
+        self
+    }
+
+    pub fn get(&self, side: Side, unum: u8) -> ActionList {
+        self.players.get(&(side, unum)).copied().unwrap_or_default()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.players.is_empty()
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (Side, u8, ActionList)> + '_ {
+        self.players.iter().map(|(&(side, unum), &actions)| (side, unum, actions))
+    }
+}
@@ -1,18 +1,32 @@
-use crate::base::BaseArgs;
+use crate::base::{BaseArgs, Blocklist, InitialState, Scenarios, StopConditions};
 
 #[derive(Clone, Debug)]
 pub struct BaseConfig {
-    pub half_time_auto_start: Option<u16>,
+    /// Kick off every phase the server pauses for, see [`MatchFormat::kick_off_due`](super::MatchFormat::kick_off_due).
+    pub half_time_auto_start: bool,
+    /// Apply `init_state` and kick off once every player in it connected.
+    pub kick_off_auto_start: bool,
     pub always_log_stdout: bool,
+    pub stopping: StopConditions,
+    pub init_state: InitialState,
+    pub blocklist: Blocklist,
+    /// Named scenarios, the built-in ones unless replaced.
+    pub scenarios: Scenarios,
 }
 
 impl From<&BaseArgs> for BaseConfig {
     fn from(args: &BaseArgs) -> Self {
         let mut ret = Self::default();
-        let timesteps = args.timesteps;
 
-        ret.half_time_auto_start = args.half_time_auto_start.then_some(timesteps / 2);
+        ret.half_time_auto_start = args.half_time_auto_start;
+        ret.kick_off_auto_start = args.kick_off_auto_start;
         ret.always_log_stdout = args.always_log_stdout;
+        ret.stopping = StopConditions {
+            time_up: args.timesteps,
+            goal_l: args.stop_goal_l,
+            goal_r: args.stop_goal_r,
+            play_modes: vec![],
+        };
 
         ret
     }
@@ -21,10 +35,16 @@ impl From<&BaseArgs> for BaseConfig {
 impl From<BaseArgs> for BaseConfig {
     fn from(args: BaseArgs) -> Self {
         let mut ret = Self::default();
-        let timesteps = args.timesteps;
 
-        ret.half_time_auto_start = args.half_time_auto_start.then_some(timesteps);
+        ret.half_time_auto_start = args.half_time_auto_start;
+        ret.kick_off_auto_start = args.kick_off_auto_start;
         ret.always_log_stdout = args.always_log_stdout;
+        ret.stopping = StopConditions {
+            time_up: args.timesteps,
+            goal_l: args.stop_goal_l,
+            goal_r: args.stop_goal_r,
+            play_modes: vec![],
+        };
 
         ret
     }
@@ -33,8 +53,13 @@ impl From<BaseArgs> for BaseConfig {
 impl Default for BaseConfig {
     fn default() -> Self {
         Self {
-            half_time_auto_start: None,
+            half_time_auto_start: false,
+            kick_off_auto_start: false,
             always_log_stdout: true,
+            stopping: StopConditions::default(),
+            init_state: InitialState::default(),
+            blocklist: Blocklist::default(),
+            scenarios: Scenarios::default(),
         }
     }
 }
\ No newline at end of file
@@ -0,0 +1,268 @@
+use std::collections::VecDeque;
+
+use common::types::{Side, Vec2, WorldSnapshot};
+use process::addon::Score;
+
+use crate::Result;
+use super::BaseService;
+use super::training::{Attempt, Frame, Training, TrainingProgress};
+
+/// What counts as a successful attempt at a stage.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub enum Success {
+    /// `side` scored during the attempt.
+    Goal { side: Side },
+    /// The ball is inside the rectangle spanned by `min` and `max`.
+    BallInRegion { min: Vec2, max: Vec2 },
+}
+
+impl Success {
+    /// Whether the predicate holds, `score` counts from the attempt's start.
+    pub fn holds(&self, score: Score, world: Option<&WorldSnapshot>) -> bool {
+        match *self {
+            Success::Goal { side: Side::LEFT } => score.left > 0,
+            Success::Goal { side: Side::RIGHT } => score.right > 0,
+            Success::Goal { side: Side::NEUTRAL } => false,
+            Success::BallInRegion { min, max } => world.is_some_and(|world| {
+                let ball = world.ball.pos;
+                (min.x..=max.x).contains(&ball.x) && (min.y..=max.y).contains(&ball.y)
+            }),
+        }
+    }
+}
+
+/// One step of a curriculum: a named scenario and how to pass it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Stage {
+    pub scenario: String,
+    /// Any of them ends the attempt as a success.
+    pub success: Vec<Success>,
+    /// Cycles an attempt may take before it counts as a failure.
+    pub within: u16,
+}
+
+/// Scenarios of increasing difficulty, moved through on the success rate of
+/// the latest `window` attempts.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Curriculum {
+    pub stages: Vec<Stage>,
+    pub window: usize,
+    /// Move to the next stage at this success rate or above.
+    pub promote: f64,
+    /// Move back a stage at this success rate or below.
+    pub demote: Option<f64>,
+    /// Start over after the last stage instead of staying there.
+    pub repeat: bool,
+}
+
+impl Default for Curriculum {
+    fn default() -> Self {
+        Self {
+            stages: vec![],
+            window: 10,
+            promote: 0.8,
+            demote: Some(0.2),
+            repeat: false,
+        }
+    }
+}
+
+/// Where a curriculum stands, published after every attempt.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct CurriculumProgress {
+    pub stage: usize,
+    pub scenario: String,
+    /// Attempts over all stages.
+    pub attempts: u64,
+    /// Outcomes of the latest attempts at the current stage, oldest first.
+    pub recent: VecDeque<bool>,
+    pub promotions: u32,
+    pub demotions: u32,
+    /// The last stage was passed and the curriculum does not repeat.
+    pub completed: bool,
+    pub running: bool,
+}
+
+impl CurriculumProgress {
+    pub fn success_rate(&self) -> Option<f64> {
+        if self.recent.is_empty() {
+            return None;
+        }
+        let successes = self.recent.iter().filter(|s| **s).count();
+        Some(successes as f64 / self.recent.len() as f64)
+    }
+}
+
+impl Curriculum {
+    /// Rejects curricula that could never promote or would promote and
+    /// demote on the same success rate.
+    pub fn verify(&self) -> std::result::Result<(), &'static str> {
+        if self.stages.is_empty() || self.window == 0 {
+            return Err("at least one stage and a window of one attempt are needed");
+        }
+        if self.stages.iter().any(|stage| stage.within == 0) {
+            return Err("every stage needs at least one cycle per attempt");
+        }
+        let rate = 0.0..=1.0;
+        if !rate.contains(&self.promote) || self.demote.is_some_and(|demote| !rate.contains(&demote)) {
+            return Err("promote and demote are success rates within [0, 1]");
+        }
+        if self.demote.is_some_and(|demote| demote >= self.promote) {
+            return Err("demote must be below promote");
+        }
+        Ok(())
+    }
+    pub(crate) fn start(&self) -> CurriculumProgress {
+        CurriculumProgress {
+            scenario: self.stages.first().map(|s| s.scenario.clone()).unwrap_or_default(),
+            running: true,
+            ..Default::default()
+        }
+    }
+
+    /// Records an attempt and moves between stages once the window is full.
+    pub(crate) fn record(&self, progress: &mut CurriculumProgress, success: bool) {
+        progress.attempts += 1;
+        progress.recent.push_back(success);
+        while progress.recent.len() > self.window {
+            progress.recent.pop_front();
+        }
+        if progress.recent.len() < self.window {
+            return;
+        }
+
+        let rate = progress.success_rate().unwrap_or_default();
+        let last = self.stages.len().saturating_sub(1);
+        let next = if rate >= self.promote {
+            progress.promotions += 1;
+            match progress.stage {
+                stage if stage < last => stage + 1,
+                _ if self.repeat => 0,
+                stage => {
+                    progress.completed = true;
+                    stage
+                }
+            }
+        } else if self.demote.is_some_and(|demote| rate <= demote) && progress.stage > 0 {
+            progress.demotions += 1;
+            progress.stage - 1
+        } else {
+            return;
+        };
+
+        progress.stage = next;
+        progress.scenario = self.stages[next].scenario.clone();
+        progress.recent.clear();
+    }
+}
+
+impl Training for Curriculum {
+    type Turn = Stage;
+    type Outcome = bool;
+
+    async fn next_attempt(&self, service: &BaseService, progress: &TrainingProgress) -> Result<Option<Attempt<Stage>>> {
+        let TrainingProgress::Curriculum(progress) = progress else { return Ok(None) };
+        if progress.completed {
+            return Ok(None);
+        }
+        let stage = self.stages[progress.stage].clone();
+        let episode = service.apply_named_scenario(&stage.scenario).await?;
+        Ok(Some(Attempt { turn: stage, episode, ready: None }))
+    }
+
+    /// A success as soon as one of the stage's predicates holds, a failure
+    /// once its cycles run out.
+    fn judge(&self, attempt: &Attempt<Stage>, frame: &Frame) -> Option<bool> {
+        let stage = &attempt.turn;
+        if stage.success.iter().any(|s| s.holds(frame.score, frame.world)) {
+            return Some(true);
+        }
+        (frame.elapsed() >= stage.within).then_some(false)
+    }
+
+    fn record(&self, progress: &mut TrainingProgress, success: bool) {
+        if let TrainingProgress::Curriculum(progress) = progress {
+            Curriculum::record(self, progress, success);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn stage(scenario: &str) -> Stage {
+        Stage {
+            scenario: scenario.to_string(),
+            success: vec![Success::Goal { side: Side::LEFT }],
+            within: 100,
+        }
+    }
+
+    #[test]
+    fn test_curriculum_promotion_and_demotion() {
+        let curriculum = Curriculum {
+            stages: vec![stage("penalty_kick_l"), stage("breakaway_l")],
+            window: 4,
+            promote: 0.75,
+            demote: Some(0.25),
+            repeat: false,
+        };
+        let mut progress = curriculum.start();
+
+        for success in [true, true, false] {
+            curriculum.record(&mut progress, success);
+        }
+        assert_eq!(progress.stage, 0);
+        curriculum.record(&mut progress, true);
+        assert_eq!((progress.stage, progress.scenario.as_str()), (1, "breakaway_l"));
+        assert!(progress.recent.is_empty());
+
+        for _ in 0..4 {
+            curriculum.record(&mut progress, false);
+        }
+        assert_eq!((progress.stage, progress.demotions), (0, 1));
+
+        for _ in 0..8 {
+            curriculum.record(&mut progress, true);
+        }
+        assert!(progress.completed);
+        assert_eq!((progress.stage, progress.promotions, progress.attempts), (1, 3, 16));
+    }
+
+    #[test]
+    fn test_success_predicates() {
+        let mut world = WorldSnapshot::default();
+        world.ball.pos = Vec2::new(48.0, 3.0);
+        let region = Success::BallInRegion { min: Vec2::new(36.0, -20.0), max: Vec2::new(52.5, 20.0) };
+        assert!(region.holds(Score::default(), Some(&world)));
+        assert!(!region.holds(Score::default(), None));
+
+        let goal = Success::Goal { side: Side::RIGHT };
+        assert!(!goal.holds(Score { left: 1, right: 0 }, Some(&world)));
+        assert!(goal.holds(Score { left: 0, right: 1 }, None));
+    }
+
+    #[test]
+    fn test_verify_curriculum() {
+        let curriculum = Curriculum { stages: vec![stage("penalty_kick_l")], ..Default::default() };
+        assert_eq!(curriculum.verify(), Ok(()));
+        assert_eq!(Curriculum { demote: None, promote: 0.0, ..curriculum.clone() }.verify(), Ok(()));
+
+        let broken = [
+            Curriculum { stages: vec![], ..curriculum.clone() },
+            Curriculum { window: 0, ..curriculum.clone() },
+            Curriculum { stages: vec![Stage { within: 0, ..stage("penalty_kick_l") }], ..curriculum.clone() },
+            // never reached
+            Curriculum { promote: 1.5, ..curriculum.clone() },
+            Curriculum { promote: f64::NAN, ..curriculum.clone() },
+            Curriculum { demote: Some(-0.1), ..curriculum.clone() },
+            // both at once
+            Curriculum { promote: 0.5, demote: Some(0.5), ..curriculum.clone() },
+            Curriculum { promote: 0.2, demote: Some(0.8), ..curriculum.clone() },
+        ];
+        for curriculum in broken {
+            assert!(curriculum.verify().is_err(), "{curriculum:?}");
+        }
+    }
+}
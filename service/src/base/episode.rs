@@ -0,0 +1,54 @@
+use process::addon::Score;
+
+/// One run between resets on the same rcssserver process.
+///
+/// rcssserver's clock and score cannot be rewound, an episode keeps where
+/// they stood at its start and counts from there.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct Episode {
+    /// Starts at 0 for a freshly spawned process.
+    pub id: u64,
+    pub start_time: u16,
+    pub start_score: Score,
+}
+
+impl Episode {
+    /// The episode after this one, starting at `time` with `score`.
+    pub fn next(&self, time: u16, score: Score) -> Self {
+        Self {
+            id: self.id + 1,
+            start_time: time,
+            start_score: score,
+        }
+    }
+
+    /// Cycles since the episode started.
+    pub fn time(&self, time: u16) -> u16 {
+        time.saturating_sub(self.start_time)
+    }
+
+    /// Goals scored since the episode started.
+    pub fn score(&self, score: Score) -> Score {
+        Score {
+            left: score.left.saturating_sub(self.start_score.left),
+            right: score.right.saturating_sub(self.start_score.right),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_episode_offsets() {
+        let first = Episode::default();
+        assert_eq!(first.time(120), 120);
+
+        let second = first.next(500, Score { left: 2, right: 1 });
+        assert_eq!(second.id, 1);
+        assert_eq!(second.time(620), 120);
+        assert_eq!(second.time(10), 0);
+        assert_eq!(second.score(Score { left: 3, right: 1 }), Score { left: 1, right: 0 });
+    }
+}
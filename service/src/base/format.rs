@@ -0,0 +1,190 @@
+use serde::Serialize;
+
+use common::types::PlayMode;
+use process::ProcessConfig;
+use process::addon::Score;
+
+/// rcssserver's `simulator_step` default, in milliseconds.
+const DEFAULT_SIMULATOR_STEP: i32 = 100;
+
+/// Length and structure of a match, in cycles.
+///
+/// Extra halves are only played on a tie after the normal halves, the
+/// penalty shoot-out only on a tie after all of them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct MatchFormat {
+    /// Cycles per normal half, `0` plays without a time limit.
+    pub half_time: u16,
+    pub nr_normal_halfs: u16,
+    /// Cycles per extra half.
+    pub extra_half_time: u16,
+    pub nr_extra_halfs: u16,
+    pub penalty_shoot_outs: bool,
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum Phase {
+    /// Normal half, counting from 1.
+    Regular { half: u16 },
+    /// Extra half, counting from 1.
+    Extra { half: u16 },
+    Penalties,
+}
+
+impl Default for MatchFormat {
+    fn default() -> Self {
+        Self {
+            half_time: 3000,
+            nr_normal_halfs: 2,
+            extra_half_time: 1000,
+            nr_extra_halfs: 2,
+            penalty_shoot_outs: true,
+        }
+    }
+}
+
+impl MatchFormat {
+    /// Reads `half_time`, `extra_half_time` (both in seconds), `nr_normal_halfs`,
+    /// `nr_extra_halfs` and `penalty_shoot_outs`, rcssserver's defaults fill the gaps.
+    pub fn from_config(config: &ProcessConfig) -> Self {
+        let server = &config.server;
+        let default = Self::default();
+
+        let step = server
+            .simulator_step
+            .filter(|step| *step > 0)
+            .unwrap_or(DEFAULT_SIMULATOR_STEP);
+        let cycles = |secs: i32| (i64::from(secs.max(0)) * 1000 / i64::from(step)).min(u16::MAX.into()) as u16;
+        let count = |n: i32| n.clamp(0, u16::MAX.into()) as u16;
+
+        Self {
+            half_time: server.half_time.map_or(default.half_time, cycles),
+            nr_normal_halfs: server.nr_normal_halfs.map_or(default.nr_normal_halfs, count),
+            extra_half_time: server.extra_half_time.map_or(default.extra_half_time, cycles),
+            nr_extra_halfs: server.nr_extra_halfs.map_or(default.nr_extra_halfs, count),
+            penalty_shoot_outs: server.penalty_shoot_outs.unwrap_or(default.penalty_shoot_outs),
+        }
+    }
+
+    fn is_timed(&self) -> bool {
+        self.half_time > 0
+    }
+
+    /// End of the normal halves.
+    pub fn regular_end(&self) -> u16 {
+        self.half_time.saturating_mul(self.nr_normal_halfs)
+    }
+
+    /// End of the extra halves, the same as [`MatchFormat::regular_end`] without any.
+    pub fn extra_end(&self) -> u16 {
+        self.regular_end()
+            .saturating_add(self.extra_half_time.saturating_mul(self.nr_extra_halfs))
+    }
+
+    /// The cycles at which a half ends, in order.
+    pub fn half_ends(&self) -> Vec<u16> {
+        if !self.is_timed() {
+            return Vec::new();
+        }
+        let regular = (1..=self.nr_normal_halfs).map(|n| self.half_time.saturating_mul(n));
+        let extra = (1..=self.nr_extra_halfs)
+            .map(|n| self.regular_end().saturating_add(self.extra_half_time.saturating_mul(n)));
+        regular.chain(extra).collect()
+    }
+
+    /// The phase at `time`, a half's last cycle still belongs to it.
+    pub fn phase(&self, time: u16, play_mode: Option<PlayMode>) -> Phase {
+        if play_mode.is_some_and(is_penalty_mode) {
+            return Phase::Penalties;
+        }
+        if !self.is_timed() || time <= self.regular_end() || self.extra_half_time == 0 {
+            let half = match self.half_time {
+                0 => 1,
+                half_time => time.saturating_sub(1) / half_time + 1,
+            };
+            return Phase::Regular { half: half.min(self.nr_normal_halfs.max(1)) };
+        }
+
+        let half = (time - self.regular_end() - 1) / self.extra_half_time + 1;
+        Phase::Extra { half: half.min(self.nr_extra_halfs.max(1)) }
+    }
+
+    /// Whether no more play follows at `time` with `score`.
+    pub fn is_over(&self, time: u16, score: Score) -> bool {
+        if !self.is_timed() {
+            return false;
+        }
+        let tied = score.left == score.right;
+        if time >= self.regular_end() && !(tied && (self.nr_extra_halfs > 0 || self.penalty_shoot_outs)) {
+            return true;
+        }
+        self.nr_extra_halfs > 0 && time >= self.extra_end() && !(tied && self.penalty_shoot_outs)
+    }
+
+    /// A half ended at `time` and the match goes on, the server waits for a kick-off.
+    pub fn kick_off_due(&self, time: u16, score: Score) -> bool {
+        self.half_ends().contains(&time) && !self.is_over(time, score)
+    }
+}
+
+fn is_penalty_mode(play_mode: PlayMode) -> bool {
+    matches!(
+        play_mode,
+        PlayMode::PM_PenaltySetup_Left
+            | PlayMode::PM_PenaltySetup_Right
+            | PlayMode::PM_PenaltyReady_Left
+            | PlayMode::PM_PenaltyReady_Right
+            | PlayMode::PM_PenaltyTaken_Left
+            | PlayMode::PM_PenaltyTaken_Right
+            | PlayMode::PM_PenaltyMiss_Left
+            | PlayMode::PM_PenaltyMiss_Right
+            | PlayMode::PM_PenaltyScore_Left
+            | PlayMode::PM_PenaltyScore_Right
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_from_config() {
+        let mut config = ProcessConfig::default();
+        assert_eq!(MatchFormat::from_config(&config), MatchFormat::default());
+
+        config.server_then(|s| {
+            s.half_time(60).nr_normal_halfs(1).nr_extra_halfs(0).simulator_step(50);
+        });
+        let format = MatchFormat::from_config(&config);
+        assert_eq!(format.half_time, 1200);
+        assert_eq!(format.regular_end(), 1200);
+        assert_eq!(format.extra_end(), 1200);
+        assert_eq!(format.half_ends(), vec![1200]);
+    }
+
+    #[test]
+    fn test_phases_and_end() {
+        let format = MatchFormat::default();
+        let tied = Score { left: 1, right: 1 };
+        let lead = Score { left: 2, right: 1 };
+
+        assert_eq!(format.half_ends(), vec![3000, 6000, 7000, 8000]);
+        assert_eq!(format.phase(0, None), Phase::Regular { half: 1 });
+        assert_eq!(format.phase(3000, None), Phase::Regular { half: 1 });
+        assert_eq!(format.phase(3001, None), Phase::Regular { half: 2 });
+        assert_eq!(format.phase(6500, None), Phase::Extra { half: 1 });
+        assert_eq!(format.phase(8000, Some(PlayMode::PM_PenaltySetup_Left)), Phase::Penalties);
+
+        assert!(format.kick_off_due(3000, lead));
+        assert!(format.is_over(6000, lead));
+        assert!(!format.kick_off_due(6000, lead));
+        assert!(format.kick_off_due(6000, tied));
+        assert!(format.kick_off_due(8000, tied));
+        assert!(!format.is_over(8000, tied));
+        assert!(format.is_over(8000, lead));
+
+        let no_penalties = MatchFormat { nr_extra_halfs: 0, penalty_shoot_outs: false, ..format };
+        assert!(no_penalties.is_over(6000, tied));
+    }
+}
@@ -0,0 +1,128 @@
+use log::debug;
+
+use common::command::trainer::{self, TrainerCommand};
+use common::scenario::{BallState, PlayerState, Scenario};
+use common::types::{PlayMode, Side, Vec2, WorldSnapshot};
+use process::CommandCaller;
+
+use crate::Result;
+use super::scenario::{call, place};
+
+/// The pitch rcssserver plays on, normalised positions are scaled to it.
+const PITCH_LENGTH: f64 = 105.0;
+const PITCH_WIDTH: f64 = 68.0;
+
+/// A player the match waits for, placed at `pos` when given.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PlayerInit {
+    pub side: Side,
+    pub unum: u8,
+    pub pos: Option<Vec2>,
+    pub stamina: Option<u16>,
+}
+
+/// Where the ball and players stand at kick-off, mirrors the allocator's
+/// `init` declaration.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct InitialState {
+    pub ball: Option<Vec2>,
+    pub players: Vec<PlayerInit>,
+}
+
+impl InitialState {
+    /// Pitch coordinates from normalised ones, `(0, 0)` is where the left
+    /// goal line meets the top touch line and `(1, 1)` the opposite corner.
+    pub fn pitch_pos(x: f64, y: f64) -> Vec2 {
+        Vec2::new((x - 0.5) * PITCH_LENGTH, (y - 0.5) * PITCH_WIDTH)
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.ball.is_none() && self.players.is_empty()
+    }
+
+    /// Whether every listed player is on the pitch, `world` needs its sides
+    /// assigned. With no players listed, such as a ball-only state, there is
+    /// no roster to wait for and both teams having a player on the pitch will do.
+    pub fn all_connected(&self, world: &WorldSnapshot) -> bool {
+        if self.players.is_empty() {
+            return [Side::LEFT, Side::RIGHT].iter()
+                .all(|side| world.players.iter().any(|p| p.side == Some(*side)));
+        }
+        self.players.iter().all(|p| world.player(p.side, p.unum).is_some())
+    }
+
+    /// Moves everything into place and recovers every player, the play mode
+    /// is left as it is.
+    pub(crate) async fn apply(&self, caller: &CommandCaller<TrainerCommand>) -> Result<()> {
+        place(&Scenario::from(self), caller).await?;
+        debug!("[InitialState] Applied: {self:?}");
+        Ok(())
+    }
+
+    /// [`InitialState::apply`] followed by `start`.
+    pub(crate) async fn kick_off(&self, caller: &CommandCaller<TrainerCommand>) -> Result<()> {
+        self.apply(caller).await?;
+        call(caller, trainer::Start).await
+    }
+}
+
+/// The kick-off situation, players without a position are left out as there
+/// is nothing to move them to.
+impl From<&InitialState> for Scenario {
+    fn from(init: &InitialState) -> Self {
+        Self {
+            description: None,
+            play_mode: PlayMode::PM_BeforeKickOff,
+            cycle: None,
+            ball: init.ball.map(|pos| BallState { pos, vel: None }),
+            players: init.players.iter()
+                .filter_map(|p| Some(PlayerState { side: p.side, unum: p.unum, pos: p.pos?, dir: None, stamina: p.stamina }))
+                .collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use common::command::Command;
+
+    use super::*;
+
+    #[test]
+    fn test_initial_state_moves() {
+        let init = InitialState {
+            ball: Some(InitialState::pitch_pos(0.5, 0.5)),
+            players: vec![
+                PlayerInit { side: Side::LEFT, unum: 1, pos: Some(InitialState::pitch_pos(0.0, 0.5)), stamina: None },
+                PlayerInit { side: Side::RIGHT, unum: 9, pos: None, stamina: Some(4000) },
+            ],
+        };
+
+        let moves: Vec<_> = Scenario::from(&init).moves("HELIOS", "CYRUS").iter().map(|m| m.encode()).collect();
+        assert_eq!(moves, vec!["(move (ball) 0 0)", "(move (player HELIOS 1) -52.5 0)"]);
+
+        let mut world = WorldSnapshot::default();
+        assert!(!init.all_connected(&world));
+        world.players = ["HELIOS", "CYRUS"].iter().zip([1, 9]).map(|(team, unum)| {
+            common::types::PlayerSnapshot {
+                team: team.to_string(),
+                side: None,
+                unum,
+                goalie: false,
+                pos: Vec2::default(),
+                vel: Vec2::default(),
+                body: 0.0,
+                neck: 0.0,
+                point_dir: None,
+            }
+        }).collect();
+        world.assign_sides(Some("HELIOS"), Some("CYRUS"));
+        assert!(init.all_connected(&world));
+
+        // a ball-only state waits for both teams rather than a roster
+        let ball_only = InitialState { ball: init.ball, players: vec![] };
+        assert!(ball_only.all_connected(&world));
+        world.assign_sides(Some("HELIOS"), None);
+        assert!(!ball_only.all_connected(&world));
+    }
+}
@@ -0,0 +1,208 @@
+use std::path::{Path, PathBuf};
+
+/// Why rcssserver's keepaway referee ended an episode.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum KeepawayEnd {
+    /// The ball left the keepaway region, `o` in the `.kwy` log and
+    /// `out_of_bounds` from the referee.
+    OutOfBounds,
+    /// The takers held the ball long enough, `t` in the `.kwy` log and
+    /// `take_ball` from the referee.
+    Taken,
+}
+
+impl KeepawayEnd {
+    pub fn encode(self) -> &'static str {
+        match self {
+            KeepawayEnd::OutOfBounds => "o",
+            KeepawayEnd::Taken => "t",
+        }
+    }
+
+    pub fn decode(s: &str) -> Option<Self> {
+        match s {
+            "o" => Some(KeepawayEnd::OutOfBounds),
+            "t" => Some(KeepawayEnd::Taken),
+            _ => None,
+        }
+    }
+
+    /// The referee message announcing the end.
+    pub fn from_referee(message: &str) -> Option<Self> {
+        match message {
+            "out_of_bounds" => Some(KeepawayEnd::OutOfBounds),
+            "take_ball" => Some(KeepawayEnd::Taken),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct KeepawayEpisode {
+    /// Starts at 1 like the `.kwy` log.
+    pub id: u32,
+    pub start: u16,
+    pub end: u16,
+    pub end_reason: KeepawayEnd,
+}
+
+impl KeepawayEpisode {
+    /// Cycles the keepers held the ball.
+    pub fn duration(&self) -> u16 {
+        self.end.saturating_sub(self.start)
+    }
+}
+
+/// Keepaway episodes of a process, followed from the trainer's referee messages.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct KeepawayStats {
+    pub episodes: Vec<KeepawayEpisode>,
+    /// When the episode in progress started, if one is.
+    pub current_start: Option<u16>,
+}
+
+impl KeepawayStats {
+    /// Opens an episode unless one is in progress.
+    pub(crate) fn start(&mut self, time: u16) {
+        self.current_start.get_or_insert(time);
+    }
+
+    /// Closes the episode in progress, the next one starts right away.
+    pub(crate) fn end(&mut self, time: u16, end_reason: KeepawayEnd) -> Option<KeepawayEpisode> {
+        let start = self.current_start?;
+        self.current_start = Some(time);
+        let episode = KeepawayEpisode {
+            id: self.episodes.len() as u32 + 1,
+            start,
+            end: time,
+            end_reason,
+        };
+        self.episodes.push(episode);
+        Some(episode)
+    }
+
+    pub fn mean_duration(&self) -> Option<f64> {
+        mean_duration(&self.episodes)
+    }
+}
+
+/// A `.kwy` log rcssserver writes with `keepaway_logging` on.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct KeepawayLog {
+    pub keepers: Option<u8>,
+    pub takers: Option<u8>,
+    /// Length and width of the region.
+    pub region: Option<(f64, f64)>,
+    pub episodes: Vec<KeepawayEpisode>,
+}
+
+impl KeepawayLog {
+    /// Parses the `# Keepers: 3` style header and one tab separated
+    /// `EPISODE START END DURATION o|t` line per episode, other lines are skipped.
+    pub fn parse(content: &str) -> Self {
+        let mut ret = Self::default();
+        for line in content.lines().map(str::trim) {
+            if let Some(header) = line.strip_prefix('#') {
+                let Some((key, value)) = header.split_once(':') else { continue };
+                let value = value.trim();
+                match key.trim() {
+                    "Keepers" => ret.keepers = value.parse().ok(),
+                    "Takers" => ret.takers = value.parse().ok(),
+                    "Region" => {
+                        ret.region = value.split_once('x').and_then(|(length, width)| {
+                            Some((length.trim().parse().ok()?, width.trim().parse().ok()?))
+                        })
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            ret.episodes.extend(parse_episode(line));
+        }
+        ret
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> std::io::Result<Self> {
+        Ok(Self::parse(&std::fs::read_to_string(path)?))
+    }
+
+    /// The most recently modified `.kwy` file in `dir`.
+    pub fn latest_in(dir: impl AsRef<Path>) -> std::io::Result<Option<PathBuf>> {
+        let mut latest = None;
+        for entry in std::fs::read_dir(dir)? {
+            let entry = entry?;
+            let path = entry.path();
+            if path.extension().and_then(|e| e.to_str()) != Some("kwy") {
+                continue;
+            }
+            let modified = entry.metadata()?.modified()?;
+            if latest.as_ref().is_none_or(|(time, _)| modified > *time) {
+                latest = Some((modified, path));
+            }
+        }
+        Ok(latest.map(|(_, path)| path))
+    }
+
+    pub fn mean_duration(&self) -> Option<f64> {
+        mean_duration(&self.episodes)
+    }
+}
+
+fn parse_episode(line: &str) -> Option<KeepawayEpisode> {
+    let fields: Vec<_> = line.split_whitespace().collect();
+    let [id, start, end, _duration, end_reason] = fields[..] else { return None };
+    Some(KeepawayEpisode {
+        id: id.parse().ok()?,
+        start: start.parse().ok()?,
+        end: end.parse().ok()?,
+        end_reason: KeepawayEnd::decode(end_reason)?,
+    })
+}
+
+fn mean_duration(episodes: &[KeepawayEpisode]) -> Option<f64> {
+    if episodes.is_empty() {
+        return None;
+    }
+    let total: f64 = episodes.iter().map(|e| e.duration() as f64).sum();
+    Some(total / episodes.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_kwy_log() {
+        let log = KeepawayLog::parse("\
+# Keepaway Log
+# Keepers: 3
+# Takers:  2
+# Region:  20 x 20
+#
+# Description of Fields:
+# 1) Episode number
+# 5) (o)ut of bounds / (t)aken away
+#
+1\t0\t91\t91\tt
+2\t91\t150\t59\to
+3\t150
+");
+        assert_eq!((log.keepers, log.takers, log.region), (Some(3), Some(2), Some((20.0, 20.0))));
+        assert_eq!(log.episodes.len(), 2);
+        assert_eq!(log.episodes[1], KeepawayEpisode { id: 2, start: 91, end: 150, end_reason: KeepawayEnd::OutOfBounds });
+        assert_eq!(log.mean_duration(), Some(75.0));
+    }
+
+    #[test]
+    fn test_keepaway_stats() {
+        let mut stats = KeepawayStats::default();
+        assert_eq!(stats.end(10, KeepawayEnd::Taken), None);
+
+        stats.start(20);
+        stats.start(25);
+        let first = stats.end(60, KeepawayEnd::Taken).unwrap();
+        assert_eq!((first.id, first.duration()), (1, 40));
+        assert_eq!(stats.current_start, Some(60));
+    }
+}
@@ -3,10 +3,30 @@ mod status;
 mod process;
 mod args;
 mod config;
+mod stop;
+mod format;
+mod init;
+mod blocklist;
+mod episode;
+mod scenario;
+mod curriculum;
+mod keepaway;
+mod penalty;
+mod training;
 
-use process::AddonProcess;
+pub(crate) use process::AddonProcess;
 
 pub use status::ServerStatus;
 pub use base::BaseService;
 pub use args::BaseArgs;
-pub use config::BaseConfig;
\ No newline at end of file
+pub use config::BaseConfig;
+pub use stop::{StopConditions, StopReason};
+pub use format::{MatchFormat, Phase};
+pub use init::{InitialState, PlayerInit};
+pub use blocklist::{ActionList, Blocklist};
+pub use episode::Episode;
+pub use scenario::Scenarios;
+pub use curriculum::{Curriculum, CurriculumProgress, Stage, Success};
+pub use keepaway::{KeepawayEnd, KeepawayEpisode, KeepawayLog, KeepawayStats};
+pub use penalty::{PenaltyAttempt, PenaltyOutcome, PenaltyProgress, PenaltyTraining};
+pub use training::TrainingProgress;
\ No newline at end of file
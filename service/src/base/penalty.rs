@@ -0,0 +1,314 @@
+use common::scenario::Scenario;
+use common::command::trainer;
+use common::types::{PlayMode, Side, WorldSnapshot};
+use process::addon::Score;
+
+use crate::Result;
+use super::BaseService;
+use super::scenario::call;
+use super::training::{Attempt, Frame, Training, TrainingProgress};
+
+/// Half the distance between the posts, rcssserver's `goal_width` is 14.02.
+const GOAL_HALF_WIDTH: f64 = 7.01;
+/// Half the pitch length, the goal line.
+const GOAL_LINE_X: f64 = 52.5;
+/// Distance to the goalie within which a ball coming back counts as saved.
+const SAVE_DISTANCE: f64 = 2.0;
+
+/// How a penalty attempt ended.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PenaltyOutcome {
+    Goal,
+    /// The goalie caught or parried the ball.
+    Save,
+    /// The ball went out or rcssserver called the penalty missed.
+    Miss,
+    /// The attempt ran out of cycles without any of the above.
+    Timeout,
+}
+
+impl PenaltyOutcome {
+    /// Judges the attempt of `side` from the play mode, the score counted from
+    /// the attempt's start and the latest `see_global` frame.
+    pub fn judge(side: Side, play_mode: Option<PlayMode>, score: Score, world: Option<&WorldSnapshot>) -> Option<Self> {
+        let (scored, penalty_score, penalty_miss, caught, goal_kick) = match side {
+            Side::LEFT => (
+                score.left > 0,
+                PlayMode::PM_PenaltyScore_Left,
+                PlayMode::PM_PenaltyMiss_Left,
+                PlayMode::PM_FreeKick_Right,
+                PlayMode::PM_GoalKick_Right,
+            ),
+            Side::RIGHT => (
+                score.right > 0,
+                PlayMode::PM_PenaltyScore_Right,
+                PlayMode::PM_PenaltyMiss_Right,
+                PlayMode::PM_FreeKick_Left,
+                PlayMode::PM_GoalKick_Left,
+            ),
+            Side::NEUTRAL => return None,
+        };
+        if scored || play_mode == Some(penalty_score) {
+            return Some(PenaltyOutcome::Goal);
+        }
+        // a goalie catch restarts with the defending side's free kick
+        if play_mode == Some(caught) {
+            return Some(PenaltyOutcome::Save);
+        }
+        if play_mode == Some(penalty_miss) || play_mode == Some(goal_kick) {
+            return Some(PenaltyOutcome::Miss);
+        }
+
+        let world = world?;
+        let attack = if side == Side::LEFT { 1.0 } else { -1.0 };
+        let ball = world.ball;
+        if ball.pos.x * attack > GOAL_LINE_X || ball.pos.y.abs() > 34.0 {
+            // out past the posts or over the touch line, inside them counts once the score does
+            return (ball.pos.y.abs() > GOAL_HALF_WIDTH).then_some(PenaltyOutcome::Miss);
+        }
+        let parried = world.players.iter()
+            .filter(|p| p.goalie && p.pos.x * attack > 0.0)
+            .any(|goalie| {
+                let distance = (ball.pos.x - goalie.pos.x).hypot(ball.pos.y - goalie.pos.y);
+                distance <= SAVE_DISTANCE && ball.vel.x * attack < 0.0
+            });
+        parried.then_some(PenaltyOutcome::Save)
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PenaltyAttempt {
+    /// Starts at 1.
+    pub id: u32,
+    /// The kicking side.
+    pub side: Side,
+    pub kicker: u8,
+    pub start: u16,
+    pub end: u16,
+    pub outcome: PenaltyOutcome,
+}
+
+/// Repeated penalty kicks, taking turns between sides and rotating through
+/// each side's kickers.
+#[derive(Clone, Debug, PartialEq)]
+pub struct PenaltyTraining {
+    /// Kickers of the left side in order, none leaves the side out.
+    pub kickers_l: Vec<u8>,
+    pub kickers_r: Vec<u8>,
+    pub goalie_l: u8,
+    pub goalie_r: u8,
+    /// Switch sides after every attempt instead of after all of one side's.
+    pub alternate: bool,
+    /// Stop after this many attempts, run until stopped when `None`.
+    pub attempts: Option<u32>,
+    /// Cycles from the kick being allowed, the first frame in `penalty_ready`,
+    /// to a timeout, rcssserver's `pen_taken_wait` by default. Counted from
+    /// the setup while the ready play mode has not shown up.
+    pub within: u16,
+}
+
+impl Default for PenaltyTraining {
+    fn default() -> Self {
+        Self {
+            kickers_l: vec![10],
+            kickers_r: vec![10],
+            goalie_l: 1,
+            goalie_r: 1,
+            alternate: true,
+            attempts: None,
+            within: 150,
+        }
+    }
+}
+
+/// Attempts of a penalty training, published after every attempt.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct PenaltyProgress {
+    pub attempts: Vec<PenaltyAttempt>,
+    /// All attempts were taken.
+    pub completed: bool,
+    pub running: bool,
+}
+
+impl PenaltyProgress {
+    pub fn count(&self, side: Side, outcome: PenaltyOutcome) -> usize {
+        self.attempts.iter().filter(|a| a.side == side && a.outcome == outcome).count()
+    }
+}
+
+impl PenaltyTraining {
+    pub(crate) fn start(&self) -> PenaltyProgress {
+        PenaltyProgress {
+            running: true,
+            ..Default::default()
+        }
+    }
+
+    /// The kicking side and kicker of the attempt after `taken` attempts.
+    pub fn turn(&self, taken: usize) -> Option<(Side, u8)> {
+        let (l, r) = (self.kickers_l.len(), self.kickers_r.len());
+        let (side, nth) = match (l, r) {
+            (0, 0) => return None,
+            (_, 0) => (Side::LEFT, taken),
+            (0, _) => (Side::RIGHT, taken),
+            _ if self.alternate => match taken % 2 {
+                0 => (Side::LEFT, taken / 2),
+                _ => (Side::RIGHT, taken / 2),
+            },
+            _ => match taken % (l + r) {
+                nth if nth < l => (Side::LEFT, nth),
+                nth => (Side::RIGHT, nth - l),
+            },
+        };
+        let kicker = match side {
+            Side::LEFT => self.kickers_l[nth % l],
+            _ => self.kickers_r[nth % r],
+        };
+        Some((side, kicker))
+    }
+
+    /// The kicker behind the ball on the penalty spot and the opposing goalie
+    /// on the line, in `penalty_setup`.
+    pub fn setup(&self, side: Side, kicker: u8) -> Scenario {
+        let (goalie, play_mode) = match side {
+            Side::RIGHT => (self.goalie_l, PlayMode::PM_PenaltySetup_Right),
+            _ => (self.goalie_r, PlayMode::PM_PenaltySetup_Left),
+        };
+        let mut setup = Scenario::penalty_kick(side);
+        setup.description = Some(format!("Penalty training, kicker {kicker}"));
+        setup.play_mode = play_mode;
+        for player in &mut setup.players {
+            player.unum = if player.side == side { kicker } else { goalie };
+        }
+        setup
+    }
+
+    /// The play mode that lets the kicker go once the setup is in place.
+    pub fn ready(side: Side) -> PlayMode {
+        match side {
+            Side::RIGHT => PlayMode::PM_PenaltyReady_Right,
+            _ => PlayMode::PM_PenaltyReady_Left,
+        }
+    }
+
+    pub(crate) fn record(&self, progress: &mut PenaltyProgress, mut attempt: PenaltyAttempt) {
+        attempt.id = progress.attempts.len() as u32 + 1;
+        progress.attempts.push(attempt);
+        if self.attempts.is_some_and(|n| progress.attempts.len() >= n as usize) {
+            progress.completed = true;
+        }
+    }
+}
+
+impl Training for PenaltyTraining {
+    /// The kicking side and kicker.
+    type Turn = (Side, u8);
+    type Outcome = PenaltyAttempt;
+
+    async fn next_attempt(&self, service: &BaseService, progress: &TrainingProgress) -> Result<Option<Attempt<(Side, u8)>>> {
+        let TrainingProgress::Penalty(progress) = progress else { return Ok(None) };
+        if progress.completed {
+            return Ok(None);
+        }
+        let Some((side, kicker)) = self.turn(progress.attempts.len()) else { return Ok(None) };
+
+        let episode = service.apply_scenario(&self.setup(side, kicker)).await?;
+        let ready = Self::ready(side);
+        call(&service.trainer_command_sender().await?, trainer::ChangeMode { play_mode: ready }).await?;
+        Ok(Some(Attempt { turn: (side, kicker), episode, ready: Some(ready) }))
+    }
+
+    fn judge(&self, attempt: &Attempt<(Side, u8)>, frame: &Frame) -> Option<PenaltyAttempt> {
+        let (side, kicker) = attempt.turn;
+        let outcome = PenaltyOutcome::judge(side, frame.play_mode, frame.score, frame.world)
+            .or_else(|| {
+                let waited = frame.since_ready().unwrap_or_else(|| frame.elapsed());
+                (waited >= self.within).then_some(PenaltyOutcome::Timeout)
+            })?;
+        Some(PenaltyAttempt { id: 0, side, kicker, start: frame.episode.start_time, end: frame.time, outcome })
+    }
+
+    fn record(&self, progress: &mut TrainingProgress, attempt: PenaltyAttempt) {
+        if let TrainingProgress::Penalty(progress) = progress {
+            PenaltyTraining::record(self, progress, attempt);
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::types::{PlayerSnapshot, Vec2};
+    use crate::Episode;
+
+    #[test]
+    fn test_penalty_turns_and_setup() {
+        let training = PenaltyTraining {
+            kickers_l: vec![9, 10],
+            kickers_r: vec![7],
+            ..Default::default()
+        };
+        let turns: Vec<_> = (0..4).filter_map(|n| training.turn(n)).collect();
+        assert_eq!(turns, vec![(Side::LEFT, 9), (Side::RIGHT, 7), (Side::LEFT, 10), (Side::RIGHT, 7)]);
+
+        let blocks = PenaltyTraining { alternate: false, ..training.clone() };
+        let turns: Vec<_> = (0..4).filter_map(|n| blocks.turn(n)).collect();
+        assert_eq!(turns, vec![(Side::LEFT, 9), (Side::LEFT, 10), (Side::RIGHT, 7), (Side::LEFT, 9)]);
+
+        let setup = training.setup(Side::RIGHT, 7);
+        assert_eq!(setup.play_mode, PlayMode::PM_PenaltySetup_Right);
+        assert_eq!(setup.ball.unwrap().pos, Vec2::new(-41.5, 0.0));
+        let placed: Vec<_> = setup.players.iter().map(|p| (p.side, p.unum)).collect();
+        assert_eq!(placed, vec![(Side::RIGHT, 7), (Side::LEFT, 1)]);
+
+        let once = PenaltyTraining { attempts: Some(1), ..training };
+        let mut progress = once.start();
+        let attempt = PenaltyAttempt { id: 0, side: Side::LEFT, kicker: 9, start: 10, end: 30, outcome: PenaltyOutcome::Goal };
+        once.record(&mut progress, attempt);
+        assert!(progress.completed);
+        assert_eq!((progress.attempts[0].id, progress.count(Side::LEFT, PenaltyOutcome::Goal)), (1, 1));
+    }
+
+    #[test]
+    fn test_judge_penalty_outcome() {
+        let none = Score::default();
+        assert_eq!(PenaltyOutcome::judge(Side::LEFT, None, Score { left: 1, right: 0 }, None), Some(PenaltyOutcome::Goal));
+        assert_eq!(PenaltyOutcome::judge(Side::RIGHT, Some(PlayMode::PM_FreeKick_Left), none, None), Some(PenaltyOutcome::Save));
+        assert_eq!(PenaltyOutcome::judge(Side::LEFT, Some(PlayMode::PM_GoalKick_Right), none, None), Some(PenaltyOutcome::Miss));
+
+        let mut world = WorldSnapshot::default();
+        world.ball.pos = Vec2::new(53.0, 9.0);
+        assert_eq!(PenaltyOutcome::judge(Side::LEFT, Some(PlayMode::PM_PenaltyTaken_Left), none, Some(&world)), Some(PenaltyOutcome::Miss));
+
+        world.ball.pos = Vec2::new(50.5, 1.0);
+        world.ball.vel = Vec2::new(-1.2, 0.3);
+        world.players.push(PlayerSnapshot {
+            team: "R".to_string(),
+            side: None,
+            unum: 1,
+            goalie: true,
+            pos: Vec2::new(51.0, 0.5),
+            vel: Vec2::default(),
+            body: 180.0,
+            neck: 0.0,
+            point_dir: None,
+        });
+        assert_eq!(PenaltyOutcome::judge(Side::LEFT, None, none, Some(&world)), Some(PenaltyOutcome::Save));
+        assert_eq!(PenaltyOutcome::judge(Side::RIGHT, None, none, Some(&world)), None);
+    }
+
+    #[test]
+    fn test_timeout_from_ready() {
+        let training = PenaltyTraining { within: 100, ..Default::default() };
+        let episode = Episode { id: 1, start_time: 200, start_score: Score::default() };
+        let attempt = Attempt { turn: (Side::LEFT, 10), episode, ready: Some(PenaltyTraining::ready(Side::LEFT)) };
+        let frame = |time, ready_time| Frame { episode, time, score: Score::default(), play_mode: None, world: None, ready_time };
+
+        // the setup and the wait for penalty_ready do not count
+        assert_eq!(training.judge(&attempt, &frame(305, Some(210))), None);
+        let timeout = training.judge(&attempt, &frame(310, Some(210))).unwrap();
+        assert_eq!((timeout.start, timeout.end, timeout.outcome), (200, 310, PenaltyOutcome::Timeout));
+        // never ready, counted from the setup
+        assert_eq!(training.judge(&attempt, &frame(300, None)).unwrap().outcome, PenaltyOutcome::Timeout);
+    }
+}
@@ -1,16 +1,21 @@
 use log::info;
-use tokio::sync::{watch};
+use tokio::sync::{broadcast, watch};
 use crate::addons;
 use crate::{Error, Result};
+use super::KeepawayStats;
 
-use common::command::trainer::TrainerCommand;
+use common::command::trainer::{self, TrainerCommand};
 use common::command::{Command, CommandResult};
-use process::{CoachedProcess, CoachedProcessSpawner, CommandCaller, ProcessStatus};
+use common::types::{EarMode, EyeMode, PlayMode, WorldSnapshot};
+use process::addon::{Addon, Score, TrainerEvent};
+use process::{CoachedProcess, CoachedProcessSpawner, CommandCaller, Ports, ProcessStatus};
 
 #[derive(Debug)]
 pub struct AddonProcess {
     process: CoachedProcess,
-    time_rx: watch::Receiver<Option<u16>>,
+    time: addons::TimeStatusAddon,
+    play_mode: addons::PlayModeStatusAddon,
+    keepaway: Option<addons::KeepawayAddon>,
 }
 
 impl AddonProcess {
@@ -24,16 +29,32 @@ impl AddonProcess {
             .map_err(|e| Error::ProcessSpawnFailed(e))?;
         info!("[AddonProcess] Process spawned");
 
-        Ok(Self::from_coached_process(process))
+        Self::from_coached_process(process).await
     }
 
-    pub fn from_coached_process(process: CoachedProcess) -> Self {
-        let time_rx = process
-            .coach()
-            .add_caller_addon::<addons::TimeStatusAddon>("time");
+    /// Turns on the trainer's eye and ear, the addons follow the match from the
+    /// `see_global` and referee traffic this enables.
+    pub async fn from_coached_process(process: CoachedProcess) -> Result<Self> {
+        let coach = process.coach();
+        let see_global = coach.see_global();
+        let events = coach.events();
+
+        coach.call(trainer::Eye { mode: EyeMode::On }).await
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?;
+        coach.call(trainer::Ear { mode: EarMode::On }).await
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?;
+        let Ok(ball) = coach.call(trainer::CheckBall).await
+            .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?;
+
+        let time = addons::TimeStatusAddon::new(Some(ball.time), see_global, events.resubscribe());
         info!("[AddonProcess] Time status addon registered");
+        // a freshly spawned server waits for the kick-off
+        let play_mode = addons::PlayModeStatusAddon::new(Some(PlayMode::PM_BeforeKickOff), events);
+        info!("[AddonProcess] Play mode status addon registered");
 
-        Self { process, time_rx }
+        Ok(Self { process, time, play_mode, keepaway: None })
     }
 
     pub async fn send_trainer_command<C: Command<Kind = TrainerCommand>>(
@@ -49,14 +70,66 @@ impl AddonProcess {
     }
 
     pub fn time_watch(&self) -> watch::Receiver<Option<u16>> {
-        self.time_rx.clone()
+        self.time.watcher()
     }
 
     pub fn time(&self) -> Option<u16> {
-        *self.time_rx.borrow()
+        self.time.time()
+    }
+
+    pub fn play_mode_watch(&self) -> watch::Receiver<Option<PlayMode>> {
+        self.play_mode.play_mode_watcher()
+    }
+
+    pub fn play_mode(&self) -> Option<PlayMode> {
+        self.play_mode.play_mode()
+    }
+
+    pub fn score_watch(&self) -> watch::Receiver<Score> {
+        self.play_mode.score_watcher()
+    }
+
+    pub fn score(&self) -> Score {
+        self.play_mode.score()
+    }
+
+    /// The latest `see_global` frame.
+    pub fn world_watch(&self) -> watch::Receiver<Option<WorldSnapshot>> {
+        self.process.coach().see_global()
+    }
+
+    /// The ports picked at spawn, see [`CoachedProcess::ports`].
+    pub fn ports(&self) -> Option<Ports> {
+        self.process.ports()
+    }
+
+    /// Follows keepaway episodes from here on, for servers started with `server::keepaway`.
+    pub fn track_keepaway(&mut self) {
+        if self.keepaway.is_none() {
+            self.keepaway = Some(addons::KeepawayAddon::new(self.trainer_events()));
+            info!("[AddonProcess] Keepaway addon registered");
+        }
+    }
+
+    pub fn keepaway_watch(&self) -> Option<watch::Receiver<KeepawayStats>> {
+        self.keepaway.as_ref().map(|k| k.watcher())
+    }
+
+    pub fn keepaway_stats(&self) -> Option<KeepawayStats> {
+        self.keepaway.as_ref().map(|k| k.stats())
+    }
+
+    /// Referee messages and players' `say`, see [`TrainerEvent`].
+    pub fn trainer_events(&self) -> broadcast::Receiver<TrainerEvent> {
+        self.process.coach().events()
     }
 
     pub async fn shutdown(&mut self) -> Result<()> {
+        self.time.close();
+        self.play_mode.close();
+        if let Some(keepaway) = &self.keepaway {
+            keepaway.close();
+        }
         self.process.shutdown().await
             .map_err(|e| Error::ProcessFailedToShutdown)?;
         Ok(())
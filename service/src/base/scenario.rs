@@ -0,0 +1,160 @@
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use log::{debug, warn};
+
+use common::command::Command;
+use common::command::trainer::{self, TrainerCommand};
+use common::scenario::{Scenario, ScenarioResult};
+use common::types::{PlayMode, Side};
+use process::CommandCaller;
+
+use crate::{Error, Result};
+
+/// rcssserver's `stamina_max` default, `(recover)` restores every player to it.
+const STAMINA_MAX: u16 = 8000;
+
+/// Scenarios by name, starting with [`Scenario::builtin`].
+#[derive(Clone, Debug, PartialEq)]
+pub struct Scenarios {
+    scenarios: BTreeMap<String, Scenario>,
+}
+
+impl Default for Scenarios {
+    fn default() -> Self {
+        let scenarios = Scenario::builtin().into_iter()
+            .map(|(name, scenario)| (name.to_string(), scenario))
+            .collect();
+        Self { scenarios }
+    }
+}
+
+impl Scenarios {
+    pub fn insert(&mut self, name: String, scenario: Scenario) -> Option<Scenario> {
+        self.scenarios.insert(name, scenario)
+    }
+
+    pub fn remove(&mut self, name: &str) -> Option<Scenario> {
+        self.scenarios.remove(name)
+    }
+
+    pub fn get(&self, name: &str) -> Option<&Scenario> {
+        self.scenarios.get(name)
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &Scenario)> + '_ {
+        self.scenarios.iter().map(|(name, scenario)| (name.as_str(), scenario))
+    }
+
+    /// Registers every `.toml` and `.json` file in `dir` by its file stem,
+    /// files that fail to parse are skipped with a warning.
+    pub fn load_dir(&mut self, dir: impl AsRef<Path>) -> ScenarioResult<usize> {
+        let mut loaded = 0;
+        for entry in std::fs::read_dir(dir)? {
+            let path = entry?.path();
+            let Some(name) = path.file_stem().and_then(|s| s.to_str()) else {
+                continue;
+            };
+            if !matches!(path.extension().and_then(|e| e.to_str()), Some("toml" | "json")) {
+                continue;
+            }
+
+            match Scenario::load(&path) {
+                Ok(scenario) => {
+                    debug!("[Scenarios] Loaded '{name}' from {}", path.display());
+                    self.scenarios.insert(name.to_string(), scenario);
+                    loaded += 1;
+                }
+                Err(e) => warn!("[Scenarios] Skipped {}: {e}", path.display()),
+            }
+        }
+        Ok(loaded)
+    }
+}
+
+/// Sets `scenario` up in one go: pauses, moves the ball and players, recovers
+/// them and resumes in the scenario's play mode.
+pub(crate) async fn execute(scenario: &Scenario, caller: &CommandCaller<TrainerCommand>) -> Result<()> {
+    call(caller, trainer::ChangeMode { play_mode: PlayMode::PM_Pause }).await?;
+    place(scenario, caller).await?;
+    call(caller, trainer::ChangeMode { play_mode: scenario.play_mode }).await?;
+
+    debug!("[Scenario] Executed: {scenario:?}");
+    Ok(())
+}
+
+/// Moves the ball and players of `scenario` into place and recovers every
+/// player, the play mode is left as it is. Fails before moving anything if a
+/// side with players to place has no team connected.
+pub(crate) async fn place(scenario: &Scenario, caller: &CommandCaller<TrainerCommand>) -> Result<()> {
+    let (team_l, team_r) = if scenario.players.is_empty() {
+        Default::default()
+    } else {
+        let names = call(caller, trainer::TeamNames).await?;
+        (team_name(scenario, Side::LEFT, names.left)?, team_name(scenario, Side::RIGHT, names.right)?)
+    };
+    for cmd in scenario.moves(&team_l, &team_r) {
+        call(caller, cmd).await?;
+    }
+
+    // rcssserver has no per-player stamina command, recover is all it offers
+    for player in scenario.players.iter().filter(|p| p.stamina.is_some_and(|s| s < STAMINA_MAX)) {
+        warn!("[Scenario] Stamina of {:?} {} can only be recovered to the maximum", player.side, player.unum);
+    }
+    call(caller, trainer::Recover).await
+}
+
+/// The team on `side`, only needed if `scenario` places players there.
+fn team_name(scenario: &Scenario, side: Side, name: Option<String>) -> Result<String> {
+    let placed = scenario.players.iter().any(|p| (p.side == Side::LEFT) == (side == Side::LEFT));
+    match name {
+        Some(name) => Ok(name),
+        None if placed => Err(Error::TeamNotConnected { side }),
+        None => Ok(String::new()),
+    }
+}
+
+pub(crate) async fn call<C>(caller: &CommandCaller<TrainerCommand>, command: C) -> Result<C::Ok>
+where C: Command<Kind = TrainerCommand> {
+    caller.call(command).await
+        .map_err(|e| Error::TrainerCommandFailed(e.to_string()))?
+        .map_err(|e| Error::TrainerCommandFailed(e.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use common::scenario::PlayerState;
+    use common::types::Vec2;
+
+    #[test]
+    fn test_load_scenario_dir() {
+        let dir = std::env::temp_dir().join(format!("scenarios-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        std::fs::write(dir.join("kick_in.toml"), "play_mode = \"kick_in_l\"\n[ball]\npos = { x = 0.0, y = -34.0 }\n").unwrap();
+        std::fs::write(dir.join("broken.json"), "{").unwrap();
+        std::fs::write(dir.join("notes.txt"), "ignored").unwrap();
+
+        let mut scenarios = Scenarios::default();
+        assert!(scenarios.get("penalty_kick_l").is_some());
+        assert_eq!(scenarios.load_dir(&dir).unwrap(), 1);
+        assert_eq!(scenarios.get("kick_in").unwrap().play_mode, PlayMode::PM_KickIn_Left);
+        assert!(scenarios.get("broken").is_none());
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_team_name_of_placed_sides() {
+        let player = PlayerState { side: Side::LEFT, unum: 9, pos: Vec2::new(0.0, 0.0), dir: None, stamina: None };
+        let scenario = Scenario { players: vec![player], ..Default::default() };
+
+        assert_eq!(team_name(&scenario, Side::LEFT, Some("A".to_string())).unwrap(), "A");
+        assert!(matches!(
+            team_name(&scenario, Side::LEFT, None),
+            Err(Error::TeamNotConnected { side: Side::LEFT })
+        ));
+        // nobody to place on the right, no team needed there
+        assert_eq!(team_name(&scenario, Side::RIGHT, None).unwrap(), "");
+    }
+}
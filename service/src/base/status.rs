@@ -1,5 +1,6 @@
-#[derive(Copy, Clone, Debug)]
+#[derive(Copy, Clone, Debug, serde::Serialize)]
 #[repr(u8)]
+#[serde(rename_all = "snake_case")]
 pub enum ServerStatus {
     Uninitialized,
     Idle,
@@ -0,0 +1,121 @@
+use serde::Serialize;
+
+use common::types::{PlayMode, Side, play_mode_name};
+use process::addon::Score;
+
+/// When a match ends, mirrors the allocator's stopping declaration
+/// (`time_up`, `goal_l`, `goal_r`) plus play modes that end it immediately.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct StopConditions {
+    /// Stop once the cycle reaches this time step.
+    pub time_up: Option<u16>,
+    /// Stop once the left team scored this many goals.
+    pub goal_l: Option<u32>,
+    /// Stop once the right team scored this many goals.
+    pub goal_r: Option<u32>,
+    pub play_modes: Vec<PlayMode>,
+}
+
+/// Why the service moved to [`ServerStatus::Finished`](super::ServerStatus::Finished).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Serialize)]
+#[serde(tag = "kind", rename_all = "snake_case")]
+pub enum StopReason {
+    TimeUp { time: u16 },
+    GoalLimit { side: Side, score: Score },
+    PlayMode {
+        #[serde(with = "play_mode_name")]
+        play_mode: PlayMode,
+    },
+    /// rcssserver ended the match on its own.
+    TimeOver,
+    /// The trainer lost track of the server, e.g. the process exited.
+    Disconnected,
+}
+
+impl StopConditions {
+    pub fn from_time_up(time_up: u16) -> Self {
+        Self {
+            time_up: Some(time_up),
+            ..Default::default()
+        }
+    }
+
+    pub fn check(&self, time: Option<u16>, play_mode: Option<PlayMode>, score: Score) -> Option<StopReason> {
+        if let Some(limit) = self.goal_l
+            && score.left >= limit
+        {
+            return Some(StopReason::GoalLimit { side: Side::LEFT, score });
+        }
+        if let Some(limit) = self.goal_r
+            && score.right >= limit
+        {
+            return Some(StopReason::GoalLimit { side: Side::RIGHT, score });
+        }
+
+        if let Some(play_mode) = play_mode {
+            if self.play_modes.contains(&play_mode) {
+                return Some(StopReason::PlayMode { play_mode });
+            }
+            if play_mode == PlayMode::PM_TimeOver {
+                return Some(StopReason::TimeOver);
+            }
+        }
+
+        if let Some(limit) = self.time_up
+            && let Some(time) = time
+            && time >= limit
+        {
+            return Some(StopReason::TimeUp { time });
+        }
+
+        None
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_stop_conditions() {
+        let stop = StopConditions {
+            time_up: Some(1000),
+            goal_l: Some(1),
+            goal_r: None,
+            play_modes: vec![PlayMode::PM_FreeKick_Left],
+        };
+        let nil = Score::default();
+
+        assert_eq!(stop.check(Some(10), Some(PlayMode::PM_PlayOn), nil), None);
+        assert_eq!(stop.check(Some(1000), Some(PlayMode::PM_PlayOn), nil), Some(StopReason::TimeUp { time: 1000 }));
+        assert_eq!(
+            stop.check(Some(10), Some(PlayMode::PM_FreeKick_Left), nil),
+            Some(StopReason::PlayMode { play_mode: PlayMode::PM_FreeKick_Left })
+        );
+        assert_eq!(stop.check(Some(10), Some(PlayMode::PM_TimeOver), nil), Some(StopReason::TimeOver));
+
+        let score = Score { left: 1, right: 5 };
+        assert_eq!(
+            stop.check(Some(10), Some(PlayMode::PM_AfterGoal_Left), score),
+            Some(StopReason::GoalLimit { side: Side::LEFT, score })
+        );
+        assert_eq!(
+            StopConditions::default().check(Some(9000), None, score),
+            None
+        );
+    }
+
+    #[test]
+    fn test_stop_reason_json() {
+        let json = |reason: StopReason| serde_json::to_string(&reason).unwrap();
+        assert_eq!(
+            json(StopReason::PlayMode { play_mode: PlayMode::PM_FreeKick_Left }),
+            r#"{"kind":"play_mode","play_mode":"free_kick_l"}"#
+        );
+        assert_eq!(
+            json(StopReason::GoalLimit { side: Side::RIGHT, score: Score { left: 0, right: 2 } }),
+            r#"{"kind":"goal_limit","side":"right","score":{"left":0,"right":2}}"#
+        );
+        assert_eq!(json(StopReason::TimeOver), r#"{"kind":"time_over"}"#);
+    }
+}
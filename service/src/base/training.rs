@@ -0,0 +1,299 @@
+//! The one training a [`BaseService`] runs at a time: attempt after attempt,
+//! each set up and judged by its [`Training`], until the training is done or
+//! stopped.
+
+use std::fmt::Debug;
+
+use log::{debug, info};
+use tokio::sync::watch;
+
+use common::types::{PlayMode, WorldSnapshot};
+use process::addon::Score;
+
+use crate::{Error, Result};
+use super::{BaseService, CurriculumProgress, Episode, PenaltyProgress};
+
+/// Progress of the latest training run, published after every attempt.
+#[derive(Clone, Debug, PartialEq)]
+pub enum TrainingProgress {
+    Curriculum(CurriculumProgress),
+    Penalty(PenaltyProgress),
+}
+
+impl TrainingProgress {
+    pub fn running(&self) -> bool {
+        match self {
+            TrainingProgress::Curriculum(p) => p.running,
+            TrainingProgress::Penalty(p) => p.running,
+        }
+    }
+
+    fn finish(&mut self) {
+        match self {
+            TrainingProgress::Curriculum(p) => p.running = false,
+            TrainingProgress::Penalty(p) => p.running = false,
+        }
+    }
+
+    /// Why another run cannot start while this one is running.
+    fn busy(&self) -> Error {
+        match self {
+            TrainingProgress::Curriculum(_) => Error::CurriculumRunning,
+            TrainingProgress::Penalty(_) => Error::PenaltyTrainingRunning,
+        }
+    }
+}
+
+/// One attempt as set up, followed until [`Training::judge`] has an outcome.
+#[derive(Clone, Debug)]
+pub(crate) struct Attempt<T> {
+    /// What the attempt is about, such as the stage or the kicker.
+    pub turn: T,
+    pub episode: Episode,
+    /// The play mode the setup ends in, judging waits until it was seen.
+    pub ready: Option<PlayMode>,
+}
+
+/// What an attempt is judged on, the score counts from its start.
+#[derive(Clone, Copy, Debug)]
+pub(crate) struct Frame<'a> {
+    pub episode: Episode,
+    /// The server cycle, the episode's start until one arrives.
+    pub time: u16,
+    pub score: Score,
+    pub play_mode: Option<PlayMode>,
+    pub world: Option<&'a WorldSnapshot>,
+    /// The server cycle the attempt settled at, see [`Attempt::ready`].
+    pub ready_time: Option<u16>,
+}
+
+impl Frame<'_> {
+    /// Cycles since the attempt started.
+    pub fn elapsed(&self) -> u16 {
+        self.episode.time(self.time)
+    }
+
+    /// Cycles since the attempt settled, `None` until it did.
+    pub fn since_ready(&self) -> Option<u16> {
+        self.ready_time.map(|ready| self.time.saturating_sub(ready))
+    }
+}
+
+/// A kind of training, what sets its attempts up and judges them.
+pub(crate) trait Training {
+    type Turn;
+    type Outcome: Debug;
+
+    /// Sets the attempt after `progress` up, `None` once the training is done.
+    async fn next_attempt(&self, service: &BaseService, progress: &TrainingProgress) -> Result<Option<Attempt<Self::Turn>>>;
+
+    /// The outcome of the attempt as of `frame`, `None` while it goes on.
+    fn judge(&self, attempt: &Attempt<Self::Turn>, frame: &Frame) -> Option<Self::Outcome>;
+
+    fn record(&self, progress: &mut TrainingProgress, outcome: Self::Outcome);
+}
+
+/// Runs one training at a time and stops it on request.
+#[derive(Debug)]
+pub(crate) struct TrainingRunner {
+    progress_tx: watch::Sender<Option<TrainingProgress>>,
+    cancel_tx: watch::Sender<bool>,
+}
+
+impl TrainingRunner {
+    pub fn new() -> Self {
+        let (progress_tx, _) = watch::channel(None);
+        let (cancel_tx, _) = watch::channel(false);
+        Self { progress_tx, cancel_tx }
+    }
+
+    pub fn progress(&self) -> Option<TrainingProgress> {
+        self.progress_tx.borrow().clone()
+    }
+
+    pub fn progress_watch(&self) -> watch::Receiver<Option<TrainingProgress>> {
+        self.progress_tx.subscribe()
+    }
+
+    /// Fails while a training is running.
+    pub fn check(&self) -> Result<()> {
+        match self.progress_tx.borrow().as_ref() {
+            Some(progress) if progress.running() => Err(progress.busy()),
+            _ => Ok(()),
+        }
+    }
+
+    /// Stops the running training, dropping the attempt in progress. Returns
+    /// whether one was running.
+    pub fn stop(&self) -> bool {
+        let running = self.progress().is_some_and(|p| p.running());
+        self.cancel_tx.send_replace(true);
+        running
+    }
+
+    /// Runs `training` from `start` until it is done or stopped.
+    pub async fn run<T: Training>(&self, service: &BaseService, training: &T, start: TrainingProgress) -> Result<()> {
+        let mut busy = None;
+        self.progress_tx.send_if_modified(|progress| {
+            if let Some(running) = progress.as_ref().filter(|p| p.running()) {
+                busy = Some(running.busy());
+                return false;
+            }
+            *progress = Some(start);
+            true
+        });
+        if let Some(e) = busy {
+            return Err(e);
+        }
+        self.cancel_tx.send_replace(false);
+
+        let res = self.attempts(service, training).await;
+        self.progress_tx.send_modify(|progress| {
+            if let Some(progress) = progress {
+                progress.finish();
+            }
+        });
+        info!("[BaseService] Training ended: {res:?}");
+        res
+    }
+
+    async fn attempts<T: Training>(&self, service: &BaseService, training: &T) -> Result<()> {
+        let mut cancel_rx = self.cancel_tx.subscribe();
+        while let Some(progress) = self.progress() {
+            if *cancel_rx.borrow_and_update() { break };
+
+            // whatever the watches hold now belongs to the previous attempt
+            let mut watches = Watches::subscribe(service).await?;
+            watches.mark_unchanged();
+            let Some(attempt) = training.next_attempt(service, &progress).await? else { break };
+
+            let outcome = tokio::select! {
+                res = watches.follow(service, training, &attempt) => res?,
+                _ = cancel_rx.changed() => break,
+            };
+
+            debug!("[BaseService] Training attempt in episode {}: {outcome:?}", attempt.episode.id);
+            self.progress_tx.send_modify(|progress| {
+                if let Some(progress) = progress {
+                    training.record(progress, outcome);
+                }
+            });
+        }
+        Ok(())
+    }
+}
+
+/// Whether the watches caught up with an attempt's setup, what they held
+/// before would judge the previous attempt again.
+#[derive(Debug)]
+struct Settle {
+    start_time: u16,
+    fresh: bool,
+    /// The play mode still to be seen.
+    ready: Option<PlayMode>,
+}
+
+impl Settle {
+    fn new<T>(attempt: &Attempt<T>) -> Self {
+        Self { start_time: attempt.episode.start_time, fresh: false, ready: attempt.ready }
+    }
+
+    /// Takes the latest `see_global` frame and play mode in, true once a frame
+    /// from after the setup and the ready play mode arrived. Moves take effect
+    /// in the cycle after they were made.
+    fn update(&mut self, world: Option<&WorldSnapshot>, play_mode: Option<PlayMode>) -> bool {
+        self.fresh |= world.is_some_and(|w| w.time > self.start_time);
+        if self.ready.is_some() && self.ready == play_mode {
+            self.ready = None;
+        }
+        self.fresh && self.ready.is_none()
+    }
+}
+
+struct Watches {
+    time_rx: watch::Receiver<Option<u16>>,
+    play_mode_rx: watch::Receiver<Option<PlayMode>>,
+    score_rx: watch::Receiver<Score>,
+    world_rx: watch::Receiver<Option<WorldSnapshot>>,
+}
+
+impl Watches {
+    async fn subscribe(service: &BaseService) -> Result<Self> {
+        let not_running = || Error::ServerNotRunning { status: service.status_now() };
+        Ok(Self {
+            time_rx: service.time().await.ok_or_else(not_running)?,
+            play_mode_rx: service.play_mode().await.ok_or_else(not_running)?,
+            score_rx: service.score().await.ok_or_else(not_running)?,
+            world_rx: service.world().await.ok_or_else(not_running)?,
+        })
+    }
+
+    fn mark_unchanged(&mut self) {
+        self.time_rx.mark_unchanged();
+        self.play_mode_rx.mark_unchanged();
+        self.score_rx.mark_unchanged();
+        self.world_rx.mark_unchanged();
+    }
+
+    /// Follows one attempt until it is judged, frames and play modes are only
+    /// shown to the judge once the attempt settled.
+    async fn follow<T: Training>(&mut self, service: &BaseService, training: &T, attempt: &Attempt<T::Turn>) -> Result<T::Outcome> {
+        let episode = attempt.episode;
+        let mut settle = Settle::new(attempt);
+        let mut ready_time = None;
+
+        loop {
+            let closed = tokio::select! {
+                res = self.time_rx.changed() => res.is_err(),
+                res = self.play_mode_rx.changed() => res.is_err(),
+                res = self.score_rx.changed() => res.is_err(),
+                res = self.world_rx.changed() => res.is_err(),
+            };
+            if closed {
+                return Err(Error::ServerNotRunning { status: service.status_now() });
+            }
+
+            let time = self.time_rx.borrow_and_update().unwrap_or(episode.start_time);
+            let score = episode.score(*self.score_rx.borrow_and_update());
+            let play_mode = *self.play_mode_rx.borrow_and_update();
+            let world = self.world_rx.borrow_and_update().clone();
+            let settled = settle.update(world.as_ref(), play_mode);
+            if settled {
+                ready_time.get_or_insert(time);
+            }
+            let play_mode = play_mode.filter(|_| settled);
+            let world = world.as_ref().filter(|_| settled);
+            let frame = Frame { episode, time, score, play_mode, world, ready_time };
+            if let Some(outcome) = training.judge(attempt, &frame) {
+                return Ok(outcome);
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_settle_on_frames_after_setup() {
+        let episode = Episode { id: 2, start_time: 40, start_score: Score::default() };
+        let mut settle = Settle::new(&Attempt { turn: (), episode, ready: None });
+        let frame = |time| WorldSnapshot { time, ..Default::default() };
+
+        assert!(!settle.update(None, None));
+        // the setup happened during cycle 40, its frame may still show the last attempt
+        assert!(!settle.update(Some(&frame(39)), None));
+        assert!(!settle.update(Some(&frame(40)), None));
+        assert!(settle.update(Some(&frame(41)), None));
+        assert!(settle.update(None, None));
+
+        // the previous penalty's play mode is ignored until the ready one shows up
+        let ready = Some(PlayMode::PM_PenaltyReady_Left);
+        let mut settle = Settle::new(&Attempt { turn: (), episode, ready });
+        assert!(!settle.update(Some(&frame(41)), Some(PlayMode::PM_PenaltyScore_Left)));
+        assert!(!settle.update(Some(&frame(41)), Some(PlayMode::PM_PenaltySetup_Left)));
+        assert!(settle.update(Some(&frame(42)), ready));
+        assert!(settle.update(Some(&frame(43)), Some(PlayMode::PM_PenaltyTaken_Left)));
+    }
+}
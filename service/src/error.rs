@@ -12,12 +12,48 @@ pub enum Error {
     #[error("Failed to shutdown the process.")]
     ProcessFailedToShutdown,
 
+    #[error("Invalid rcssserver configuration: {}", .0.iter().map(ToString::to_string).collect::<Vec<_>>().join(", "))]
+    InvalidConfig(Vec<process::ConfigViolation>),
+
+    #[error("Invalid rcssserver overrides: {0}")]
+    InvalidOverrides(#[source] serde_json::Error),
+
+    #[error("No rcssserver preset named '{0}'")]
+    UnknownPreset(String),
+
+    #[error("Failed to load the rcssserver configuration directory: {0}")]
+    InvalidConfDir(#[source] process::ConfigError),
+
+    #[error("Failed to load the scenario directory: {0}")]
+    InvalidScenarioDir(#[source] common::scenario::ScenarioError),
+
     #[error("Failed to spawn process: {0}")]
     ProcessSpawnFailed(#[source] process::Error),
 
     #[error("Failed to send trainer command: {0}")]
     TrainerCommandFailed(String),
 
+    #[error("No scenario named '{0}'")]
+    UnknownScenario(String),
+
+    #[error("No team connected on the {side:?} side to place players of")]
+    TeamNotConnected { side: common::types::Side },
+
+    #[error("A curriculum is already running.")]
+    CurriculumRunning,
+
+    #[error("Invalid curriculum: {0}")]
+    InvalidCurriculum(&'static str),
+
+    #[error("A penalty training is already running.")]
+    PenaltyTrainingRunning,
+
+    #[error("Invalid penalty training: {0}")]
+    InvalidPenaltyTraining(&'static str),
+
+    #[error("Failed to read the keepaway log: {0}")]
+    KeepawayLogFailed(#[source] std::io::Error),
+
     #[error("Status channel closed unexpectedly")]
     StatusChannelClosed,
 
@@ -28,6 +28,5 @@ pub use agones::{
 };
 
 pub use error::{Error, Result};
-pub use base::ServerStatus;
-
-pub const GAME_END_TIMESTEP: u16 = 6000;
+pub use process::addon::Score;
+pub use base::{ActionList, Blocklist, Curriculum, CurriculumProgress, Episode, InitialState, KeepawayEnd, KeepawayEpisode, KeepawayLog, KeepawayStats, MatchFormat, PenaltyAttempt, PenaltyOutcome, PenaltyProgress, PenaltyTraining, Phase, PlayerInit, Scenarios, ServerStatus, Stage, StopConditions, StopReason, Success, TrainingProgress};
@@ -8,7 +8,7 @@ pub struct StandaloneService {
 
 impl StandaloneService {
     pub async fn from_args(args: StandaloneArgs) -> crate::Result<Self> {
-        let base = BaseService::from_args(args.base_args).await;
+        let base = BaseService::from_args(args.base_args).await?;
         Ok(Self::new(base))
     }
 